@@ -35,4 +35,6 @@ pub enum ChangeSetError {
     },
     #[error("Expected to recieve field path")]
     MissingFieldPath,
+    #[error("{new_name} is renamed from {old_name} but no {old_name} exists in the previous version")]
+    MissingRenamedFrom { old_name: String, new_name: String },
 }
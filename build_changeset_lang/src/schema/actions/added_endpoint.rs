@@ -3,7 +3,7 @@ use build_script_shared::parsers::*;
 use build_script_shared::InputType;
 use std::fmt::Display;
 
-use crate::{ChangeSetError, ChangeSetResult};
+use crate::{ChangeSetError, ChangeSetResult, RemovedEndpoint, SingleChange};
 use build_script_lang::schema::*;
 use fake::Dummy;
 use nom::character::complete::*;
@@ -56,6 +56,17 @@ impl<I> AddedEndpoint<I> {
 
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, _schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Clone,
+    {
+        Ok(vec![SingleChange::RemovedEndpoint(RemovedEndpoint {
+            type_name: self.type_name.clone(),
+            endpoint: self.endpoint.clone(),
+        })])
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for AddedEndpoint<I> {
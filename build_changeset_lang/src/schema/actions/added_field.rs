@@ -6,15 +6,19 @@ use build_script_shared::parsers::*;
 use build_script_shared::InputType;
 
 use fake::Dummy;
+use nom::bytes::complete::*;
 use nom::character::complete::*;
+use nom::combinator::*;
 use nom::error::context;
 use nom::sequence::*;
 
 use crate::ChangeSetError;
 use crate::ChangeSetResult;
 use crate::FieldPath;
+use crate::FillExpression;
+use crate::{RemovedField, SingleChange};
 
-/// "+ \<ident\>.\<ident\>:\<type\>"
+/// "+ \<ident\>.\<ident\>:\<type\> (= \<literal\>)? (\<- \<fill\>)?"
 #[derive(PartialEq, Eq, Debug, Clone, Hash, Dummy)]
 pub struct AddedField<I> {
     pub(crate) comments: Comments,
@@ -22,7 +26,9 @@ pub struct AddedField<I> {
     pub(crate) visibility: Visibility,
     pub(crate) field_path: FieldPath<I>,
     pub(crate) field_type: Types<I>,
+    pub(crate) default: Option<Literal<I>>,
     pub(crate) order: u64,
+    pub(crate) fill: Option<FillExpression<I>>,
 }
 
 impl<I> AddedField<I> {
@@ -30,6 +36,32 @@ impl<I> AddedField<I> {
         &self.field_type
     }
 
+    pub fn default(&self) -> Option<&Literal<I>> {
+        self.default.as_ref()
+    }
+
+    /// Value given to the field when migrating from the previous version
+    pub fn fill(&self) -> Option<&FillExpression<I>> {
+        self.fill.as_ref()
+    }
+
+    /// Create the change which adds the field back at the given path
+    pub fn from_field(field_path: FieldPath<I>, field: &FieldValue<I>) -> AddedField<I>
+    where
+        I: Clone,
+    {
+        AddedField {
+            comments: field.comments.get_doc_comments(),
+            attributes: field.attributes.clone(),
+            visibility: field.visibility,
+            field_path,
+            field_type: field.field_type.clone(),
+            default: field.default.clone(),
+            order: field.order,
+            fill: None,
+        }
+    }
+
     /// Move from one input type to another
     pub fn map<O, F>(self, f: F) -> AddedField<O>
     where
@@ -41,7 +73,9 @@ impl<I> AddedField<I> {
             visibility: self.visibility,
             field_path: self.field_path.map(f),
             field_type: self.field_type.map(f),
+            default: self.default.map(|default| default.map(f)),
             order: self.order,
+            fill: self.fill.map(|fill| fill.map(f)),
         }
     }
 
@@ -60,37 +94,66 @@ impl<I> AddedField<I> {
             });
         }
 
+        if let Some(fill) = &self.fill {
+            fill.check("add field", &self.field_type, named_fields)?;
+        }
+
         named_fields.insert_field(FieldValue {
             name: named_key.clone(),
             attributes: self.attributes.clone(),
             visibility: self.visibility,
             comments: self.comments.get_doc_comments(),
             field_type: self.field_type.clone(),
+            default: self.default.clone(),
             order: self.order,
         });
 
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, _schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Clone,
+    {
+        Ok(vec![SingleChange::RemovedField(RemovedField {
+            field_path: self.field_path.clone(),
+        })])
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for AddedField<I> {
     fn parse(s: I) -> build_script_shared::error::ParserResult<I, Self> {
-        let (s, (comments, attributes, ((visibility, field_path), (field_type, order)))) =
-            context(
-                "Parsing AddedField",
-                tuple((
-                    Comments::parse,
-                    Attributes::parse,
-                    preceded(
-                        ws(char('+')),
-                        key_value(
-                            pair(Visibility::parse, FieldPath::parse),
-                            char(':'),
-                            pair(Types::parse, surrounded('(', u64, ')')),
+        let (
+            s,
+            (
+                comments,
+                attributes,
+                ((visibility, field_path), (field_type, (default, order))),
+                fill,
+            ),
+        ) = context(
+            "Parsing AddedField",
+            tuple((
+                Comments::parse,
+                Attributes::parse,
+                preceded(
+                    ws(char('+')),
+                    key_value(
+                        pair(Visibility::parse, FieldPath::parse),
+                        char(':'),
+                        pair(
+                            Types::parse,
+                            pair(
+                                opt(preceded(ws(char('=')), cut(ws(Literal::parse)))),
+                                surrounded('(', u64, ')'),
+                            ),
                         ),
                     ),
-                )),
-            )(s)?;
+                ),
+                opt(preceded(ws(tag("<-")), cut(ws(FillExpression::parse)))),
+            )),
+        )(s)?;
 
         Ok((
             s,
@@ -100,7 +163,9 @@ impl<I: InputType> ParserDeserialize<I> for AddedField<I> {
                 visibility,
                 field_path,
                 field_type,
+                default,
                 order,
+                fill,
             },
         ))
     }
@@ -122,7 +187,15 @@ impl<I> ParserSerialize for AddedField<I> {
         self.field_path.compose(f, new_ctx)?;
         write!(f, ": ")?;
         self.field_type.compose(f, new_ctx)?;
+        if let Some(default) = &self.default {
+            write!(f, " = ")?;
+            default.compose(f, new_ctx)?;
+        }
         write!(f, "({})", self.order)?;
+        if let Some(fill) = &self.fill {
+            write!(f, " <- ")?;
+            fill.compose(f, new_ctx)?;
+        }
         Ok(())
     }
 }
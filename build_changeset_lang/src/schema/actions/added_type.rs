@@ -10,9 +10,9 @@ use nom::character::complete::*;
 use nom::error::context;
 use nom::sequence::*;
 
-use crate::{AddedTypeData, ChangeSetError, ChangeSetResult};
+use crate::{AddedTypeData, ChangeSetError, ChangeSetResult, RemovedType, SingleChange};
 
-/// "+ (node|edge(\<end_points\>)|struct|enum) \<ident\>"
+/// "+ (node|edge(\<end_points\>)|struct|enum|import|type(\<type\>)|group(\<members\>)) \<ident\>"
 #[derive(PartialEq, Eq, Debug, Clone, Hash, Dummy)]
 pub struct AddedType<I> {
     pub comments: Comments,
@@ -85,12 +85,36 @@ impl<I> AddedType<I> {
                 Default::default(),
                 Mark::null(),
             )),
+            AddedTypeData::Alias { ty } => SchemaStm::Alias(AliasExp::new(
+                self.comments.get_doc_comments(),
+                self.type_name.clone(),
+                Default::default(),
+                ty.clone(),
+                Mark::null(),
+            )),
+            AddedTypeData::Group { members } => SchemaStm::Group(GroupExp::new(
+                self.comments.get_doc_comments(),
+                self.type_name.clone(),
+                members.clone(),
+                Mark::null(),
+            )),
         };
 
         schema.push(new_content);
 
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, _schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Clone,
+    {
+        Ok(vec![SingleChange::RemovedType(RemovedType {
+            type_type: self.type_type.get_type(),
+            type_name: self.type_name.clone(),
+        })])
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for AddedType<I> {
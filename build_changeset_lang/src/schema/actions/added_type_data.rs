@@ -7,6 +7,7 @@ use build_script_shared::parsers::*;
 use build_script_shared::InputType;
 
 use fake::Dummy;
+use fake::Faker;
 use nom::branch::*;
 use nom::bytes::complete::*;
 use nom::combinator::*;
@@ -23,6 +24,13 @@ pub enum AddedTypeData<I> {
     },
     Enum,
     Import,
+    Alias {
+        ty: Types<I>,
+    },
+    Group {
+        #[dummy(faker = "(Faker, 1..5)")]
+        members: Vec<Ident<I>>,
+    },
 }
 
 impl<I> AddedTypeData<I> {
@@ -32,6 +40,8 @@ impl<I> AddedTypeData<I> {
             AddedTypeData::Struct => SchemaStmType::Struct,
             AddedTypeData::Enum => SchemaStmType::Enum,
             AddedTypeData::Import => SchemaStmType::Import,
+            AddedTypeData::Alias { .. } => SchemaStmType::Alias,
+            AddedTypeData::Group { .. } => SchemaStmType::Group,
             AddedTypeData::Edge { .. } => SchemaStmType::Edge,
         }
     }
@@ -45,6 +55,10 @@ impl<I> AddedTypeData<I> {
             SchemaStm::Struct(_) => AddedTypeData::Struct,
             SchemaStm::Enum(_) => AddedTypeData::Enum,
             SchemaStm::Import(_) => AddedTypeData::Import,
+            SchemaStm::Alias(a) => AddedTypeData::Alias { ty: a.ty.clone() },
+            SchemaStm::Group(g) => AddedTypeData::Group {
+                members: g.members.clone(),
+            },
             SchemaStm::Edge(e) => AddedTypeData::Edge {
                 endpoints: e.endpoints.clone(),
             },
@@ -61,6 +75,10 @@ impl<I> AddedTypeData<I> {
             AddedTypeData::Struct => AddedTypeData::Struct,
             AddedTypeData::Enum => AddedTypeData::Enum,
             AddedTypeData::Import => AddedTypeData::Import,
+            AddedTypeData::Alias { ty } => AddedTypeData::Alias { ty: ty.map(f) },
+            AddedTypeData::Group { members } => AddedTypeData::Group {
+                members: members.into_iter().map(|m| m.map(f)).collect(),
+            },
             AddedTypeData::Edge { endpoints } => AddedTypeData::Edge {
                 endpoints: endpoints
                     .into_iter()
@@ -82,6 +100,17 @@ impl<I: InputType> ParserDeserialize<I> for AddedTypeData<I> {
                 value(AddedTypeData::Struct, tag("struct")),
                 value(AddedTypeData::Enum, tag("enum")),
                 value(AddedTypeData::Import, tag("import")),
+                map(
+                    pair(ws(tag("type")), surrounded('(', Types::parse, ')')),
+                    |(_, ty)| AddedTypeData::Alias { ty },
+                ),
+                map(
+                    pair(
+                        ws(tag("group")),
+                        surrounded('(', punctuated(Ident::ident, '|'), ')'),
+                    ),
+                    |(_, members)| AddedTypeData::Group { members },
+                ),
                 map(
                     pair(ws(tag("edge")), EdgeExp::parse_endpoints),
                     |(_, endpoints)| AddedTypeData::Edge { endpoints },
@@ -104,6 +133,24 @@ impl<I> ParserSerialize for AddedTypeData<I> {
             AddedTypeData::Struct => write!(f, "{indents}struct")?,
             AddedTypeData::Enum => write!(f, "{indents}enum")?,
             AddedTypeData::Import => write!(f, "{indents}import")?,
+            AddedTypeData::Alias { ty } => {
+                write!(f, "{indents}type(")?;
+                ty.compose(f, ctx.set_indents(0))?;
+                write!(f, ")")?;
+            }
+            AddedTypeData::Group { members } => {
+                write!(f, "{indents}group(")?;
+                let mut first = true;
+                for member in members {
+                    if !first {
+                        write!(f, " | ")?;
+                    } else {
+                        first = false;
+                    }
+                    member.compose(f, ctx.set_indents(0))?;
+                }
+                write!(f, ")")?;
+            }
             AddedTypeData::Edge { endpoints } => {
                 let mut first = true;
                 write!(f, "{indents}edge ( ")?;
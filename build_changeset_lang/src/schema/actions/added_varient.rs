@@ -10,7 +10,7 @@ use nom::character::complete::*;
 use nom::error::context;
 use nom::sequence::*;
 
-use crate::AddedVarientType;
+use crate::{AddedField, AddedVarientType, FieldPath, RemovedVarient, SingleChange};
 use crate::{ChangeSetError, ChangeSetResult};
 
 /// "+ enum \<ident\>.\<ident\>"
@@ -25,6 +25,37 @@ pub struct AddedVarient<I> {
 }
 
 impl<I> AddedVarient<I> {
+    /// Create the changes which add the varient and its fields back to the enum
+    pub fn from_varient(
+        type_name: &Ident<I>,
+        varient: &EnumVarient<I>,
+        order: usize,
+    ) -> Vec<SingleChange<I>>
+    where
+        I: Clone,
+    {
+        let mut changes = vec![SingleChange::AddedVarient(AddedVarient {
+            attributes: varient.attributes().clone(),
+            comments: varient.comments().get_doc_comments(),
+            type_name: type_name.clone(),
+            varient_name: varient.name().clone(),
+            varient_type: AddedVarientType::from(varient),
+            order: order as u64,
+        })];
+
+        if let EnumVarient::Struct { fields, name, .. } = varient {
+            let path = FieldPath::new_path(type_name.clone(), vec![name.clone()]);
+            for field in fields.iter() {
+                changes.push(SingleChange::AddedField(AddedField::from_field(
+                    path.push(field.name.clone()),
+                    field,
+                )));
+            }
+        }
+
+        changes
+    }
+
     /// Move from one input type to another
     pub fn map<O, F>(self, f: F) -> AddedVarient<O>
     where
@@ -88,6 +119,17 @@ impl<I> AddedVarient<I> {
 
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, _schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Clone,
+    {
+        Ok(vec![SingleChange::RemovedVarient(RemovedVarient {
+            type_name: self.type_name.clone(),
+            varient_name: self.varient_name.clone(),
+        })])
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for AddedVarient<I> {
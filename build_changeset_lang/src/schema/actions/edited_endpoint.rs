@@ -3,7 +3,7 @@ use build_script_shared::parsers::*;
 use build_script_shared::InputType;
 use std::fmt::Display;
 
-use crate::{ChangeSetError, ChangeSetResult};
+use crate::{ChangeSetError, ChangeSetResult, SingleChange};
 use build_script_lang::schema::*;
 use fake::Dummy;
 use nom::character::complete::*;
@@ -55,6 +55,33 @@ impl<I> EditedEndpoint<I> {
 
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Default + Clone + PartialEq + Ord,
+    {
+        let edge = schema.get_type(Some(SchemaStmType::Edge), &self.type_name);
+
+        let key = (self.endpoint.source.clone(), self.endpoint.target.clone());
+        let endpoint = match edge {
+            Some(SchemaStm::Edge(e)) => e.endpoints.get(&key),
+            _ => None,
+        };
+
+        let endpoint = endpoint.ok_or_else(|| ChangeSetError::InvalidAction {
+            action: format!("invert edit endpoint"),
+            reason: format!(
+                "{} is missing {} => {}",
+                self.type_name, self.endpoint.source, self.endpoint.target
+            ),
+        })?;
+
+        Ok(vec![SingleChange::EditedEndpoint(EditedEndpoint {
+            type_name: self.type_name.clone(),
+            endpoint: endpoint.clone(),
+        })])
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for EditedEndpoint<I> {
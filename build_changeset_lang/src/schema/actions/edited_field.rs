@@ -6,16 +6,17 @@ use build_script_shared::parsers::*;
 use build_script_shared::InputType;
 use fake::{Faker, Rng};
 
-use crate::FieldPath;
-use crate::{ChangeSetError, ChangeSetResult};
+use crate::{ChangeSetError, ChangeSetResult, SingleChange};
+use crate::{FieldPath, FillExpression};
 use build_script_lang::schema::*;
 use fake::Dummy;
+use nom::bytes::complete::*;
 use nom::character::complete::*;
 use nom::combinator::*;
 use nom::error::context;
 use nom::sequence::*;
 
-/// "* \<ident\>.\<ident\>: \<type\> => \<type\>"
+/// "* \<ident\>.\<ident\>: \<type\> (= \<literal\>)? => \<type\> (= \<literal\>)? (\<- \<fill\>)?"
 #[derive(PartialEq, Eq, Debug, Clone, Hash)]
 pub struct EditedField<I> {
     pub(crate) field_path: FieldPath<I>,
@@ -25,8 +26,11 @@ pub struct EditedField<I> {
     pub(crate) new_visibility: Visibility,
     pub(crate) old_type: Types<I>,
     pub(crate) new_type: Types<I>,
+    pub(crate) old_default: Option<Literal<I>>,
+    pub(crate) new_default: Option<Literal<I>>,
     pub(crate) old_order: u64,
     pub(crate) new_order: u64,
+    pub(crate) fill: Option<FillExpression<I>>,
 }
 
 impl<I> EditedField<I> {
@@ -38,6 +42,19 @@ impl<I> EditedField<I> {
         &self.new_type
     }
 
+    pub fn old_default(&self) -> Option<&Literal<I>> {
+        self.old_default.as_ref()
+    }
+
+    pub fn new_default(&self) -> Option<&Literal<I>> {
+        self.new_default.as_ref()
+    }
+
+    /// Value given to the field instead of converting the old one
+    pub fn fill(&self) -> Option<&FillExpression<I>> {
+        self.fill.as_ref()
+    }
+
     /// Move from one input type to another
     pub fn map<O, F>(self, f: F) -> EditedField<O>
     where
@@ -51,8 +68,11 @@ impl<I> EditedField<I> {
             new_visibility: self.new_visibility,
             old_type: self.old_type.map(f),
             new_type: self.new_type.map(f),
+            old_default: self.old_default.map(|default| default.map(f)),
+            new_default: self.new_default.map(|default| default.map(f)),
             old_order: self.old_order,
             new_order: self.new_order,
+            fill: self.fill.map(|fill| fill.map(f)),
         }
     }
 
@@ -63,6 +83,11 @@ impl<I> EditedField<I> {
         let named_fields = self.field_path.retrieve_field(schema)?;
 
         let named_key = self.field_path.get_field_name_res()?;
+
+        if let Some(fill) = &self.fill {
+            fill.check("edit field", &self.new_type, named_fields)?;
+        }
+
         let field = named_fields
             .get_field_mut(named_key.as_str())
             .ok_or_else(|| ChangeSetError::InvalidAction {
@@ -103,17 +128,46 @@ impl<I> EditedField<I> {
         field.comments.replace_doc_comments(&self.comments);
         field.attributes = self.attributes.clone();
         field.field_type = self.new_type.clone();
+        field.default = self.new_default.clone();
         field.visibility = self.new_visibility;
         field.order = self.new_order;
 
         Ok(())
     }
 
+    /// Create the changes undoing this change
+    ///
+    /// The fill is not kept as it refers to the fields of the previous version
+    pub fn invert(&self, schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Clone + Ord,
+    {
+        let field = self.field_path.get_field(schema)?;
+
+        Ok(vec![SingleChange::EditedFieldType(EditedField {
+            field_path: self.field_path.clone(),
+            comments: field.comments.get_doc_comments(),
+            attributes: field.attributes.clone(),
+            old_visibility: self.new_visibility,
+            new_visibility: field.visibility,
+            old_type: self.new_type.clone(),
+            new_type: field.field_type.clone(),
+            old_default: self.new_default.clone(),
+            new_default: field.default.clone(),
+            old_order: self.new_order,
+            new_order: field.order,
+            fill: None,
+        })])
+    }
+
     pub fn check_convertion_res(&self) -> ParserSlimResult<I, ()>
     where
         I: Clone,
     {
-        self.old_type.check_convertion_res(&self.new_type)?;
+        // The old value is not converted when the field is filled
+        if self.fill.is_none() {
+            self.old_type.check_convertion_res(&self.new_type)?;
+        }
         Ok(())
     }
 }
@@ -127,23 +181,38 @@ impl<I: InputType> ParserDeserialize<I> for EditedField<I> {
             s,
             (
                 field_path,
-                ((old_visibility, old_type, old_order), (new_visibility, new_type, new_order)),
+                (
+                    (old_visibility, old_type, old_default, old_order),
+                    (new_visibility, new_type, new_default, new_order),
+                ),
+                fill,
             ),
         ) = context(
             "Parsing EditedField",
             preceded(
                 ws(char('*')),
-                pair(
+                tuple((
                     FieldPath::parse,
                     preceded(
                         ws(char(':')),
                         cut(key_value(
-                            tuple((Visibility::parse, Types::parse, surrounded('(', u64, ')'))),
+                            tuple((
+                                Visibility::parse,
+                                Types::parse,
+                                opt(preceded(ws(char('=')), ws(Literal::parse))),
+                                surrounded('(', u64, ')'),
+                            )),
                             pair(char('='), char('>')),
-                            tuple((Visibility::parse, Types::parse, surrounded('(', u64, ')'))),
+                            tuple((
+                                Visibility::parse,
+                                Types::parse,
+                                opt(preceded(ws(char('=')), ws(Literal::parse))),
+                                surrounded('(', u64, ')'),
+                            )),
                         )),
                     ),
-                ),
+                    opt(preceded(ws(tag("<-")), cut(ws(FillExpression::parse)))),
+                )),
             ),
         )(s)?;
 
@@ -157,8 +226,11 @@ impl<I: InputType> ParserDeserialize<I> for EditedField<I> {
                 old_visibility,
                 old_type,
                 new_type,
+                old_default,
+                new_default,
                 old_order: old_order as u64,
                 new_order: new_order as u64,
+                fill,
             },
         ))
     }
@@ -180,11 +252,23 @@ impl<I> ParserSerialize for EditedField<I> {
         write!(f, ": ")?;
         self.old_visibility.compose(f, new_ctx)?;
         self.old_type.compose(f, new_ctx)?;
+        if let Some(default) = &self.old_default {
+            write!(f, " = ")?;
+            default.compose(f, new_ctx)?;
+        }
         write!(f, "({})", self.old_order)?;
         write!(f, " => ")?;
         self.new_visibility.compose(f, new_ctx)?;
         self.new_type.compose(f, new_ctx)?;
+        if let Some(default) = &self.new_default {
+            write!(f, " = ")?;
+            default.compose(f, new_ctx)?;
+        }
         write!(f, "({})", self.new_order)?;
+        if let Some(fill) = &self.fill {
+            write!(f, " <- ")?;
+            fill.compose(f, new_ctx)?;
+        }
         Ok(())
     }
 }
@@ -216,8 +300,11 @@ impl<I: Dummy<Faker>> Dummy<Faker> for EditedField<I> {
             new_visibility: Visibility::dummy_with_rng(config, rng),
             old_type,
             new_type,
+            old_default: Option::dummy_with_rng(config, rng),
+            new_default: Option::dummy_with_rng(config, rng),
             old_order: u64::dummy_with_rng(config, rng),
             new_order: u64::dummy_with_rng(config, rng),
+            fill: Option::dummy_with_rng(config, rng),
         }
     }
 }
@@ -10,7 +10,7 @@ use nom::character::complete::char;
 use nom::error::context;
 use nom::sequence::*;
 
-use crate::{ChangeSetError, ChangeSetResult};
+use crate::{ChangeSetError, ChangeSetResult, SingleChange};
 
 #[derive(PartialEq, Eq, Debug, Clone, Hash, Dummy)]
 pub struct EditedGenerics<I> {
@@ -48,6 +48,7 @@ impl<I> EditedGenerics<I> {
         let generics_opt = match stm {
             SchemaStm::Struct(s) => Some(&mut s.generics),
             SchemaStm::Enum(e) => Some(&mut e.generics),
+            SchemaStm::Alias(a) => Some(&mut a.generics),
             _ => None,
         };
 
@@ -73,6 +74,18 @@ impl<I> EditedGenerics<I> {
 
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, _schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Clone,
+    {
+        Ok(vec![SingleChange::EditedGenerics(EditedGenerics {
+            type_name: self.type_name.clone(),
+            old_generics: self.new_generics.clone(),
+            new_generics: self.old_generics.clone(),
+        })])
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for EditedGenerics<I> {
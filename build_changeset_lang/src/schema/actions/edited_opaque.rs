@@ -7,14 +7,18 @@ use build_script_shared::InputType;
 use fake::{Faker, Rng};
 
 use crate::FieldPath;
-use crate::{ChangeSetError, ChangeSetResult};
+use crate::{ChangeSetError, ChangeSetResult, SingleChange};
 use build_script_lang::schema::*;
 use fake::Dummy;
+use nom::branch::alt;
 use nom::character::complete::*;
+use nom::combinator::map;
 use nom::error::context;
 use nom::sequence::*;
 
-/// "* \<ident\>.\<ident\>: \<type\> => \<type\>"
+/// "* \<ident\>(.\<ident\>)?: \<type\> => \<type\>"
+///
+/// Without a varient the change is made to the type of an alias
 #[derive(PartialEq, Eq, Debug, Clone, Hash)]
 pub struct EditedOpaque<I> {
     pub(crate) field_path: FieldPath<I>,
@@ -47,13 +51,6 @@ impl<I> EditedOpaque<I> {
     where
         I: Default + Clone + PartialEq + Ord,
     {
-        if self.field_path.path.is_empty() {
-            return Err(ChangeSetError::InvalidAction {
-                action: format!("retrieving opaque"),
-                reason: format!("Attempted to resolve type path without type {}", self),
-            });
-        }
-
         let ty = schema
             .iter_mut()
             .find(|s| s.get_type() == &self.field_path.root)
@@ -63,6 +60,26 @@ impl<I> EditedOpaque<I> {
             })?;
 
         match ty {
+            SchemaStm::Alias(a) => {
+                if !self.field_path.path.is_empty() {
+                    return Err(ChangeSetError::InvalidAction {
+                        action: format!("retrieving opaque"),
+                        reason: format!("Attempted to go deeper than possible {}", self),
+                    });
+                }
+
+                if a.ty != self.old_type {
+                    return Err(ChangeSetError::InvalidAction {
+                        action: format!("edit opaque"),
+                        reason: format!(
+                            "old type of {} does not match, expected {} got {}",
+                            self.field_path, self.old_type, a.ty
+                        ),
+                    });
+                }
+
+                a.ty = self.new_type.clone();
+            }
             SchemaStm::Enum(e) => {
                 if self.field_path.path.len() != 1 {
                     return Err(ChangeSetError::InvalidAction {
@@ -107,6 +124,7 @@ impl<I> EditedOpaque<I> {
             SchemaStm::Edge(_)
             | SchemaStm::Node(_)
             | SchemaStm::Import(_)
+            | SchemaStm::Group(_)
             | SchemaStm::Struct(_) => {
                 return Err(ChangeSetError::InvalidAction {
                     action: format!("retrieving opaque"),
@@ -118,6 +136,18 @@ impl<I> EditedOpaque<I> {
         Ok(())
     }
 
+    /// Create the changes undoing this change
+    pub fn invert(&self, _schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Clone,
+    {
+        Ok(vec![SingleChange::EditedOpaque(EditedOpaque {
+            field_path: self.field_path.clone(),
+            old_type: self.new_type.clone(),
+            new_type: self.old_type.clone(),
+        })])
+    }
+
     pub fn check_convertion_res(&self) -> ParserSlimResult<I, ()>
     where
         I: Clone,
@@ -134,7 +164,7 @@ impl<I: InputType> ParserDeserialize<I> for EditedOpaque<I> {
             preceded(
                 ws(char('*')),
                 pair(
-                    FieldPath::parse,
+                    alt((FieldPath::parse, map(Ident::ident, FieldPath::new))),
                     preceded(
                         ws(char(':')),
                         key_value(
@@ -168,7 +198,11 @@ impl<I> ParserSerialize for EditedOpaque<I> {
         let new_ctx = ctx.set_indents(0);
 
         write!(f, "{indents}* ")?;
-        self.field_path.compose(f, new_ctx)?;
+        if self.field_path.path.is_empty() {
+            self.field_path.root.compose(f, new_ctx)?;
+        } else {
+            self.field_path.compose(f, new_ctx)?;
+        }
         write!(f, ": ")?;
         self.old_type.compose(f, new_ctx)?;
         write!(f, " => ")?;
@@ -10,7 +10,7 @@ use nom::character::complete::*;
 use nom::error::context;
 use nom::sequence::*;
 
-use crate::ChangeSetResult;
+use crate::{ChangeSetResult, SingleChange};
 
 /// "\<attributes\>
 /// * (node|edge(\<end_points\>)|struct|enum) \<ident\>"
@@ -28,6 +28,13 @@ impl EditedSchema {
 
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    pub fn invert<I>(&self, schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>> {
+        Ok(vec![SingleChange::EditedSchema(EditedSchema {
+            comments: schema.comments.clone(),
+        })])
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for EditedSchema {
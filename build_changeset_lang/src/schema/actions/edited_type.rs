@@ -6,20 +6,26 @@ use build_script_shared::parsers::*;
 use build_script_shared::InputType;
 
 use fake::Dummy;
+use nom::bytes::complete::tag;
 use nom::character::complete::*;
+use nom::combinator::*;
 use nom::error::context;
 use nom::sequence::*;
 
-use crate::{ChangeSetError, ChangeSetResult};
+use crate::{ChangeSetError, ChangeSetResult, SingleChange};
 
 /// "\<attributes\>
-/// * (node|edge(\<end_points\>)|struct|enum) \<ident\>"
+/// * abstract? (node|edge(\<end_points\>)|struct|enum) \<ident\> (: \<parent\>)?"
 #[derive(PartialEq, Eq, Debug, Clone, Hash, Dummy)]
 pub struct EditedType<I> {
     pub comments: Comments,
     pub attributes: Attributes<I>,
     pub type_type: SchemaStmType,
     pub type_name: Ident<I>,
+    /// Only nodes can be abstract
+    pub is_abstract: bool,
+    /// Only nodes can inherit from other nodes
+    pub parent: Option<Ident<I>>,
 }
 
 impl<I> EditedType<I> {
@@ -33,6 +39,8 @@ impl<I> EditedType<I> {
             attributes: self.attributes.map(f),
             type_name: self.type_name.map(f),
             type_type: self.type_type,
+            is_abstract: self.is_abstract,
+            parent: self.parent.map(|parent| parent.map(f)),
         }
     }
 
@@ -53,28 +61,78 @@ impl<I> EditedType<I> {
             SchemaStm::Edge(n) => &mut n.comments,
             SchemaStm::Struct(n) => &mut n.comments,
             SchemaStm::Import(n) => &mut n.comments,
+            SchemaStm::Alias(n) => &mut n.comments,
+            SchemaStm::Group(n) => &mut n.comments,
         };
 
         current_comments.replace_doc_comments(&self.comments);
 
         match stm {
             SchemaStm::Edge(e) => e.attributes = self.attributes.clone(),
-            SchemaStm::Node(n) => n.attributes = self.attributes.clone(),
+            SchemaStm::Node(n) => {
+                n.attributes = self.attributes.clone();
+                n.is_abstract = self.is_abstract;
+                n.parent = self.parent.clone();
+            }
             _ => (),
         }
 
+        if !matches!(stm, SchemaStm::Node(_)) && (self.is_abstract || self.parent.is_some()) {
+            return Err(ChangeSetError::InvalidAction {
+                action: format!("edit {} {}", self.type_type, self.type_name),
+                reason: "only nodes can be abstract or inherit from other nodes".to_string(),
+            });
+        }
+
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Default + Clone + PartialEq,
+    {
+        let stm = schema
+            .get_type(Some(self.type_type), &self.type_name)
+            .ok_or_else(|| ChangeSetError::InvalidAction {
+                action: format!("invert edit type"),
+                reason: format!("no {} named {} exists", self.type_type, self.type_name),
+            })?;
+
+        let (is_abstract, parent) = match stm {
+            SchemaStm::Node(n) => (n.is_abstract, n.parent.clone()),
+            _ => (false, None),
+        };
+
+        Ok(vec![SingleChange::EditedType(EditedType {
+            comments: stm.get_comments().get_doc_comments(),
+            attributes: stm.get_attributes().cloned().unwrap_or_default(),
+            type_type: self.type_type,
+            type_name: self.type_name.clone(),
+            is_abstract,
+            parent,
+        })])
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for EditedType<I> {
     fn parse(s: I) -> build_script_shared::error::ParserResult<I, Self> {
-        let (s, (comments, attributes, (type_type, type_name))) = context(
+        let (s, (comments, attributes, (is_abstract, type_type, type_name, parent))) = context(
             "Parsing EditedType",
             tuple((
                 Comments::parse,
                 Attributes::parse,
-                preceded(ws(char('*')), pair(SchemaStmType::parse, ws(Ident::ident))),
+                preceded(
+                    ws(char('*')),
+                    tuple((
+                        map(opt(ws(terminated(tag("abstract"), multispace1))), |abs| {
+                            abs.is_some()
+                        }),
+                        SchemaStmType::parse,
+                        ws(Ident::ident),
+                        opt(preceded(ws(char(':')), ws(Ident::ident))),
+                    )),
+                ),
             )),
         )(s)?;
 
@@ -85,6 +143,8 @@ impl<I: InputType> ParserDeserialize<I> for EditedType<I> {
                 attributes,
                 type_type,
                 type_name,
+                is_abstract,
+                parent,
             },
         ))
     }
@@ -102,9 +162,16 @@ impl<I> ParserSerialize for EditedType<I> {
         self.comments.compose(f, ctx)?;
         self.attributes.compose(f, ctx)?;
         write!(f, "{indents}* ")?;
+        if self.is_abstract {
+            write!(f, "abstract ")?;
+        }
         self.type_type.compose(f, new_ctx)?;
         write!(f, " ")?;
         self.type_name.compose(f, new_ctx)?;
+        if let Some(parent) = &self.parent {
+            write!(f, ": ")?;
+            parent.compose(f, new_ctx)?;
+        }
         Ok(())
     }
 }
@@ -4,7 +4,7 @@ use build_script_shared::compose_test;
 use build_script_shared::parsers::*;
 use build_script_shared::InputType;
 
-use crate::{ChangeSetError, ChangeSetResult};
+use crate::{ChangeSetError, ChangeSetResult, SingleChange};
 use build_script_lang::schema::*;
 use fake::Dummy;
 use nom::character::complete::*;
@@ -67,6 +67,31 @@ impl<I> EditedVariant<I> {
 
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Default + Clone + PartialEq,
+    {
+        let ty = schema.get_type(Some(SchemaStmType::Enum), &self.type_name);
+
+        let varient = match ty {
+            Some(SchemaStm::Enum(e)) => e.get_varient(&self.varient_name),
+            _ => None,
+        };
+
+        let varient = varient.ok_or_else(|| ChangeSetError::InvalidAction {
+            action: format!("invert edit varient"),
+            reason: format!("Failed to find {}.{}", self.type_name, self.varient_name),
+        })?;
+
+        Ok(vec![SingleChange::EditedVariant(EditedVariant {
+            type_name: self.type_name.clone(),
+            varient_name: self.varient_name.clone(),
+            comments: varient.comments().get_doc_comments(),
+            attributes: varient.attributes().clone(),
+        })])
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for EditedVariant<I> {
@@ -5,7 +5,7 @@ use build_script_shared::compose_test;
 use build_script_shared::parsers::*;
 use build_script_shared::InputType;
 
-use crate::{ChangeSetError, ChangeSetResult};
+use crate::{ChangeSetError, ChangeSetResult, SingleChange};
 use build_script_lang::schema::*;
 use fake::Dummy;
 use nom::character::complete::*;
@@ -70,6 +70,29 @@ impl<I> EditedVariantsOrder<I> {
 
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Default + Clone + PartialEq,
+    {
+        let ty = schema.get_type(Some(SchemaStmType::Enum), &self.type_name);
+
+        if let Some(SchemaStm::Enum(e)) = ty {
+            Ok(vec![SingleChange::EditedVariantsOrder(
+                EditedVariantsOrder {
+                    type_name: self.type_name.clone(),
+                    old_order: self.new_order.clone(),
+                    new_order: e.varients.iter().map(|v| v.name().clone()).collect(),
+                },
+            )])
+        } else {
+            Err(ChangeSetError::InvalidAction {
+                action: format!("invert edit varients order"),
+                reason: format!("Failed to find enum type {}", self.type_name),
+            })
+        }
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for EditedVariantsOrder<I> {
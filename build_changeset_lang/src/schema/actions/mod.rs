@@ -15,6 +15,9 @@ mod removed_endpoint;
 mod removed_field;
 mod removed_type;
 mod removed_varient;
+mod renamed_field;
+mod renamed_type;
+mod renamed_varient;
 mod single_change;
 mod edited_schema;
 
@@ -36,4 +39,7 @@ pub use removed_endpoint::*;
 pub use removed_field::*;
 pub use removed_type::*;
 pub use removed_varient::*;
+pub use renamed_field::*;
+pub use renamed_type::*;
+pub use renamed_varient::*;
 pub use single_change::*;
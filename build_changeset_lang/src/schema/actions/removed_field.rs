@@ -6,7 +6,7 @@ use build_script_shared::InputType;
 use fake::Dummy;
 
 use crate::FieldPath;
-use crate::{ChangeSetError, ChangeSetResult};
+use crate::{AddedField, ChangeSetError, ChangeSetResult, SingleChange};
 use build_script_lang::schema::*;
 use nom::character::complete::*;
 use nom::error::context;
@@ -49,6 +49,19 @@ impl<I> RemovedField<I> {
 
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Clone + Ord,
+    {
+        let field = self.field_path.get_field(schema)?;
+
+        Ok(vec![SingleChange::AddedField(AddedField::from_field(
+            self.field_path.clone(),
+            field,
+        ))])
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for RemovedField<I> {
@@ -6,14 +6,17 @@ use build_script_shared::parsers::Ident;
 use build_script_shared::parsers::*;
 use build_script_shared::InputType;
 
-use crate::{ChangeSetError, ChangeSetResult};
+use crate::{
+    AddedField, AddedType, AddedTypeData, AddedVarient, ChangeSetError, ChangeSetResult,
+    EditedGenerics, EditedType, FieldPath, SingleChange,
+};
 use build_script_lang::schema::*;
 use fake::Dummy;
 use nom::character::complete::*;
 use nom::error::context;
 use nom::sequence::*;
 
-/// "- (node|edge|struct|enum) \<ident\>"
+/// "- (node|edge|struct|enum|import|type|group) \<ident\>"
 #[derive(PartialEq, Eq, Debug, Clone, Hash, Dummy)]
 pub struct RemovedType<I> {
     pub type_type: SchemaStmType,
@@ -52,6 +55,110 @@ impl<I> RemovedType<I> {
             })?;
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    ///
+    /// The type is added back together with all of its content
+    pub fn invert(&self, schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Default + Clone + PartialEq,
+    {
+        let stm = schema
+            .get_type(Some(self.type_type), &self.type_name)
+            .ok_or_else(|| ChangeSetError::InvalidAction {
+                action: format!("invert remove {} {}", self.type_type, self.type_name),
+                reason: format!("no {} with that name exists", self.type_type),
+            })?;
+
+        let mut changes = vec![SingleChange::AddedType(AddedType {
+            comments: stm.get_comments().get_doc_comments(),
+            attributes: stm.get_attributes().cloned().unwrap_or_default(),
+            type_type: AddedTypeData::from_stm(stm),
+            type_name: self.type_name.clone(),
+        })];
+
+        let path = FieldPath::new(self.type_name.clone());
+        match stm {
+            SchemaStm::Node(NodeExp { fields, .. }) | SchemaStm::Edge(EdgeExp { fields, .. }) => {
+                for field in fields.iter() {
+                    changes.push(SingleChange::AddedField(AddedField::from_field(
+                        path.push(field.name.clone()),
+                        field,
+                    )));
+                }
+            }
+            SchemaStm::Struct(s) => {
+                for field in s.fields.iter() {
+                    changes.push(SingleChange::AddedField(AddedField::from_field(
+                        path.push(field.name.clone()),
+                        field,
+                    )));
+                }
+
+                if s.generics != Generics::default() {
+                    changes.push(SingleChange::EditedGenerics(EditedGenerics {
+                        type_name: self.type_name.clone(),
+                        old_generics: Generics::default(),
+                        new_generics: s.generics.clone(),
+                    }));
+                }
+            }
+            SchemaStm::Enum(e) => {
+                for (order, varient) in e.varients.iter().enumerate() {
+                    changes.extend(AddedVarient::from_varient(&self.type_name, varient, order));
+                }
+
+                if e.generics != Generics::default() {
+                    changes.push(SingleChange::EditedGenerics(EditedGenerics {
+                        type_name: self.type_name.clone(),
+                        old_generics: Generics::default(),
+                        new_generics: e.generics.clone(),
+                    }));
+                }
+            }
+            SchemaStm::Alias(a) => {
+                if a.generics != Generics::default() {
+                    changes.push(SingleChange::EditedGenerics(EditedGenerics {
+                        type_name: self.type_name.clone(),
+                        old_generics: Generics::default(),
+                        new_generics: a.generics.clone(),
+                    }));
+                }
+            }
+            // The members are part of the added type
+            SchemaStm::Group(_) => (),
+            SchemaStm::Import(i) => {
+                // Imports are added without comments
+                let comments = i.comments.get_doc_comments();
+                if comments != Comments::default() {
+                    changes.push(SingleChange::EditedType(EditedType {
+                        comments,
+                        attributes: Attributes::default(),
+                        type_type: SchemaStmType::Import,
+                        type_name: self.type_name.clone(),
+                        is_abstract: false,
+                        parent: None,
+                    }));
+                }
+            }
+        }
+
+        // Nodes are added as concrete nodes without a parent
+        if let SchemaStm::Node(n) = stm {
+            if n.is_abstract || n.parent.is_some() {
+                changes.push(SingleChange::EditedType(EditedType {
+                    comments: n.comments.get_doc_comments(),
+                    attributes: n.attributes.clone(),
+                    type_type: SchemaStmType::Node,
+                    type_name: self.type_name.clone(),
+                    is_abstract: n.is_abstract,
+                    parent: n.parent.clone(),
+                }));
+            }
+        }
+
+        Ok(changes)
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for RemovedType<I> {
@@ -6,7 +6,7 @@ use build_script_shared::parsers::Ident;
 use build_script_shared::parsers::*;
 use build_script_shared::InputType;
 
-use crate::{ChangeSetError, ChangeSetResult};
+use crate::{AddedVarient, ChangeSetError, ChangeSetResult, SingleChange};
 use build_script_lang::schema::*;
 use fake::Dummy;
 use nom::bytes::complete::*;
@@ -57,6 +57,34 @@ impl<I> RemovedVarient<I> {
 
         Ok(())
     }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Default + Clone + PartialEq,
+    {
+        let enum_stm = schema.get_type(Some(SchemaStmType::Enum), &self.type_name);
+
+        if let Some(SchemaStm::Enum(e)) = enum_stm {
+            let order = e.varient_position(&self.varient_name).ok_or_else(|| {
+                ChangeSetError::InvalidAction {
+                    action: format!("invert remove varient"),
+                    reason: format!("no varient named {} exists", self.varient_name),
+                }
+            })?;
+
+            Ok(AddedVarient::from_varient(
+                &self.type_name,
+                &e.varients[order],
+                order,
+            ))
+        } else {
+            Err(ChangeSetError::InvalidAction {
+                action: format!("invert remove varient"),
+                reason: format!("no enum type named {} exists", self.type_name),
+            })
+        }
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for RemovedVarient<I> {
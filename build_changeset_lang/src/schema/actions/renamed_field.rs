@@ -0,0 +1,151 @@
+use std::fmt::Display;
+
+use build_script_shared::compose_test;
+use build_script_shared::parsers::*;
+use build_script_shared::InputType;
+use fake::Dummy;
+
+use crate::FieldPath;
+use crate::{ChangeSetError, ChangeSetResult, SingleChange};
+use build_script_lang::schema::*;
+use nom::character::complete::*;
+use nom::error::context;
+use nom::sequence::*;
+
+/// "~ \<ident\>.\<ident\> => \<ident\>"
+#[derive(PartialEq, Eq, Debug, Clone, Hash, Dummy)]
+pub struct RenamedField<I> {
+    pub(crate) field_path: FieldPath<I>,
+    pub(crate) new_name: Ident<I>,
+}
+
+impl<I> RenamedField<I> {
+    /// The name of the field before it was renamed
+    pub fn old_name(&self) -> Option<&Ident<I>> {
+        self.field_path.get_field_name()
+    }
+
+    /// The name of the field after it was renamed
+    pub fn new_name(&self) -> &Ident<I> {
+        &self.new_name
+    }
+
+    /// Check if the path points at the field either before or after it was renamed
+    pub fn is_renamed_path(&self, path: &FieldPath<I>) -> bool
+    where
+        I: PartialEq,
+    {
+        if &self.field_path == path {
+            return true;
+        }
+
+        match (self.field_path.path.split_last(), path.path.split_last()) {
+            (Some((_, old_parents)), Some((name, parents))) => {
+                self.field_path.root == path.root && old_parents == parents && name == &self.new_name
+            }
+            _ => false,
+        }
+    }
+
+    /// Move from one input type to another
+    pub fn map<O, F>(self, f: F) -> RenamedField<O>
+    where
+        F: Fn(I) -> O + Copy,
+    {
+        RenamedField {
+            field_path: self.field_path.map(f),
+            new_name: self.new_name.map(f),
+        }
+    }
+
+    pub fn apply(&self, schema: &mut Schema<I>) -> ChangeSetResult<()>
+    where
+        I: Default + Clone + PartialEq + Ord,
+    {
+        let named_fields = self.field_path.retrieve_field(schema)?;
+
+        let named_key = self.field_path.get_field_name_res()?;
+
+        if named_fields.has_field(self.new_name.as_str()) {
+            return Err(ChangeSetError::InvalidAction {
+                action: format!("rename field"),
+                reason: format!(
+                    "field {} already exists next to {}",
+                    self.new_name, self.field_path
+                ),
+            });
+        }
+
+        let field = named_fields
+            .get_field_mut(named_key.as_str())
+            .ok_or_else(|| ChangeSetError::InvalidAction {
+                action: format!("rename field"),
+                reason: format!("field at {} did not exists", self.field_path),
+            })?;
+
+        field.name = self.new_name.clone();
+
+        Ok(())
+    }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, _schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Clone,
+    {
+        let old_name = self.field_path.get_field_name_res()?;
+        let mut field_path = self.field_path.clone();
+        field_path.path.pop();
+
+        Ok(vec![SingleChange::RenamedField(RenamedField {
+            field_path: field_path.push(self.new_name.clone()),
+            new_name: old_name.clone(),
+        })])
+    }
+}
+
+impl<I: InputType> ParserDeserialize<I> for RenamedField<I> {
+    fn parse(s: I) -> build_script_shared::error::ParserResult<I, Self> {
+        let (s, (field_path, new_name)) = context(
+            "Parsing RenamedField",
+            preceded(
+                ws(char('~')),
+                key_value(FieldPath::parse, pair(char('='), char('>')), Ident::ident),
+            ),
+        )(s)?;
+
+        Ok((
+            s,
+            RenamedField {
+                field_path,
+                new_name,
+            },
+        ))
+    }
+}
+
+impl<I> ParserSerialize for RenamedField<I> {
+    fn compose<W: std::fmt::Write>(
+        &self,
+        f: &mut W,
+        ctx: ComposeContext,
+    ) -> build_script_shared::error::ComposerResult<()> {
+        let indents = ctx.create_indents();
+        let new_ctx = ctx.set_indents(0);
+
+        write!(f, "{indents}~ ")?;
+        self.field_path.compose(f, new_ctx)?;
+        write!(f, " => ")?;
+        self.new_name.compose(f, new_ctx)?;
+        Ok(())
+    }
+}
+
+impl<I> Display for RenamedField<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ser = self.serialize_to_string().map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", ser)
+    }
+}
+
+compose_test! {renamed_field_compose, RenamedField<I>}
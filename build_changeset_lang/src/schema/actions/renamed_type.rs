@@ -0,0 +1,202 @@
+use std::fmt::Display;
+
+use build_script_lang::schema::*;
+use build_script_shared::compose_test;
+use build_script_shared::parsers::*;
+use build_script_shared::InputType;
+
+use crate::{ChangeSetError, ChangeSetResult, SingleChange};
+use fake::Dummy;
+use nom::character::complete::*;
+use nom::error::context;
+use nom::sequence::*;
+
+/// "~ (node|edge|struct|enum|import|type) \<ident\> => \<ident\>"
+#[derive(PartialEq, Eq, Debug, Clone, Hash, Dummy)]
+pub struct RenamedType<I> {
+    pub type_type: SchemaStmType,
+    pub old_name: Ident<I>,
+    pub new_name: Ident<I>,
+}
+
+impl<I> RenamedType<I> {
+    /// Move from one input type to another
+    pub fn map<O, F>(self, f: F) -> RenamedType<O>
+    where
+        F: Fn(I) -> O + Copy,
+    {
+        RenamedType {
+            type_type: self.type_type,
+            old_name: self.old_name.map(f),
+            new_name: self.new_name.map(f),
+        }
+    }
+
+    pub fn apply(&self, schema: &mut Schema<I>) -> ChangeSetResult<()>
+    where
+        I: Default + Clone + PartialEq + Ord,
+    {
+        let action = format!(
+            "rename {} {} to {}",
+            self.type_type, self.old_name, self.new_name
+        );
+
+        if schema.get_type(None, &self.new_name).is_some() {
+            return Err(ChangeSetError::InvalidAction {
+                action,
+                reason: format!("a type named {} already exists", self.new_name),
+            });
+        }
+
+        let stm = schema
+            .get_type_mut(Some(self.type_type), &self.old_name)
+            .ok_or_else(|| ChangeSetError::InvalidAction {
+                action: action.clone(),
+                reason: format!("no {} with that name exists", self.type_type),
+            })?;
+
+        *stm.get_type_mut() = self.new_name.clone();
+
+        // Make sure everything pointing at the old name now points at the new one
+        for stm in schema.iter_mut() {
+            match stm {
+                SchemaStm::Node(n) => {
+                    self.rename_fields(&mut n.fields);
+                    n.parent = n.parent.take().map(|parent| self.rename_ident(parent));
+                }
+                SchemaStm::Struct(s) => self.rename_fields(&mut s.fields),
+                SchemaStm::Edge(e) => {
+                    self.rename_fields(&mut e.fields);
+
+                    let endpoints = std::mem::take(&mut e.endpoints);
+                    e.endpoints = endpoints
+                        .into_values()
+                        .map(|mut endpoint| {
+                            endpoint.source = self.rename_ident(endpoint.source);
+                            endpoint.target = self.rename_ident(endpoint.target);
+                            (
+                                (endpoint.source.clone(), endpoint.target.clone()),
+                                endpoint,
+                            )
+                        })
+                        .collect();
+                }
+                SchemaStm::Enum(e) => {
+                    for varient in &mut e.varients {
+                        match varient {
+                            EnumVarient::Struct { fields, .. } => self.rename_fields(fields),
+                            EnumVarient::Opaque { ty, .. } => {
+                                *ty = ty.clone().map_reference(|r| self.rename_ident(r))
+                            }
+                            EnumVarient::Unit { .. } => (),
+                        }
+                    }
+                }
+                SchemaStm::Alias(a) => {
+                    a.ty = a.ty.clone().map_reference(|r| self.rename_ident(r))
+                }
+                SchemaStm::Group(g) => {
+                    let members = std::mem::take(&mut g.members);
+                    g.members = members
+                        .into_iter()
+                        .map(|member| self.rename_ident(member))
+                        .collect();
+                }
+                SchemaStm::Import(_) => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, _schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Clone,
+    {
+        Ok(vec![SingleChange::RenamedType(RenamedType {
+            type_type: self.type_type,
+            old_name: self.new_name.clone(),
+            new_name: self.old_name.clone(),
+        })])
+    }
+
+    fn rename_ident(&self, ident: Ident<I>) -> Ident<I>
+    where
+        I: Clone,
+    {
+        if ident == self.old_name {
+            self.new_name.clone()
+        } else {
+            ident
+        }
+    }
+
+    fn rename_fields(&self, fields: &mut Fields<I>)
+    where
+        I: Clone,
+    {
+        for field in fields.iter_mut() {
+            field.field_type = field
+                .field_type
+                .clone()
+                .map_reference(|r| self.rename_ident(r));
+        }
+    }
+}
+
+impl<I: InputType> ParserDeserialize<I> for RenamedType<I> {
+    fn parse(s: I) -> build_script_shared::error::ParserResult<I, Self> {
+        let (s, (type_type, (old_name, new_name))) = context(
+            "Parsing RenamedType",
+            preceded(
+                ws(char('~')),
+                pair(
+                    SchemaStmType::parse,
+                    ws(key_value(
+                        Ident::ident,
+                        pair(char('='), char('>')),
+                        Ident::ident,
+                    )),
+                ),
+            ),
+        )(s)?;
+
+        Ok((
+            s,
+            RenamedType {
+                type_type,
+                old_name,
+                new_name,
+            },
+        ))
+    }
+}
+
+impl<I> ParserSerialize for RenamedType<I> {
+    fn compose<W: std::fmt::Write>(
+        &self,
+        f: &mut W,
+        ctx: ComposeContext,
+    ) -> build_script_shared::error::ComposerResult<()> {
+        let indents = ctx.create_indents();
+        let new_ctx = ctx.set_indents(0);
+
+        write!(f, "{indents}~ ")?;
+        self.type_type.compose(f, new_ctx)?;
+        write!(f, " ")?;
+        self.old_name.compose(f, new_ctx)?;
+        write!(f, " => ")?;
+        self.new_name.compose(f, new_ctx)?;
+        Ok(())
+    }
+}
+
+impl<I> Display for RenamedType<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ser = self.serialize_to_string().map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", ser)
+    }
+}
+
+compose_test! {renamed_type_compose, RenamedType<I>}
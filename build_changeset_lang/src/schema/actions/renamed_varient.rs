@@ -0,0 +1,135 @@
+use std::fmt::Display;
+
+use build_script_lang::schema::*;
+use build_script_shared::compose_test;
+use build_script_shared::parsers::*;
+use build_script_shared::InputType;
+
+use crate::{ChangeSetError, ChangeSetResult, SingleChange};
+use fake::Dummy;
+use nom::bytes::complete::*;
+use nom::character::complete::*;
+use nom::error::context;
+use nom::sequence::*;
+
+/// "~ enum \<ident\>.\<ident\> => \<ident\>"
+#[derive(PartialEq, Eq, Debug, Clone, Hash, Dummy)]
+pub struct RenamedVarient<I> {
+    pub type_name: Ident<I>,
+    pub old_name: Ident<I>,
+    pub new_name: Ident<I>,
+}
+
+impl<I> RenamedVarient<I> {
+    /// Move from one input type to another
+    pub fn map<O, F>(self, f: F) -> RenamedVarient<O>
+    where
+        F: Fn(I) -> O + Copy,
+    {
+        RenamedVarient {
+            type_name: self.type_name.map(f),
+            old_name: self.old_name.map(f),
+            new_name: self.new_name.map(f),
+        }
+    }
+
+    pub fn apply(&self, schema: &mut Schema<I>) -> ChangeSetResult<()>
+    where
+        I: Default + Clone + PartialEq,
+    {
+        let enum_stm = schema
+            .get_type_mut(Some(SchemaStmType::Enum), &self.type_name)
+            .ok_or_else(|| ChangeSetError::InvalidAction {
+                action: format!("rename varient"),
+                reason: format!("no enum type named {} exists", self.type_name),
+            })?;
+
+        if let SchemaStm::Enum(e) = enum_stm {
+            if e.has_varient(&self.new_name) {
+                return Err(ChangeSetError::InvalidAction {
+                    action: format!("rename varient"),
+                    reason: format!("varient {} already exists", self.new_name),
+                });
+            }
+
+            let varient = e.get_varient_mut(&self.old_name).ok_or_else(|| {
+                ChangeSetError::InvalidAction {
+                    action: format!("rename varient"),
+                    reason: format!("no varient named {} exists", self.old_name),
+                }
+            })?;
+
+            *varient.name_mut() = self.new_name.clone();
+        }
+
+        Ok(())
+    }
+
+    /// Create the changes undoing this change
+    pub fn invert(&self, _schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Clone,
+    {
+        Ok(vec![SingleChange::RenamedVarient(RenamedVarient {
+            type_name: self.type_name.clone(),
+            old_name: self.new_name.clone(),
+            new_name: self.old_name.clone(),
+        })])
+    }
+}
+
+impl<I: InputType> ParserDeserialize<I> for RenamedVarient<I> {
+    fn parse(s: I) -> build_script_shared::error::ParserResult<I, Self> {
+        let (s, ((type_name, old_name), new_name)) = context(
+            "Parsing RenamedVarient",
+            preceded(
+                ws(char('~')),
+                preceded(
+                    tag("enum"),
+                    ws(key_value(
+                        separated_pair(Ident::ident, char('.'), Ident::ident),
+                        pair(char('='), char('>')),
+                        Ident::ident,
+                    )),
+                ),
+            ),
+        )(s)?;
+
+        Ok((
+            s,
+            RenamedVarient {
+                type_name,
+                old_name,
+                new_name,
+            },
+        ))
+    }
+}
+
+impl<I> ParserSerialize for RenamedVarient<I> {
+    fn compose<W: std::fmt::Write>(
+        &self,
+        f: &mut W,
+        ctx: ComposeContext,
+    ) -> build_script_shared::error::ComposerResult<()> {
+        let indents = ctx.create_indents();
+        let new_ctx = ctx.set_indents(0);
+
+        write!(f, "{indents}~ enum ")?;
+        self.type_name.compose(f, new_ctx)?;
+        write!(f, ".")?;
+        self.old_name.compose(f, new_ctx)?;
+        write!(f, " => ")?;
+        self.new_name.compose(f, new_ctx)?;
+        Ok(())
+    }
+}
+
+impl<I> Display for RenamedVarient<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ser = self.serialize_to_string().map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", ser)
+    }
+}
+
+compose_test! {renamed_varient_compose, RenamedVarient<I>}
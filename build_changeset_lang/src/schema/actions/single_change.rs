@@ -1,4 +1,4 @@
-use build_script_lang::schema::Schema;
+use build_script_lang::schema::{Schema, SchemaStm, SchemaStmType};
 use build_script_shared::compose_test;
 use build_script_shared::error::ParserSlimResult;
 use build_script_shared::InputType;
@@ -18,7 +18,7 @@ use crate::*;
 
 /// Represent a single change in the schema
 ///
-/// Changes are seperated into add, edit and remove events
+/// Changes are seperated into add, edit, rename and remove events
 ///
 /// This makes it easier to see what actually changed
 #[derive(PartialEq, Eq, Debug, Clone, Hash, Dummy)]
@@ -31,6 +31,9 @@ pub enum SingleChange<I> {
     RemovedVarient(RemovedVarient<I>),
     RemovedEndpoint(RemovedEndpoint<I>),
     RemovedField(RemovedField<I>),
+    RenamedType(RenamedType<I>),
+    RenamedField(RenamedField<I>),
+    RenamedVarient(RenamedVarient<I>),
     EditedSchema(EditedSchema),
     EditedFieldType(EditedField<I>),
     EditedOpaque(EditedOpaque<I>),
@@ -56,6 +59,9 @@ impl<I> SingleChange<I> {
             SingleChange::RemovedVarient(s) => SingleChange::RemovedVarient(s.map(f)),
             SingleChange::RemovedField(s) => SingleChange::RemovedField(s.map(f)),
             SingleChange::RemovedEndpoint(s) => SingleChange::RemovedEndpoint(s.map(f)),
+            SingleChange::RenamedType(s) => SingleChange::RenamedType(s.map(f)),
+            SingleChange::RenamedField(s) => SingleChange::RenamedField(s.map(f)),
+            SingleChange::RenamedVarient(s) => SingleChange::RenamedVarient(s.map(f)),
             SingleChange::EditedSchema(s) => SingleChange::EditedSchema(s),
             SingleChange::EditedFieldType(s) => SingleChange::EditedFieldType(s.map(f)),
             SingleChange::EditedOpaque(s) => SingleChange::EditedOpaque(s.map(f)),
@@ -80,6 +86,9 @@ impl<I> SingleChange<I> {
             SingleChange::RemovedType(s) => s.apply(schema),
             SingleChange::RemovedVarient(s) => s.apply(schema),
             SingleChange::RemovedField(s) => s.apply(schema),
+            SingleChange::RenamedType(s) => s.apply(schema),
+            SingleChange::RenamedField(s) => s.apply(schema),
+            SingleChange::RenamedVarient(s) => s.apply(schema),
             SingleChange::EditedSchema(s) => s.apply(schema),
             SingleChange::EditedFieldType(s) => s.apply(schema),
             SingleChange::RemovedEndpoint(s) => s.apply(schema),
@@ -92,6 +101,186 @@ impl<I> SingleChange<I> {
         }
     }
 
+    /// Create the changes undoing this change
+    ///
+    /// Most changes only describe what the schema looks like afterwards,
+    /// so the schema has to be the one the change is about to be applied to
+    pub fn invert(&self, schema: &Schema<I>) -> ChangeSetResult<Vec<SingleChange<I>>>
+    where
+        I: Default + Clone + PartialEq + Ord,
+    {
+        match self {
+            SingleChange::AddedType(s) => s.invert(schema),
+            SingleChange::AddedVarient(s) => s.invert(schema),
+            SingleChange::AddedField(s) => s.invert(schema),
+            SingleChange::AddedEndpoint(s) => s.invert(schema),
+            SingleChange::RemovedType(s) => s.invert(schema),
+            SingleChange::RemovedVarient(s) => s.invert(schema),
+            SingleChange::RemovedField(s) => s.invert(schema),
+            SingleChange::RenamedType(s) => s.invert(schema),
+            SingleChange::RenamedField(s) => s.invert(schema),
+            SingleChange::RenamedVarient(s) => s.invert(schema),
+            SingleChange::EditedSchema(s) => s.invert(schema),
+            SingleChange::EditedFieldType(s) => s.invert(schema),
+            SingleChange::RemovedEndpoint(s) => s.invert(schema),
+            SingleChange::EditedOpaque(s) => s.invert(schema),
+            SingleChange::EditedType(s) => s.invert(schema),
+            SingleChange::EditedVariantsOrder(s) => s.invert(schema),
+            SingleChange::EditedEndpoint(s) => s.invert(schema),
+            SingleChange::EditedGenerics(s) => s.invert(schema),
+            SingleChange::EditedVariant(s) => s.invert(schema),
+        }
+    }
+
+    /// Describe the data which is lost when migrating data through the change
+    ///
+    /// The schema is the one the change is about to be applied to
+    pub fn get_data_loss(&self, schema: &Schema<I>) -> Option<String>
+    where
+        I: Default + Clone + PartialEq + Ord,
+    {
+        match self {
+            SingleChange::RemovedType(t)
+                if matches!(t.type_type, SchemaStmType::Node | SchemaStmType::Edge) =>
+            {
+                Some(format!("all instances of {} are dropped", t.type_name))
+            }
+            SingleChange::RemovedField(f) => {
+                Some(format!("the data in {} is dropped", f.field_path))
+            }
+            SingleChange::RemovedVarient(v) => Some(format!(
+                "values using {}.{} can not be migrated",
+                v.type_name, v.varient_name
+            )),
+            SingleChange::RemovedEndpoint(e) => Some(format!(
+                "all {} going from {} to {} are dropped",
+                e.type_name, e.endpoint.source, e.endpoint.target
+            )),
+            SingleChange::EditedFieldType(f)
+                if f.fill.is_none() && !f.old_type.check_convertion(&f.new_type) =>
+            {
+                Some(format!(
+                    "{} can not be converted from {} to {}",
+                    f.field_path, f.old_type, f.new_type
+                ))
+            }
+            SingleChange::EditedOpaque(o) if !o.old_type.check_convertion(&o.new_type) => {
+                Some(format!(
+                    "{} can not be converted from {} to {}",
+                    o.field_path, o.old_type, o.new_type
+                ))
+            }
+            SingleChange::EditedEndpoint(e) => {
+                let key = (e.endpoint.source.clone(), e.endpoint.target.clone());
+                let old_endpoint = match schema.get_type(Some(SchemaStmType::Edge), &e.type_name) {
+                    Some(SchemaStm::Edge(edge)) => edge.endpoints.get(&key),
+                    _ => None,
+                }?;
+
+                let is_contained = e
+                    .endpoint
+                    .incoming_quantity
+                    .contains(&old_endpoint.incoming_quantity)
+                    && e.endpoint
+                        .outgoing_quantity
+                        .contains(&old_endpoint.outgoing_quantity);
+
+                if is_contained {
+                    None
+                } else {
+                    Some(format!(
+                        "{} going from {} to {} may exceed the new quantity",
+                        e.type_name, e.endpoint.source, e.endpoint.target
+                    ))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Find out if data is able to move between the versions before and after this change
+    ///
+    /// The schema is the one before the change is applied
+    pub fn get_compatibility(&self, schema: &Schema<I>) -> Compatibility
+    where
+        I: Default + Clone + PartialEq + Ord,
+    {
+        match self {
+            // Old versions does not know how to read the new nodes and edges
+            SingleChange::AddedType(t) => match t.type_type {
+                AddedTypeData::Node | AddedTypeData::Edge { .. } => Compatibility::Backward,
+                _ => Compatibility::Safe,
+            },
+            SingleChange::RemovedType(t) => match t.type_type {
+                SchemaStmType::Node | SchemaStmType::Edge => Compatibility::Forward,
+                _ => Compatibility::Safe,
+            },
+            SingleChange::AddedVarient(_) | SingleChange::AddedEndpoint(_) => {
+                Compatibility::Backward
+            }
+            SingleChange::RemovedVarient(_) | SingleChange::RemovedEndpoint(_) => {
+                Compatibility::Forward
+            }
+            // Data missing a field can only be read if the field is optional
+            SingleChange::AddedField(f) => {
+                let is_optional =
+                    f.default.is_some() || matches!(f.field_type, Types::Option { .. });
+                Compatibility::new(is_optional, true)
+            }
+            SingleChange::RemovedField(f) => {
+                let is_optional = match f.field_path.get_field(schema) {
+                    Ok(field) => {
+                        field.default.is_some() || matches!(field.field_type, Types::Option { .. })
+                    }
+                    Err(_) => false,
+                };
+                Compatibility::new(true, is_optional)
+            }
+            // Renaming changes how the data is stored
+            SingleChange::RenamedType(_)
+            | SingleChange::RenamedField(_)
+            | SingleChange::RenamedVarient(_)
+            | SingleChange::EditedGenerics(_) => Compatibility::Breaking,
+            SingleChange::EditedFieldType(f) => Compatibility::new(
+                f.old_type.check_convertion(&f.new_type),
+                f.new_type.check_convertion(&f.old_type),
+            ),
+            SingleChange::EditedOpaque(o) => Compatibility::new(
+                o.old_type.check_convertion(&o.new_type),
+                o.new_type.check_convertion(&o.old_type),
+            ),
+            SingleChange::EditedEndpoint(e) => {
+                let key = (e.endpoint.source.clone(), e.endpoint.target.clone());
+                let old_endpoint = match schema.get_type(Some(SchemaStmType::Edge), &e.type_name) {
+                    Some(SchemaStm::Edge(edge)) => edge.endpoints.get(&key),
+                    _ => None,
+                };
+
+                match old_endpoint {
+                    Some(old_endpoint) => Compatibility::new(
+                        e.endpoint
+                            .incoming_quantity
+                            .contains(&old_endpoint.incoming_quantity)
+                            && e.endpoint
+                                .outgoing_quantity
+                                .contains(&old_endpoint.outgoing_quantity),
+                        old_endpoint
+                            .incoming_quantity
+                            .contains(&e.endpoint.incoming_quantity)
+                            && old_endpoint
+                                .outgoing_quantity
+                                .contains(&e.endpoint.outgoing_quantity),
+                    ),
+                    None => Compatibility::Breaking,
+                }
+            }
+            SingleChange::EditedSchema(_)
+            | SingleChange::EditedType(_)
+            | SingleChange::EditedVariantsOrder(_)
+            | SingleChange::EditedVariant(_) => Compatibility::Safe,
+        }
+    }
+
     pub fn check_convertion_res(&self) -> ParserSlimResult<I, ()>
     where
         I: Clone,
@@ -102,6 +291,81 @@ impl<I> SingleChange<I> {
             _ => Ok(()),
         }
     }
+
+    /// Retrieve the path of the type, varient or field this change targets
+    ///
+    /// Renames target the old name and endpoint changes target the edge type
+    pub fn get_target(&self) -> Option<FieldPath<I>>
+    where
+        I: Clone,
+    {
+        let target = match self {
+            SingleChange::AddedType(t) => FieldPath::new(t.type_name.clone()),
+            SingleChange::EditedType(t) => FieldPath::new(t.type_name.clone()),
+            SingleChange::RemovedType(t) => FieldPath::new(t.type_name.clone()),
+            SingleChange::RenamedType(t) => FieldPath::new(t.old_name.clone()),
+            SingleChange::EditedGenerics(t) => FieldPath::new(t.type_name.clone()),
+            SingleChange::EditedVariantsOrder(t) => FieldPath::new(t.type_name.clone()),
+            SingleChange::AddedEndpoint(t) => FieldPath::new(t.type_name.clone()),
+            SingleChange::EditedEndpoint(t) => FieldPath::new(t.type_name.clone()),
+            SingleChange::RemovedEndpoint(t) => FieldPath::new(t.type_name.clone()),
+            SingleChange::AddedVarient(t) => {
+                FieldPath::new_path(t.type_name.clone(), vec![t.varient_name.clone()])
+            }
+            SingleChange::EditedVariant(t) => {
+                FieldPath::new_path(t.type_name.clone(), vec![t.varient_name.clone()])
+            }
+            SingleChange::RemovedVarient(t) => {
+                FieldPath::new_path(t.type_name.clone(), vec![t.varient_name.clone()])
+            }
+            SingleChange::RenamedVarient(t) => {
+                FieldPath::new_path(t.type_name.clone(), vec![t.old_name.clone()])
+            }
+            SingleChange::AddedField(t) => t.field_path.clone(),
+            SingleChange::EditedFieldType(t) => t.field_path.clone(),
+            SingleChange::EditedOpaque(t) => t.field_path.clone(),
+            SingleChange::RemovedField(t) => t.field_path.clone(),
+            SingleChange::RenamedField(t) => t.field_path.clone(),
+            SingleChange::EditedSchema(_) => return None,
+        };
+
+        Some(target)
+    }
+
+    /// Update the path to the name it had before this change was applied
+    ///
+    /// Returns true if the change renamed any part of the path
+    pub(crate) fn rename_back(&self, path: &mut FieldPath<I>) -> bool
+    where
+        I: Clone + PartialEq,
+    {
+        match self {
+            SingleChange::RenamedType(r) if path.root == r.new_name => {
+                path.root = r.old_name.clone();
+                true
+            }
+            SingleChange::RenamedVarient(r)
+                if path.root == r.type_name && path.path.first() == Some(&r.new_name) =>
+            {
+                path.path[0] = r.old_name.clone();
+                true
+            }
+            SingleChange::RenamedField(r) => {
+                let depth = r.field_path.path.len();
+                let is_renamed = depth != 0
+                    && r.field_path.root == path.root
+                    && path.path.len() >= depth
+                    && path.path[..depth - 1] == r.field_path.path[..depth - 1]
+                    && path.path[depth - 1] == r.new_name;
+
+                if is_renamed {
+                    path.path[depth - 1] = r.field_path.path[depth - 1].clone();
+                }
+                is_renamed
+            }
+            _ => false,
+        }
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for SingleChange<I> {
@@ -119,6 +383,9 @@ impl<I: InputType> ParserDeserialize<I> for SingleChange<I> {
                     map(RemovedVarient::parse, SingleChange::RemovedVarient),
                     map(RemovedEndpoint::parse, SingleChange::RemovedEndpoint),
                     map(RemovedType::parse, SingleChange::RemovedType),
+                    map(RenamedVarient::parse, SingleChange::RenamedVarient),
+                    map(RenamedField::parse, SingleChange::RenamedField),
+                    map(RenamedType::parse, SingleChange::RenamedType),
                     map(EditedSchema::parse, SingleChange::EditedSchema),
                     map(EditedOpaque::parse, SingleChange::EditedOpaque),
                     map(EditedField::parse, SingleChange::EditedFieldType),
@@ -156,6 +423,9 @@ impl<I> ParserSerialize for SingleChange<I> {
             SingleChange::RemovedVarient(s) => s.compose(f, ctx),
             SingleChange::RemovedField(s) => s.compose(f, ctx),
             SingleChange::RemovedType(s) => s.compose(f, ctx),
+            SingleChange::RenamedVarient(s) => s.compose(f, ctx),
+            SingleChange::RenamedField(s) => s.compose(f, ctx),
+            SingleChange::RenamedType(s) => s.compose(f, ctx),
             SingleChange::EditedSchema(s) => s.compose(f, ctx),
             SingleChange::EditedOpaque(s) => s.compose(f, ctx),
             SingleChange::EditedFieldType(s) => s.compose(f, ctx),
@@ -182,6 +452,9 @@ impl<I> Display for SingleChange<I> {
             SingleChange::RemovedVarient(s) => write!(f, "{}", s),
             SingleChange::RemovedField(s) => write!(f, "{}", s),
             SingleChange::RemovedEndpoint(s) => write!(f, "{}", s),
+            SingleChange::RenamedType(s) => write!(f, "{}", s),
+            SingleChange::RenamedField(s) => write!(f, "{}", s),
+            SingleChange::RenamedVarient(s) => write!(f, "{}", s),
             SingleChange::EditedSchema(s) => write!(f, "{}", s),
             SingleChange::EditedType(s) => write!(f, "{}", s),
             SingleChange::EditedVariantsOrder(s) => write!(f, "{}", s),
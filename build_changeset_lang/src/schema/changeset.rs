@@ -1,33 +1,49 @@
-use super::SingleChange;
-use crate::{ChangeSetResult, FieldPath};
-use build_script_lang::schema::Schema;
+use super::*;
+use crate::traits::ChangeSetBuilder;
+use crate::ChangeSetResult;
+use build_script_lang::schema::{EdgeExp, EndPoint, Schema, SchemaStm, SchemaStmType};
 use build_script_shared::error::{ParserResult, ParserSlimResult};
 use build_script_shared::parsers::*;
-use build_script_shared::{compose_test, InputMarkerRef, InputType};
+use build_script_shared::{compose_test, stable_hash, InputMarkerRef, InputType, HASH_VERSION};
 use fake::Dummy;
 use nom::bytes::complete::tag;
 use nom::character::complete::*;
-use nom::combinator::{eof, opt};
+use nom::combinator::{eof, map, opt};
 use nom::error::context;
 use nom::multi::*;
 use nom::sequence::*;
-use std::collections::hash_map::DefaultHasher;
 use std::fmt::Debug;
 use std::fmt::Display;
-use std::hash::{Hash, Hasher};
+use std::hash::Hash;
 
 pub type DefaultChangeset<'a> = ChangeSet<InputMarkerRef<'a>>;
 
-#[derive(PartialEq, Eq, Debug, Hash, Default, Dummy)]
+#[derive(PartialEq, Eq, Debug, Clone, Hash, Default, Dummy)]
 pub struct ChangeSet<I> {
     pub new_version: Ident<I>,
     pub old_version: Ident<I>,
     pub handler: Option<Ident<I>>,
     pub new_hash: u64,
     pub old_hash: u64,
+    /// Version of the algorithm used to create the hashes
+    pub hash_version: u32,
     pub changes: Vec<SingleChange<I>>,
 }
 
+/// A change in an inverted changeset which is not able to restore all data
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct LossyChange<I> {
+    pub change: SingleChange<I>,
+    pub reason: String,
+}
+
+/// A change and how it affects data moving between versions
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ClassifiedChange<I> {
+    pub change: SingleChange<I>,
+    pub compatibility: Compatibility,
+}
+
 impl<I> ChangeSet<I> {
     pub fn new() -> ChangeSet<I>
     where
@@ -39,6 +55,7 @@ impl<I> ChangeSet<I> {
             handler: None,
             old_hash: 0,
             new_hash: 0,
+            hash_version: HASH_VERSION,
             changes: Vec::new(),
         }
     }
@@ -61,6 +78,7 @@ impl<I> ChangeSet<I> {
         ChangeSet {
             old_hash: self.old_hash,
             new_hash: self.new_hash,
+            hash_version: self.hash_version,
             handler: self.handler.map(|i| i.map(f)),
             old_version: self.old_version.map(f),
             new_version: self.new_version.map(f),
@@ -104,7 +122,7 @@ impl<I> ChangeSet<I> {
                             .path
                             .get(0)
                             .map(|n| n == &f.varient_name)
-                            .unwrap_or_else(|| true);
+                            .unwrap_or(true);
 
                     if is_changed {
                         changes.push(change)
@@ -172,7 +190,32 @@ impl<I> ChangeSet<I> {
                             .path
                             .get(0)
                             .map(|n| n == &f.varient_name)
-                            .unwrap_or_else(|| true);
+                            .unwrap_or(true);
+
+                    if is_changed {
+                        changes.push(change)
+                    }
+                }
+                SingleChange::RenamedType(f) => {
+                    if f.old_name == path.root || f.new_name == path.root {
+                        changes.push(change)
+                    }
+                }
+                SingleChange::RenamedField(f) => {
+                    let is_changed = f.is_renamed_path(&path)
+                        || path.path.is_empty() && f.field_path.root == path.root;
+
+                    if is_changed {
+                        changes.push(change)
+                    }
+                }
+                SingleChange::RenamedVarient(f) => {
+                    let is_changed = f.type_name == path.root
+                        && path
+                            .path
+                            .first()
+                            .map(|n| n == &f.old_name || n == &f.new_name)
+                            .unwrap_or(true);
 
                     if is_changed {
                         changes.push(change)
@@ -184,6 +227,299 @@ impl<I> ChangeSet<I> {
         changes
     }
 
+    /// Find the name a type had before this changeset was applied
+    ///
+    /// Composed changesets may rename the same type several times, so the renames are followed backwards
+    pub fn get_old_type_name<'a>(&'a self, new_name: &'a Ident<I>) -> &'a Ident<I>
+    where
+        I: PartialEq,
+    {
+        self.changes
+            .iter()
+            .rev()
+            .fold(new_name, |name, change| match change {
+                SingleChange::RenamedType(r) if &r.new_name == name => &r.old_name,
+                _ => name,
+            })
+    }
+
+    /// Find the name a type has after this changeset was applied
+    pub fn get_new_type_name<'a>(&'a self, old_name: &'a Ident<I>) -> &'a Ident<I>
+    where
+        I: PartialEq,
+    {
+        self.changes
+            .iter()
+            .fold(old_name, |name, change| match change {
+                SingleChange::RenamedType(r) if &r.old_name == name => &r.new_name,
+                _ => name,
+            })
+    }
+
+    /// Find the name a field had before this changeset was applied
+    pub fn get_old_field_name<'a>(&'a self, new_path: &'a FieldPath<I>) -> Option<&'a Ident<I>>
+    where
+        I: PartialEq,
+    {
+        let (new_name, parents) = new_path.path.split_last()?;
+        let old_name = self
+            .changes
+            .iter()
+            .rev()
+            .fold(new_name, |name, change| match change {
+                SingleChange::RenamedField(r)
+                    if r.new_name() == name
+                        && r.field_path.root == new_path.root
+                        && r.field_path.path.split_last().map(|(_, p)| p) == Some(parents) =>
+                {
+                    r.old_name().unwrap_or(name)
+                }
+                _ => name,
+            });
+
+        Some(old_name)
+    }
+
+    /// Find the name a varient had before this changeset was applied
+    pub fn get_old_varient_name<'a>(
+        &'a self,
+        type_name: &'a Ident<I>,
+        new_name: &'a Ident<I>,
+    ) -> &'a Ident<I>
+    where
+        I: PartialEq,
+    {
+        self.changes
+            .iter()
+            .rev()
+            .fold(new_name, |name, change| match change {
+                SingleChange::RenamedVarient(r)
+                    if &r.type_name == type_name && &r.new_name == name =>
+                {
+                    &r.old_name
+                }
+                _ => name,
+            })
+    }
+
+    /// Copy the fill expressions of a previous version of the changeset
+    ///
+    /// Fills are written by hand so they have to survive the changeset being rebuilt
+    pub fn keep_fills(&mut self, previous: &ChangeSet<I>)
+    where
+        I: Clone + PartialEq,
+    {
+        for change in &mut self.changes {
+            match change {
+                SingleChange::AddedField(added) => {
+                    added.fill = previous.changes.iter().find_map(|change| match change {
+                        SingleChange::AddedField(prev) if prev.field_path == added.field_path => {
+                            prev.fill.clone()
+                        }
+                        _ => None,
+                    });
+                }
+                SingleChange::EditedFieldType(edited) => {
+                    edited.fill = previous.changes.iter().find_map(|change| match change {
+                        SingleChange::EditedFieldType(prev)
+                            if prev.field_path == edited.field_path
+                                && prev.new_type == edited.new_type =>
+                        {
+                            prev.fill.clone()
+                        }
+                        _ => None,
+                    });
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Replace every alias used by the changes with the type it stands for
+    ///
+    /// The old types are expanded using the old schema and the new types using the new schema
+    pub fn expand_aliases(&self, old_schema: &Schema<I>, new_schema: &Schema<I>) -> ChangeSet<I>
+    where
+        I: Clone,
+    {
+        let mut changeset = self.clone();
+        for change in &mut changeset.changes {
+            match change {
+                SingleChange::AddedField(added) => {
+                    added.field_type = new_schema.expand_type(&added.field_type);
+                }
+                SingleChange::EditedFieldType(edited) => {
+                    edited.old_type = old_schema.expand_type(&edited.old_type);
+                    edited.new_type = new_schema.expand_type(&edited.new_type);
+                }
+                SingleChange::EditedOpaque(edited) => {
+                    edited.old_type = old_schema.expand_type(&edited.old_type);
+                    edited.new_type = new_schema.expand_type(&edited.new_type);
+                }
+                _ => (),
+            }
+        }
+
+        changeset
+    }
+
+    /// Rewrite the field changes of every node inheriting fields so they cover the inherited fields as well
+    ///
+    /// Edits to a base node are propagated to all of its children,
+    /// the changeset then works on the schemas with their inheritance resolved
+    pub fn resolve_inheritance(
+        &self,
+        old_schema: &Schema<I>,
+        new_schema: &Schema<I>,
+    ) -> ChangeSetResult<ChangeSet<I>>
+    where
+        I: Clone + Default + PartialEq + Ord,
+    {
+        let old_resolved = old_schema.resolve_inheritance();
+        let new_resolved = new_schema.resolve_inheritance();
+
+        let mut changeset = self.clone();
+        let mut resolved_changes = Vec::new();
+        for node in new_resolved.nodes() {
+            let old_node = match old_resolved.get_type(
+                Some(SchemaStmType::Node),
+                self.get_old_type_name(&node.name),
+            ) {
+                Some(SchemaStm::Node(n)) => Some(n),
+                _ => None,
+            };
+
+            let inherits = node.parent.is_some() || old_node.is_some_and(|n| n.parent.is_some());
+            if !inherits {
+                continue;
+            }
+
+            changeset.changes.retain(|change| match change {
+                SingleChange::AddedField(c) => c.field_path.root != node.name,
+                SingleChange::EditedFieldType(c) => c.field_path.root != node.name,
+                SingleChange::RemovedField(c) => c.field_path.root != node.name,
+                SingleChange::RenamedField(c) => c.field_path.root != node.name,
+                _ => true,
+            });
+
+            let old_fields = old_node.map(|n| n.fields.clone()).unwrap_or_default();
+            let mut field_changes = old_fields
+                .build_changeset_with_path(&node.fields, Some(FieldPath::new(node.name.clone())))?;
+
+            // Fills are looked up on the node itself before the closest ancestor
+            let mut owners = vec![&node.name];
+            if let Some(SchemaStm::Node(n)) =
+                new_schema.get_type(Some(SchemaStmType::Node), &node.name)
+            {
+                let ancestors = n.ancestors(new_schema).unwrap_or_default();
+                owners.extend(ancestors.iter().rev().map(|a| &a.name));
+            }
+
+            let mut fills = ChangeSet::new();
+            for owner in owners {
+                for change in &self.changes {
+                    match change {
+                        SingleChange::AddedField(c) if &c.field_path.root == owner => {
+                            let mut c = c.clone();
+                            c.field_path.root = node.name.clone();
+                            fills.push(SingleChange::AddedField(c));
+                        }
+                        SingleChange::EditedFieldType(c) if &c.field_path.root == owner => {
+                            let mut c = c.clone();
+                            c.field_path.root = node.name.clone();
+                            fills.push(SingleChange::EditedFieldType(c));
+                        }
+                        _ => (),
+                    }
+                }
+            }
+
+            field_changes.keep_fills(&fills);
+            resolved_changes.extend(field_changes.changes);
+        }
+
+        changeset.changes.extend(resolved_changes);
+        Ok(changeset)
+    }
+
+    /// Rewrite the endpoint changes of every edge using a group so they only refer to nodes
+    ///
+    /// Changing the members of a group shows up as endpoints being added to or removed from the edges using it,
+    /// the changeset then works on the schemas with their groups expanded
+    pub fn expand_groups(
+        &self,
+        old_schema: &Schema<I>,
+        new_schema: &Schema<I>,
+    ) -> ChangeSetResult<ChangeSet<I>>
+    where
+        I: Clone + Default + PartialEq + Debug + Ord,
+    {
+        let old_expanded = old_schema.expand_groups();
+        let new_expanded = new_schema.expand_groups();
+
+        let mut changeset = self.clone();
+        for change in &mut changeset.changes {
+            if let SingleChange::AddedType(AddedType {
+                type_type: AddedTypeData::Edge { endpoints },
+                type_name,
+                ..
+            }) = change
+            {
+                if let Some(SchemaStm::Edge(e)) =
+                    new_expanded.get_type(Some(SchemaStmType::Edge), type_name)
+                {
+                    *endpoints = e.endpoints.clone();
+                }
+            }
+        }
+
+        let uses_groups = |schema: &Schema<I>, edge: &EdgeExp<I>| {
+            edge.endpoints.values().any(|endpoint| {
+                schema
+                    .get_type(Some(SchemaStmType::Group), &endpoint.source)
+                    .or_else(|| schema.get_type(Some(SchemaStmType::Group), &endpoint.target))
+                    .is_some()
+            })
+        };
+
+        let mut expanded_changes = Vec::new();
+        for edge in new_expanded.edges() {
+            let old_name = self.get_old_type_name(&edge.name);
+            let (
+                Some(SchemaStm::Edge(old_edge)),
+                Some(SchemaStm::Edge(old_expanded_edge)),
+                Some(SchemaStm::Edge(new_edge)),
+            ) = (
+                old_schema.get_type(Some(SchemaStmType::Edge), old_name),
+                old_expanded.get_type(Some(SchemaStmType::Edge), old_name),
+                new_schema.get_type(Some(SchemaStmType::Edge), &edge.name),
+            )
+            else {
+                continue;
+            };
+
+            if !uses_groups(old_schema, old_edge) && !uses_groups(new_schema, new_edge) {
+                continue;
+            }
+
+            changeset.changes.retain(|change| match change {
+                SingleChange::AddedEndpoint(c) => c.type_name != edge.name,
+                SingleChange::RemovedEndpoint(c) => c.type_name != edge.name,
+                SingleChange::EditedEndpoint(c) => c.type_name != edge.name,
+                _ => true,
+            });
+
+            // Only the endpoints differ so only endpoint changes are made
+            let mut old_version = edge.clone();
+            old_version.endpoints = old_expanded_edge.endpoints.clone();
+            let endpoint_changes = old_version.build_changeset_with_path(edge, None)?;
+            expanded_changes.extend(endpoint_changes.changes);
+        }
+
+        changeset.changes.extend(expanded_changes);
+        Ok(changeset)
+    }
+
     pub fn apply(&self, schema: Schema<I>) -> ChangeSetResult<Schema<I>>
     where
         I: Hash + Clone + Default + PartialEq + Debug + Ord,
@@ -224,6 +560,146 @@ impl<I> ChangeSet<I> {
         Ok(updated_schema)
     }
 
+    /// Find the changes which are not able to carry all data over to the new version
+    pub fn get_lossy_changes(&self, old_schema: &Schema<I>) -> ChangeSetResult<Vec<LossyChange<I>>>
+    where
+        I: Clone + Default + PartialEq + Ord,
+    {
+        let mut schema = old_schema.clone();
+        let mut lossy_changes = Vec::new();
+        for change in &self.changes {
+            if let Some(reason) = change.get_data_loss(&schema) {
+                lossy_changes.push(LossyChange {
+                    change: change.clone(),
+                    reason,
+                });
+            }
+            change.apply(&mut schema)?;
+        }
+
+        Ok(lossy_changes)
+    }
+
+    /// Find the compatibility of each change
+    pub fn get_compatibility(
+        &self,
+        old_schema: &Schema<I>,
+    ) -> ChangeSetResult<Vec<ClassifiedChange<I>>>
+    where
+        I: Clone + Default + PartialEq + Ord,
+    {
+        let mut schema = old_schema.clone();
+        let mut added_types = Vec::new();
+        let mut classified_changes = Vec::new();
+        for change in &self.changes {
+            // The content of new types has no old data to be compatible with
+            let is_added = change
+                .get_target()
+                .is_some_and(|target| added_types.contains(&target.root));
+
+            let compatibility = if is_added {
+                Compatibility::Safe
+            } else {
+                change.get_compatibility(&schema)
+            };
+
+            if let SingleChange::AddedType(t) = change {
+                added_types.push(t.type_name.clone());
+            }
+
+            classified_changes.push(ClassifiedChange {
+                change: change.clone(),
+                compatibility,
+            });
+            change.apply(&mut schema)?;
+        }
+
+        Ok(classified_changes)
+    }
+
+    /// Create the changeset going from the new version back to the old one
+    ///
+    /// Most changes only describe the new version, so the old schema is used to restore what was there before.
+    /// The changes which are not able to restore all data are returned alongside the changeset
+    pub fn invert(&self, old_schema: &Schema<I>) -> ChangeSetResult<(ChangeSet<I>, Vec<LossyChange<I>>)>
+    where
+        I: Hash + Clone + Default + PartialEq + Debug + Ord,
+    {
+        let old_hash = old_schema.get_hash();
+        if old_hash != self.old_hash || old_schema.version != self.old_version {
+            return Err(crate::ChangeSetError::IncompatibleSchemaVersion {
+                expected: self.old_hash,
+                recieved: old_hash,
+                old_version: self.old_version.to_string(),
+                new_version: self.new_version.to_string(),
+            });
+        }
+
+        let mut schema = old_schema.clone();
+        schema.version = self.new_version.clone();
+        schema.handler = self.handler.clone();
+
+        // Each change is inverted using the schema as it looked right before the change
+        let mut inverted_changes = Vec::new();
+        for change in &self.changes {
+            inverted_changes.push(change.invert(&schema)?);
+            change.apply(&mut schema)?;
+        }
+
+        // The changes are undone in reverse order
+        let changes = inverted_changes.into_iter().rev().flatten().collect();
+
+        let inverted = ChangeSet {
+            old_version: self.new_version.clone(),
+            new_version: self.old_version.clone(),
+            handler: old_schema.handler.clone(),
+            old_hash: self.new_hash,
+            new_hash: self.old_hash,
+            hash_version: self.hash_version,
+            changes,
+        };
+
+        let lossy_changes = inverted.get_lossy_changes(&schema)?;
+
+        // Verify that the inverted changeset restores the old schema
+        inverted.apply(schema)?;
+
+        Ok((inverted, lossy_changes))
+    }
+
+    /// Merge this changeset with the one following it into a single changeset
+    ///
+    /// Items added by this changeset and removed by the other cancel out
+    /// and repeated edits of the same item are merged into a single edit
+    pub fn compose(&self, other: &ChangeSet<I>) -> ChangeSetResult<ChangeSet<I>>
+    where
+        I: Clone + PartialEq,
+    {
+        if self.new_hash != other.old_hash || self.new_version != other.old_version {
+            return Err(crate::ChangeSetError::IncompatibleSchemaVersion {
+                expected: self.new_hash,
+                recieved: other.old_hash,
+                old_version: other.old_version.to_string(),
+                new_version: other.new_version.to_string(),
+            });
+        }
+
+        let mut changes = self.changes.clone();
+        for change in &other.changes {
+            compose_change(&mut changes, change.clone());
+        }
+
+        Ok(ChangeSet {
+            old_version: self.old_version.clone(),
+            new_version: other.new_version.clone(),
+            handler: other.handler.clone(),
+            old_hash: self.old_hash,
+            new_hash: other.new_hash,
+            hash_version: other.hash_version,
+            changes,
+        })
+    }
+
     pub fn check_convertion_res(&self) -> ParserSlimResult<I, ()>
     where
         I: Clone,
@@ -236,9 +712,288 @@ impl<I> ChangeSet<I> {
     }
 }
 
+/// Add a change to the end of the changes while cancelling or merging it with the previous ones
+fn compose_change<I>(changes: &mut Vec<SingleChange<I>>, change: SingleChange<I>)
+where
+    I: Clone + PartialEq,
+{
+    match &change {
+        SingleChange::EditedSchema(_) => {
+            // Only the last comments matter
+            changes.retain(|c| !matches!(c, SingleChange::EditedSchema(_)));
+            changes.push(change);
+        }
+        SingleChange::AddedEndpoint(_)
+        | SingleChange::EditedEndpoint(_)
+        | SingleChange::RemovedEndpoint(_) => compose_endpoint(changes, change),
+        SingleChange::RemovedType(_)
+        | SingleChange::RemovedVarient(_)
+        | SingleChange::RemovedField(_) => compose_removal(changes, change),
+        SingleChange::EditedType(_)
+        | SingleChange::EditedVariant(_)
+        | SingleChange::EditedFieldType(_)
+        | SingleChange::EditedOpaque(_)
+        | SingleChange::EditedGenerics(_)
+        | SingleChange::EditedVariantsOrder(_) => compose_edit(changes, change),
+        SingleChange::AddedType(_)
+        | SingleChange::AddedVarient(_)
+        | SingleChange::AddedField(_)
+        | SingleChange::RenamedType(_)
+        | SingleChange::RenamedField(_)
+        | SingleChange::RenamedVarient(_) => changes.push(change),
+    }
+}
+
+/// Check if the target of a change is the item at path or something inside it
+fn is_inside<I: PartialEq>(target: &FieldPath<I>, path: &FieldPath<I>) -> bool {
+    target.root == path.root && target.path.starts_with(&path.path)
+}
+
+/// Remove everything that happened to an item if it was added by the previous changes
+fn compose_removal<I>(changes: &mut Vec<SingleChange<I>>, change: SingleChange<I>)
+where
+    I: Clone + PartialEq,
+{
+    let mut path = match change.get_target() {
+        Some(path) => path,
+        None => return changes.push(change),
+    };
+
+    // Walk backwards while keeping track of what the item was called at the time
+    let mut affected = Vec::new();
+    for (i, prev) in changes.iter().enumerate().rev() {
+        prev.rename_back(&mut path);
+
+        let target = match prev.get_target() {
+            Some(target) => target,
+            None => continue,
+        };
+
+        if !is_inside(&target, &path) {
+            continue;
+        }
+
+        affected.push(i);
+
+        let is_added = match (prev, &change) {
+            (SingleChange::AddedType(_), SingleChange::RemovedType(_))
+            | (SingleChange::AddedVarient(_), SingleChange::RemovedVarient(_))
+            | (SingleChange::AddedField(_), SingleChange::RemovedField(_)) => target == path,
+            _ => false,
+        };
+
+        if is_added {
+            // affected is in descending order so the indices stay valid while removing
+            for i in affected {
+                changes.remove(i);
+            }
+            return;
+        }
+    }
+
+    changes.push(change);
+}
+
+/// Merge an edit with the previous edit or addition of the same item
+fn compose_edit<I>(changes: &mut Vec<SingleChange<I>>, change: SingleChange<I>)
+where
+    I: Clone + PartialEq,
+{
+    let mut path = match change.get_target() {
+        Some(path) => path,
+        None => return changes.push(change),
+    };
+
+    let mut is_renamed = false;
+    for i in (0..changes.len()).rev() {
+        is_renamed |= changes[i].rename_back(&mut path);
+
+        if changes[i].get_target().as_ref() != Some(&path) {
+            continue;
+        }
+
+        match (&mut changes[i], &change) {
+            (SingleChange::EditedFieldType(prev), SingleChange::EditedFieldType(c)) => {
+                // Fills written for a type change no longer apply if the type changes again
+                let fill = c.fill.clone().or_else(|| {
+                    if c.old_type == c.new_type {
+                        prev.fill.clone()
+                    } else {
+                        None
+                    }
+                });
+                let merged = EditedField {
+                    field_path: c.field_path.clone(),
+                    comments: c.comments.clone(),
+                    attributes: c.attributes.clone(),
+                    old_visibility: prev.old_visibility,
+                    new_visibility: c.new_visibility,
+                    old_type: prev.old_type.clone(),
+                    new_type: c.new_type.clone(),
+                    old_default: prev.old_default.clone(),
+                    new_default: c.new_default.clone(),
+                    old_order: prev.old_order,
+                    new_order: c.new_order,
+                    fill,
+                };
+                changes.remove(i);
+                changes.push(SingleChange::EditedFieldType(merged));
+            }
+            (SingleChange::AddedField(prev), SingleChange::EditedFieldType(c)) if !is_renamed => {
+                let fill = if prev.field_type == c.new_type {
+                    prev.fill.clone()
+                } else {
+                    None
+                };
+                let merged = AddedField {
+                    comments: c.comments.clone(),
+                    attributes: c.attributes.clone(),
+                    visibility: c.new_visibility,
+                    field_path: prev.field_path.clone(),
+                    field_type: c.new_type.clone(),
+                    default: c.new_default.clone(),
+                    order: c.new_order,
+                    fill,
+                };
+                // The new type may refer to types added after the field
+                changes.remove(i);
+                changes.push(SingleChange::AddedField(merged));
+            }
+            (SingleChange::EditedOpaque(prev), SingleChange::EditedOpaque(c)) => {
+                let merged = EditedOpaque {
+                    field_path: c.field_path.clone(),
+                    old_type: prev.old_type.clone(),
+                    new_type: c.new_type.clone(),
+                };
+                changes.remove(i);
+                changes.push(SingleChange::EditedOpaque(merged));
+            }
+            (SingleChange::EditedGenerics(prev), SingleChange::EditedGenerics(c)) => {
+                let merged = EditedGenerics {
+                    type_name: c.type_name.clone(),
+                    old_generics: prev.old_generics.clone(),
+                    new_generics: c.new_generics.clone(),
+                };
+                changes.remove(i);
+                changes.push(SingleChange::EditedGenerics(merged));
+            }
+            (SingleChange::EditedVariantsOrder(prev), SingleChange::EditedVariantsOrder(c)) => {
+                let merged = EditedVariantsOrder {
+                    type_name: c.type_name.clone(),
+                    old_order: prev.old_order.clone(),
+                    new_order: c.new_order.clone(),
+                };
+                changes.remove(i);
+                changes.push(SingleChange::EditedVariantsOrder(merged));
+            }
+            (SingleChange::EditedType(_), SingleChange::EditedType(_))
+            | (SingleChange::EditedVariant(_), SingleChange::EditedVariant(_)) => {
+                changes.remove(i);
+                changes.push(change);
+            }
+            (
+                SingleChange::AddedType(_)
+                | SingleChange::AddedVarient(_)
+                | SingleChange::AddedField(_)
+                | SingleChange::RemovedType(_)
+                | SingleChange::RemovedVarient(_)
+                | SingleChange::RemovedField(_),
+                _,
+            ) => changes.push(change),
+            _ => continue,
+        }
+
+        return;
+    }
+
+    changes.push(change);
+}
+
+/// Cancel or merge endpoint changes on the same edge going between the same types
+fn compose_endpoint<I>(changes: &mut Vec<SingleChange<I>>, change: SingleChange<I>)
+where
+    I: Clone + PartialEq,
+{
+    let (type_name, endpoint) = match &change {
+        SingleChange::AddedEndpoint(e) => (&e.type_name, &e.endpoint),
+        SingleChange::EditedEndpoint(e) => (&e.type_name, &e.endpoint),
+        SingleChange::RemovedEndpoint(e) => (&e.type_name, &e.endpoint),
+        _ => return changes.push(change),
+    };
+    let is_same = |other_type: &Ident<I>, other: &EndPoint<I>| {
+        other_type == type_name
+            && other.source == endpoint.source
+            && other.target == endpoint.target
+    };
+
+    let mut affected = Vec::new();
+    for i in (0..changes.len()).rev() {
+        match (&mut changes[i], &change) {
+            // Keep it simple and stop when anything is renamed
+            (SingleChange::RenamedType(_), _) => break,
+            (SingleChange::AddedType(t), _) if &t.type_name == type_name => {
+                let key = (endpoint.source.clone(), endpoint.target.clone());
+                let is_folded = match (&mut t.type_type, &change) {
+                    (AddedTypeData::Edge { endpoints }, SingleChange::EditedEndpoint(_))
+                        if endpoints.contains_key(&key) =>
+                    {
+                        endpoints.insert(key, endpoint.clone());
+                        true
+                    }
+                    (AddedTypeData::Edge { endpoints }, SingleChange::RemovedEndpoint(_)) => {
+                        endpoints.remove(&key).is_some()
+                    }
+                    _ => false,
+                };
+
+                if !is_folded {
+                    break;
+                }
+
+                for i in affected {
+                    changes.remove(i);
+                }
+                return;
+            }
+            (SingleChange::RemovedType(t), _) if &t.type_name == type_name => break,
+            (SingleChange::EditedEndpoint(e), _) if is_same(&e.type_name, &e.endpoint) => {
+                affected.push(i)
+            }
+            (SingleChange::AddedEndpoint(e), SingleChange::EditedEndpoint(c))
+                if is_same(&e.type_name, &e.endpoint) =>
+            {
+                e.endpoint = c.endpoint.clone();
+                for i in affected {
+                    changes.remove(i);
+                }
+                return;
+            }
+            (SingleChange::AddedEndpoint(e), SingleChange::RemovedEndpoint(_))
+                if is_same(&e.type_name, &e.endpoint) =>
+            {
+                affected.push(i);
+                for i in affected {
+                    changes.remove(i);
+                }
+                return;
+            }
+            _ => (),
+        }
+    }
+
+    // Only the last edit of an endpoint matters
+    if matches!(change, SingleChange::EditedEndpoint(_)) {
+        for i in affected {
+            changes.remove(i);
+        }
+    }
+
+    changes.push(change);
+}
+
 impl<I: InputType> ParserDeserialize<I> for ChangeSet<I> {
     fn parse(s: I) -> ParserResult<I, Self> {
-        let (s, (((old_version, old_hash), (new_version, new_hash)), handler)) = context(
+        let (s, (((old_version, old_hash), (new_version, new_hash)), handler, hash_version)) = context(
             "Parsing ChangeSet version",
             surrounded(
                 '<',
@@ -252,6 +1007,14 @@ impl<I: InputType> ParserDeserialize<I> for ChangeSet<I> {
                         tuple((ws(char(',')), tag("handler"), ws(char('=')))),
                         Ident::ident,
                     )),
+                    // Changesets without a hash version were created with the DefaultHasher
+                    map(
+                        opt(preceded(
+                            tuple((ws(char(',')), tag("hash"), ws(char('=')))),
+                            build_script_shared::parsers::u32,
+                        )),
+                        Option::unwrap_or_default,
+                    ),
                 )),
                 '>',
             ),
@@ -271,6 +1034,7 @@ impl<I: InputType> ParserDeserialize<I> for ChangeSet<I> {
             handler,
             new_hash,
             old_hash,
+            hash_version,
             changes,
         };
 
@@ -297,6 +1061,9 @@ impl<I> ParserSerialize for ChangeSet<I> {
             write!(f, ", handler = ")?;
             handler.compose(f, changset_ctx)?;
         }
+        if self.hash_version != 0 {
+            write!(f, ", hash = {}", self.hash_version)?;
+        }
         writeln!(f, " >")?;
 
         for change in &self.changes {
@@ -307,11 +1074,12 @@ impl<I> ParserSerialize for ChangeSet<I> {
     }
 }
 
-impl<I: Hash> ChangeSet<I> {
+impl<I> ChangeSet<I> {
+    /// Hash of the changeset as it is written to a file
     pub fn get_hash(&self) -> u64 {
-        let mut s = DefaultHasher::new();
-        self.hash(&mut s);
-        s.finish()
+        // Writing to a string does not fail
+        let s = self.serialize_to_string().unwrap_or_default();
+        stable_hash(s.as_bytes())
     }
 }
 
@@ -333,6 +1101,8 @@ fn schema_changeset_test() {
     use build_script_shared::tests::display_diff;
     use build_script_shared::CodePreview;
     use fake::{Fake, Faker};
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
 
     for _ in 0..build_script_shared::tests::TEST_ITERATION_COUNT {
         let old_schema: Schema<String> = Faker.fake();
@@ -344,8 +1114,10 @@ fn schema_changeset_test() {
         let change_showcase = changes.serialize_to_string().unwrap();
         println!("{}", CodePreview::showcase(change_showcase.clone()));
 
+        // The handler is stored on the changeset so it is set the same way ChangeSet::apply does
         let mut updated_schema = old_schema.clone();
         updated_schema.version = changes.new_version.clone();
+        updated_schema.handler = changes.handler.clone();
 
         for change in &changes.changes {
             let res = change.apply(&mut updated_schema);
@@ -430,3 +1202,614 @@ fn schema_changeset_test() {
         assert_eq!(updated_hash, new_hash);
     }
 }
+
+#[test]
+fn changeset_handler_test() {
+    use crate::ChangeSetBuilder;
+
+    let old_schema = "
+    <V0>
+    node A {
+        b: String
+    };";
+
+    let new_schema = "
+    <V1, handler=MigrationHandler>
+    node A {
+        b: String
+    };";
+
+    let (_, old_schema) = Schema::parse(old_schema).unwrap();
+    let (_, new_schema) = Schema::parse(new_schema).unwrap();
+
+    // The handler is stored on the changeset and not as a change
+    let changes = old_schema.build_changeset(&new_schema).unwrap();
+    assert!(changes.changes.is_empty());
+    assert_eq!(changes.handler, new_schema.handler);
+
+    let updated_schema = changes.apply(old_schema).unwrap();
+    assert_eq!(updated_schema.handler, new_schema.handler);
+}
+
+#[test]
+fn schema_rename_test() {
+    let old_schema = "
+    <V0>
+    node Student {
+        name: String,
+        status: Status,
+        grade: Grade
+    };
+
+    node Class {
+        name: String
+    };
+
+    struct Grade {
+        score: u8
+    };
+
+    enum Status {
+        Active,
+        Pending { since: u64 }
+    };
+
+    edge Attends(Student => Class) {
+        grade: Grade
+    };";
+
+    let new_schema = "
+    <V1>
+    node Pupil {
+        full_name: String,
+        status: Status,
+        grade: Mark
+    };
+
+    node Class {
+        name: String
+    };
+
+    struct Mark {
+        score: u8
+    };
+
+    enum Status {
+        Enrolled,
+        Waiting { waiting_since: u64 }
+    };
+
+    edge Attends(Pupil => Class) {
+        grade: Mark
+    };";
+
+    let changes = "
+    ~ node Student => Pupil;
+    ~ struct Grade => Mark;
+    ~ Pupil.name => full_name;
+    ~ enum Status.Active => Enrolled;
+    ~ enum Status.Pending => Waiting;
+    ~ Status.Waiting.since => waiting_since;";
+
+    let (_, mut updated_schema) = Schema::parse(old_schema).unwrap();
+    let (_, new_schema) = Schema::parse(new_schema).unwrap();
+    let (_, changes) = many1(ws(SingleChange::parse))(changes).unwrap();
+
+    updated_schema.version = new_schema.version.clone();
+    for change in &changes {
+        change.apply(&mut updated_schema).unwrap();
+    }
+
+    assert_eq!(updated_schema, new_schema);
+
+    // Renaming onto an existing name must fail
+    let (_, mut updated_schema) = Schema::parse(old_schema).unwrap();
+    let (_, collision) = SingleChange::parse("~ node Student => Class;").unwrap();
+    assert!(collision.apply(&mut updated_schema).is_err());
+}
+
+#[test]
+fn schema_renamed_from_test() {
+    use crate::traits::ChangeSetBuilder;
+
+    let old_schema = "
+    <V0>
+    node Student {
+        name: String,
+        status: Status
+    };
+
+    node Class {
+        name: String
+    };
+
+    enum Status {
+        Active,
+        Pending { since: u64 }
+    };
+
+    edge Attends(Student => Class) {
+        since: u64
+    };";
+
+    let new_schema = "
+    <V1>
+    @renamed_from(Student)
+    node Pupil {
+        @renamed_from(name)
+        full_name: String,
+        status: Status
+    };
+
+    node Class {
+        name: String
+    };
+
+    enum Status {
+        @renamed_from(Active)
+        Enrolled,
+        Pending {
+            @renamed_from(since)
+            waiting_since: u64
+        }
+    };
+
+    edge Attends(Pupil => Class) {
+        since: u64
+    };";
+
+    let (_, old_schema) = Schema::parse(old_schema).unwrap();
+    let (_, new_schema) = Schema::parse(new_schema).unwrap();
+    new_schema.check_integrity().unwrap();
+
+    let changes = old_schema.build_changeset(&new_schema).unwrap();
+    let renames = changes
+        .changes
+        .iter()
+        .filter(|change| {
+            matches!(
+                change,
+                SingleChange::RenamedType(_)
+                    | SingleChange::RenamedField(_)
+                    | SingleChange::RenamedVarient(_)
+            )
+        })
+        .count();
+    assert_eq!(renames, 4);
+
+    let updated_schema = changes.apply(old_schema.clone()).unwrap();
+    assert_eq!(updated_schema, new_schema);
+
+    // Once applied the attributes no longer result in renames
+    let changes = new_schema.build_changeset(&new_schema).unwrap();
+    assert!(changes.changes.is_empty());
+
+    // The renamed type must exist in the old schema
+    let (_, missing_schema) = Schema::parse(
+        "
+    <V1>
+    @renamed_from(Teacher)
+    node Pupil {
+        name: String
+    };",
+    )
+    .unwrap();
+    assert!(matches!(
+        old_schema.build_changeset(&missing_schema),
+        Err(crate::ChangeSetError::MissingRenamedFrom { .. })
+    ));
+}
+
+#[test]
+fn fill_expression_test() {
+    let old_schema = "
+    <V0>
+    node Student {
+        name: String,
+        grade: u16
+    };";
+
+    let new_schema = "
+    <V1>
+    node Student {
+        name: String,
+        nickname: String,
+        grade: u8,
+        active: bool
+    };";
+
+    let changes = "
+    + Student.nickname: String(1) <- old.name;
+    + Student.active: bool(3) <- true;
+    * Student.grade: u16(1) => u8(2) <- 0;";
+
+    let (_, mut updated_schema) = Schema::parse(old_schema).unwrap();
+    let (_, new_schema) = Schema::parse(new_schema).unwrap();
+    let (_, changes) = many1(ws(SingleChange::parse))(changes).unwrap();
+
+    // The fill expressions survive a roundtrip
+    for change in &changes {
+        let s = change.serialize_to_string().unwrap();
+        assert_eq!(SingleChange::parse(s.as_str()).map(|(_, c)| c), Ok(change.clone()));
+    }
+
+    updated_schema.version = new_schema.version.clone();
+    for change in &changes {
+        change.apply(&mut updated_schema).unwrap();
+    }
+
+    assert_eq!(updated_schema, new_schema);
+
+    // The fill has to match the type and refer to an existing field
+    let (_, mut updated_schema) = Schema::parse(old_schema).unwrap();
+    let (_, wrong_type) = SingleChange::parse("+ Student.active: bool(3) <- 5;").unwrap();
+    assert!(wrong_type.apply(&mut updated_schema).is_err());
+    let (_, missing_field) =
+        SingleChange::parse("+ Student.nickname: String(1) <- old.nickname;").unwrap();
+    assert!(missing_field.apply(&mut updated_schema).is_err());
+
+    // Old fields have to be convertible to the type they fill
+    let (_, wrong_old_type) =
+        SingleChange::parse("+ Student.active: bool(3) <- old.name;").unwrap();
+    assert!(wrong_old_type.apply(&mut updated_schema).is_err());
+    let (_, widened) = SingleChange::parse("+ Student.score: u32(3) <- old.grade;").unwrap();
+    assert!(widened.apply(&mut updated_schema).is_ok());
+}
+
+#[test]
+fn invert_changeset_test() {
+    use crate::ChangeSetBuilder;
+    use fake::{Fake, Faker};
+
+    for _ in 0..build_script_shared::tests::TEST_ITERATION_COUNT {
+        let old_schema: Schema<String> = Faker.fake();
+        let new_schema: Schema<String> = Faker.fake();
+
+        let changes = old_schema.build_changeset(&new_schema).unwrap();
+        let (inverted, _) = changes.invert(&old_schema).unwrap();
+
+        assert_eq!(inverted.old_hash, changes.new_hash);
+        assert_eq!(inverted.new_hash, changes.old_hash);
+        assert_eq!(inverted.apply(new_schema).unwrap(), old_schema);
+    }
+
+    let old_schema = "
+    <V0>
+    node Student {
+        name: String,
+        grade: u8
+    };
+    node Teacher {
+        name: String
+    };
+    edge Teaches(Teacher => Student[0..30]) {};
+    enum State {
+        Idle,
+        Busy
+    };";
+
+    let new_schema = "
+    <V1>
+    @renamed_from(Student)
+    node Pupil {
+        @renamed_from(name)
+        full_name: String,
+        grade: u16,
+        active: bool
+    };
+    node Teacher {
+        name: String
+    };
+    edge Teaches(Teacher => Pupil) {};
+    enum State {
+        Idle
+    };";
+
+    let (_, old_schema) = Schema::parse(old_schema).unwrap();
+    let (_, new_schema) = Schema::parse(new_schema).unwrap();
+
+    let changes = old_schema.build_changeset(&new_schema).unwrap();
+    let (inverted, lossy) = changes.invert(&old_schema).unwrap();
+    assert_eq!(inverted.apply(new_schema).unwrap(), old_schema);
+
+    // The order of the changes depends on the order the builder found them in
+    let mut lossy: Vec<_> = lossy.iter().map(|l| l.change.to_string()).collect();
+    lossy.sort();
+    assert_eq!(
+        lossy,
+        vec![
+            "* Pupil.grade: u16(1) => u8(1)".to_string(),
+            "* Teaches( Teacher => Pupil[0..30] )".to_string(),
+            "- Pupil.active".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn compose_changeset_test() {
+    use crate::ChangeSetBuilder;
+    use fake::{Fake, Faker};
+
+    for _ in 0..build_script_shared::tests::TEST_ITERATION_COUNT {
+        let schema0: Schema<String> = Faker.fake();
+        let schema1: Schema<String> = Faker.fake();
+        let schema2: Schema<String> = Faker.fake();
+
+        let changes0 = schema0.build_changeset(&schema1).unwrap();
+        let changes1 = schema1.build_changeset(&schema2).unwrap();
+        let composed = changes0.compose(&changes1).unwrap();
+
+        assert_eq!(composed.old_hash, changes0.old_hash);
+        assert_eq!(composed.new_hash, changes1.new_hash);
+        assert_eq!(composed.apply(schema0).unwrap(), schema2);
+    }
+
+    let schema0 = "
+    <V0>
+    node Student {
+        name: String,
+        grade: u8
+    };";
+
+    let schema1 = "
+    <V1>
+    node Student {
+        name: String,
+        grade: u16,
+        age: u8
+    };
+    node Course {
+        title: String
+    };";
+
+    let schema2 = "
+    <V2>
+    node Student {
+        @renamed_from(name)
+        full_name: String,
+        grade: u32
+    };";
+
+    let (_, schema0) = Schema::parse(schema0).unwrap();
+    let (_, schema1) = Schema::parse(schema1).unwrap();
+    let (_, schema2) = Schema::parse(schema2).unwrap();
+
+    let changes0 = schema0.build_changeset(&schema1).unwrap();
+    let changes1 = schema1.build_changeset(&schema2).unwrap();
+    let composed = changes0.compose(&changes1).unwrap();
+
+    // Course and Student.age are added and removed again so they cancel out
+    let mut changes: Vec<_> = composed.changes.iter().map(|c| c.to_string()).collect();
+    changes.sort();
+    assert_eq!(
+        changes,
+        vec![
+            "* Student.grade: u8(1) => u32(1)".to_string(),
+            "@renamed_from(name)\n* Student.full_name: String(0) => String(0)".to_string(),
+            "~ Student.name => full_name".to_string(),
+        ]
+    );
+    assert_eq!(composed.apply(schema0).unwrap(), schema2);
+
+    // The changesets has to follow each other
+    assert!(changes1.compose(&changes0).is_err());
+}
+
+#[test]
+fn compatibility_test() {
+    let old_schema = "
+    <V0>
+    node Student {
+        name: String,
+        score: i64,
+        grade: u8
+    };
+    node Teacher {
+        name: String
+    };
+    edge Teaches(Teacher => Student[0..30]) {};
+    enum State {
+        Idle,
+        Busy
+    };";
+
+    let changes = "
+    * Student.score: i64(1) => i32(1) <- 0;
+    * Student.grade: u8(2) => u16(2);
+    + Student.nickname: Option<String>(3);
+    + Student.age: u8(4) <- 0;
+    * Teaches( Teacher => Student[0..20] );
+    - enum State.Busy;
+    ~ Teacher.name => full_name;
+    + node Course;
+    + Course.name: String(0);";
+
+    let (_, old_schema) = Schema::parse(old_schema).unwrap();
+    let (_, changes) = many1(ws(SingleChange::parse))(changes).unwrap();
+
+    let mut changeset = ChangeSet::new();
+    for change in changes {
+        changeset.push(change);
+    }
+
+    let compatibility: Vec<_> = changeset
+        .get_compatibility(&old_schema)
+        .unwrap()
+        .into_iter()
+        .map(|c| c.compatibility)
+        .collect();
+
+    assert_eq!(
+        compatibility,
+        vec![
+            Compatibility::Forward,
+            Compatibility::Backward,
+            Compatibility::Safe,
+            Compatibility::Forward,
+            Compatibility::Forward,
+            Compatibility::Forward,
+            Compatibility::Breaking,
+            Compatibility::Backward,
+            Compatibility::Safe,
+        ]
+    );
+}
+
+#[test]
+fn alias_dependents_test() {
+    use crate::ChangeSetBuilder;
+
+    let schema0 = "
+    <V0>
+    type Scores<K> = Map<K, Grade>;
+    type Grade = u16;
+    struct Report {
+        scores: Scores<String>
+    };
+    node Student {
+        name: String,
+        best: Grade
+    };";
+
+    let schema1 = "
+    <V1>
+    type Scores<K> = Map<K, Grade>;
+    type Grade = u32;
+    struct Report {
+        scores: Scores<String>
+    };
+    node Student {
+        name: String,
+        best: Grade
+    };";
+
+    let (_, schema0) = Schema::parse(schema0).unwrap();
+    let (_, schema1) = Schema::parse(schema1).unwrap();
+
+    let changeset = schema0.build_changeset(&schema1).unwrap();
+
+    // Every field using Grade is edited, even through another alias
+    let mut changes: Vec<_> = changeset.changes.iter().map(|c| c.to_string()).collect();
+    changes.sort();
+    assert_eq!(
+        changes,
+        vec![
+            "* Grade: u16 => u32".to_string(),
+            "* Report.scores: Scores<String>(0) => Scores<String>(0)".to_string(),
+            "* Student.best: Grade(1) => Grade(1)".to_string(),
+        ]
+    );
+    assert_eq!(changeset.apply(schema0.clone()).unwrap(), schema1);
+
+    let expanded = changeset.expand_aliases(&schema0, &schema1);
+    assert!(expanded
+        .changes
+        .iter()
+        .any(|c| c.to_string() == "* Student.best: u16(1) => u32(1)"));
+}
+
+#[test]
+fn inheritance_test() {
+    use crate::ChangeSetBuilder;
+
+    let schema0 = "
+    <V0>
+    abstract node Person {
+        name: String
+    };
+    node Teacher: Person {
+        subject: String
+    };";
+
+    let schema1 = "
+    <V1>
+    abstract node Person {
+        name: String,
+        owner: String
+    };
+    node Teacher: Person {
+        subject: String
+    };
+    node Student {
+        name: String
+    };";
+
+    let (_, schema0) = Schema::parse(schema0).unwrap();
+    let (_, schema1) = Schema::parse(schema1).unwrap();
+
+    let changeset = schema0.build_changeset(&schema1).unwrap();
+    assert_eq!(changeset.apply(schema0.clone()).unwrap(), schema1);
+
+    // The field added to Person is also added to Teacher
+    let resolved = changeset.resolve_inheritance(&schema0, &schema1).unwrap();
+    let changes: Vec<_> = resolved.changes.iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        changes,
+        vec![
+            "+ node Student".to_string(),
+            "+ Student.name: String(0)".to_string(),
+            "+ Person.owner: String(1)".to_string(),
+            "+ Teacher.owner: String(1)".to_string(),
+            "* Teacher.subject: String(1) => String(2)".to_string(),
+        ]
+    );
+
+    let mut schema = schema0.resolve_inheritance();
+    schema.version = schema1.version.clone();
+    for change in &resolved.changes {
+        change.apply(&mut schema).unwrap();
+    }
+    assert_eq!(
+        schema.get_canonical_form(),
+        schema1.resolve_inheritance().get_canonical_form()
+    );
+}
+
+#[test]
+fn group_test() {
+    use crate::ChangeSetBuilder;
+
+    let schema0 = "
+    <V0>
+    node Person {};
+    node Building {};
+    node Vehicle {};
+    group Asset = Building | Vehicle;
+    edge Owns(Person => Asset) {};";
+
+    let schema1 = "
+    <V1>
+    node Person {};
+    node Building {};
+    node Vehicle {};
+    group Asset = Building;
+    edge Owns(Person => Asset) {};";
+
+    let (_, schema0) = Schema::parse(schema0).unwrap();
+    let (_, schema1) = Schema::parse(schema1).unwrap();
+
+    let changeset = schema0.build_changeset(&schema1).unwrap();
+    assert_eq!(changeset.apply(schema0.clone()).unwrap(), schema1);
+
+    // Removing Vehicle from the group removes the endpoint going to it
+    let expanded = changeset.expand_groups(&schema0, &schema1).unwrap();
+    let changes: Vec<_> = expanded.changes.iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        changes,
+        vec![
+            "- group Asset".to_string(),
+            "+ group(Building) Asset".to_string(),
+            "- Owns( Person => Vehicle )".to_string(),
+        ]
+    );
+
+    let mut schema = schema0.expand_groups();
+    schema.version = schema1.version.clone();
+    for change in &expanded.changes {
+        change.apply(&mut schema).unwrap();
+    }
+    assert_eq!(
+        schema.get_canonical_form(),
+        schema1.expand_groups().get_canonical_form()
+    );
+}
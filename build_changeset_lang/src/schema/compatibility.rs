@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+/// How a change affects data moving between two versions of a schema
+///
+/// Backward compatible means the new version can read data stored by the old version
+/// Forward compatible means the old version can read data stored by the new version
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Compatibility {
+    /// Both versions can read data from each other
+    Safe,
+    /// Only the new version can read data from the old version
+    Backward,
+    /// Only the old version can read data from the new version
+    Forward,
+    /// Neither version can read data from the other
+    Breaking,
+}
+
+impl Compatibility {
+    pub fn new(backward: bool, forward: bool) -> Compatibility {
+        match (backward, forward) {
+            (true, true) => Compatibility::Safe,
+            (true, false) => Compatibility::Backward,
+            (false, true) => Compatibility::Forward,
+            (false, false) => Compatibility::Breaking,
+        }
+    }
+
+    pub fn is_backward(&self) -> bool {
+        matches!(self, Compatibility::Safe | Compatibility::Backward)
+    }
+
+    pub fn is_forward(&self) -> bool {
+        matches!(self, Compatibility::Safe | Compatibility::Forward)
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        matches!(self, Compatibility::Breaking)
+    }
+
+    /// Find the compatibility of applying both changes
+    pub fn combine(&self, other: &Compatibility) -> Compatibility {
+        Compatibility::new(
+            self.is_backward() && other.is_backward(),
+            self.is_forward() && other.is_forward(),
+        )
+    }
+}
+
+impl Display for Compatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Compatibility::Safe => write!(f, "safe"),
+            Compatibility::Backward => write!(f, "backward compatible"),
+            Compatibility::Forward => write!(f, "forward compatible"),
+            Compatibility::Breaking => write!(f, "breaking"),
+        }
+    }
+}
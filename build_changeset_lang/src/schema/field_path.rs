@@ -111,6 +111,8 @@ impl<I> FieldPath<I> {
             SchemaStm::Edge(_)
             | SchemaStm::Node(_)
             | SchemaStm::Import(_)
+            | SchemaStm::Alias(_)
+            | SchemaStm::Group(_)
             | SchemaStm::Struct(_) => {
                 return Err(ChangeSetError::InvalidAction {
                     action: format!("retrieving fields"),
@@ -123,6 +125,38 @@ impl<I> FieldPath<I> {
         };
     }
 
+    /// Retrieve the field the path points to without modifying the schema
+    pub fn get_field<'a>(&'a self, schema: &'a Schema<I>) -> ChangeSetResult<&'a FieldValue<I>>
+    where
+        I: Ord,
+    {
+        let field_name = self.get_field_name_res()?;
+
+        let ty = schema
+            .iter()
+            .find(|s| s.get_type() == &self.root)
+            .ok_or_else(|| ChangeSetError::InvalidAction {
+                action: format!("retrieving fields"),
+                reason: format!("Failed to find type for {}", self),
+            })?;
+
+        let fields = match (ty, self.path.len()) {
+            (_, 1) => ty.get_fields(),
+            (SchemaStm::Enum(e), 2) => match e.get_varient(&self.path[0]) {
+                Some(EnumVarient::Struct { fields, .. }) => Some(fields),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        fields
+            .and_then(|fields| fields.get_field(field_name.as_str()))
+            .ok_or_else(|| ChangeSetError::InvalidAction {
+                action: format!("retrieving fields"),
+                reason: format!("Failed to find field at {}", self),
+            })
+    }
+
     /// Move from one input type to another
     pub fn map<O, F>(self, f: F) -> FieldPath<O>
     where
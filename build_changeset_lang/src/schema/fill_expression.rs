@@ -0,0 +1,117 @@
+use build_script_lang::schema::*;
+use build_script_shared::compose_test;
+use build_script_shared::error::ParserResult;
+use build_script_shared::parsers::*;
+use build_script_shared::InputType;
+use fake::Dummy;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::char;
+use nom::combinator::{cut, map};
+use nom::error::context;
+use nom::sequence::{pair, preceded};
+use std::fmt::Display;
+
+use crate::{ChangeSetError, ChangeSetResult};
+
+/// "\<literal\>|old.\<ident\>"
+///
+/// Value used to fill a field when migrating data from the previous version
+#[derive(Debug, PartialEq, Eq, Clone, Hash, Dummy)]
+pub enum FillExpression<I> {
+    Literal(Literal<I>),
+    /// Value of a field in the previous version of the type
+    OldField(Ident<I>),
+}
+
+impl<I> FillExpression<I> {
+    /// Make sure the expression can be used to fill a field of the given type
+    ///
+    /// The fields are the ones of the type before the change is applied
+    pub fn check(&self, action: &str, ty: &Types<I>, old_fields: &Fields<I>) -> ChangeSetResult<()>
+    where
+        I: Clone,
+    {
+        match self {
+            FillExpression::Literal(literal) => {
+                literal
+                    .check_type(ty)
+                    .map_err(|_| ChangeSetError::InvalidAction {
+                        action: action.to_string(),
+                        reason: format!("{literal} is not a valid value for {ty}"),
+                    })
+            }
+            FillExpression::OldField(name) => {
+                let Some(old_field) = old_fields.get_field(name.as_str()) else {
+                    return Err(ChangeSetError::InvalidAction {
+                        action: action.to_string(),
+                        reason: format!("no field named {name} exists in the previous version"),
+                    });
+                };
+
+                let old_type = &old_field.field_type;
+                if old_type.check_convertion(ty) {
+                    Ok(())
+                } else {
+                    Err(ChangeSetError::InvalidAction {
+                        action: action.to_string(),
+                        reason: format!("{name} is a {old_type} which cannot be converted to {ty}"),
+                    })
+                }
+            }
+        }
+    }
+
+    /// Move from one input type to another
+    pub fn map<O, F>(self, f: F) -> FillExpression<O>
+    where
+        F: Fn(I) -> O + Copy,
+    {
+        match self {
+            FillExpression::Literal(literal) => FillExpression::Literal(literal.map(f)),
+            FillExpression::OldField(name) => FillExpression::OldField(name.map(f)),
+        }
+    }
+}
+
+impl<I: InputType> ParserDeserialize<I> for FillExpression<I> {
+    fn parse(s: I) -> ParserResult<I, Self> {
+        context(
+            "Parsing FillExpression",
+            alt((
+                map(
+                    preceded(pair(tag("old"), char('.')), cut(Ident::ident)),
+                    FillExpression::OldField,
+                ),
+                map(Literal::parse, FillExpression::Literal),
+            )),
+        )(s)
+    }
+}
+
+impl<I> ParserSerialize for FillExpression<I> {
+    fn compose<W: std::fmt::Write>(
+        &self,
+        f: &mut W,
+        ctx: ComposeContext,
+    ) -> build_script_shared::error::ComposerResult<()> {
+        match self {
+            FillExpression::Literal(literal) => literal.compose(f, ctx)?,
+            FillExpression::OldField(name) => {
+                let indents = ctx.create_indents();
+                write!(f, "{indents}old.")?;
+                name.compose(f, ctx.set_indents(0))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<I> Display for FillExpression<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ser = self.serialize_to_string().map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", ser)
+    }
+}
+
+compose_test! {fill_expression_compose, FillExpression<I>}
@@ -1,7 +1,11 @@
 mod actions;
 mod changeset;
+mod compatibility;
 mod field_path;
+mod fill_expression;
 
 pub use actions::*;
 pub use changeset::*;
+pub use compatibility::*;
 pub use field_path::*;
+pub use fill_expression::*;
@@ -0,0 +1,67 @@
+use std::fmt::Debug;
+
+use build_script_lang::schema::AliasExp;
+use build_script_lang::schema::SchemaStmType;
+use build_script_shared::parsers::Attributes;
+
+use crate::schema::*;
+use crate::traits::ChangeSetBuilder;
+use crate::ChangeSetError;
+use crate::ChangeSetResult;
+
+impl<I> ChangeSetBuilder<I> for AliasExp<I>
+where
+    I: Clone + Default + PartialEq + Debug,
+{
+    fn build_changeset_with_path(
+        &self,
+        new_version: &Self,
+        path: Option<FieldPath<I>>,
+    ) -> ChangeSetResult<ChangeSet<I>> {
+        if let Some(path) = path {
+            return Err(ChangeSetError::InvalidFieldPath {
+                path: path.to_string(),
+                target: self.name.to_string(),
+            });
+        }
+
+        if self.name != new_version.name {
+            return Err(ChangeSetError::InvalidTypeComparison {
+                type0: self.name.to_string(),
+                type1: new_version.name.to_string(),
+            });
+        }
+
+        let mut changes = ChangeSet::new();
+
+        let new_doc_comments = new_version.comments.get_doc_comments();
+        if new_doc_comments != self.comments.get_doc_comments() {
+            changes.push(SingleChange::EditedType(EditedType {
+                comments: new_doc_comments,
+                attributes: Attributes::default(),
+                type_type: SchemaStmType::Alias,
+                type_name: self.name.clone(),
+                is_abstract: false,
+                parent: None,
+            }));
+        }
+
+        if new_version.generics != self.generics {
+            changes.push(SingleChange::EditedGenerics(EditedGenerics {
+                type_name: self.name.clone(),
+                old_generics: self.generics.clone(),
+                new_generics: new_version.generics.clone(),
+            }));
+        }
+
+        if new_version.ty != self.ty {
+            changes.push(SingleChange::EditedOpaque(EditedOpaque {
+                field_path: FieldPath::new(self.name.clone()),
+                old_type: self.ty.clone(),
+                new_type: new_version.ty.clone(),
+            }));
+        }
+
+        Ok(changes)
+    }
+}
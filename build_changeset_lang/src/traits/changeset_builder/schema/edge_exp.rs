@@ -1,5 +1,5 @@
 use build_script_lang::schema::{EdgeExp, SchemaStmType};
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 use std::fmt::Debug;
 
 use crate::schema::*;
@@ -30,8 +30,8 @@ where
             });
         }
 
-        let old_keys: HashSet<_> = self.endpoints.keys().collect();
-        let new_keys: HashSet<_> = new_version.endpoints.keys().collect();
+        let old_keys: BTreeSet<_> = self.endpoints.keys().collect();
+        let new_keys: BTreeSet<_> = new_version.endpoints.keys().collect();
 
         let mut changes = ChangeSet::new();
 
@@ -84,6 +84,8 @@ where
                 attributes: new_version.attributes.clone(),
                 type_type: SchemaStmType::Edge,
                 type_name: self.name.clone(),
+                is_abstract: false,
+                parent: None,
             }));
         }
 
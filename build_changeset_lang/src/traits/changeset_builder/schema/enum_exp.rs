@@ -4,6 +4,7 @@ use build_script_lang::schema::Fields;
 use build_script_lang::schema::SchemaStmType;
 use build_script_lang::schema::Visibility;
 use build_script_shared::parsers::Attributes;
+use build_script_shared::HASH_VERSION;
 
 use crate::schema::*;
 use crate::traits::ChangeSetBuilder;
@@ -33,6 +34,49 @@ where
             });
         }
 
+        // Rename the old varients first so the remaining varients can be compared by name
+        let mut renamed_enum = self.clone();
+        let mut renames = Vec::new();
+        for varient in &new_version.varients {
+            let old_name = match varient.attributes().get_renamed_from() {
+                Some(old_name) => old_name,
+                None => continue,
+            };
+
+            // The rename happened in an earlier version
+            if renamed_enum.has_varient(varient.name()) {
+                continue;
+            }
+
+            if new_version.has_varient(old_name) {
+                return Err(ChangeSetError::InvalidAction {
+                    action: format!("rename {}.{} to {}", self.name, old_name, varient.name()),
+                    reason: format!("{} still exists in the new version", old_name),
+                });
+            }
+
+            let renamed_varient = renamed_enum.get_varient_mut(old_name).ok_or_else(|| {
+                ChangeSetError::MissingRenamedFrom {
+                    old_name: format!("{}.{}", self.name, old_name),
+                    new_name: format!("{}.{}", self.name, varient.name()),
+                }
+            })?;
+            *renamed_varient.name_mut() = varient.name().clone();
+
+            renames.push(SingleChange::RenamedVarient(RenamedVarient {
+                type_name: self.name.clone(),
+                old_name: old_name.clone(),
+                new_name: varient.name().clone(),
+            }));
+        }
+
+        if !renames.is_empty() {
+            let mut changes = renamed_enum.build_changeset_with_path(new_version, None)?;
+            renames.append(&mut changes.changes);
+            changes.changes = renames;
+            return Ok(changes);
+        }
+
         // Check which varients are new
         let mut added_varients = Vec::new();
         let new_varient_iter = new_version.varients.iter().enumerate();
@@ -114,6 +158,8 @@ where
                                 attributes: Attributes::default(),
                                 type_type: SchemaStmType::Enum,
                                 type_name: self.name.clone(),
+                                is_abstract: false,
+                                parent: None,
                             }));
                         }
                     }
@@ -172,6 +218,8 @@ where
                                 attributes: Attributes::default(),
                                 type_type: SchemaStmType::Enum,
                                 type_name: self.name.clone(),
+                                is_abstract: false,
+                                parent: None,
                             }));
                         }
                     }
@@ -228,7 +276,10 @@ where
                                 old_type: old_ty.clone(),
                                 new_order: 0,
                                 new_type: ty.clone(),
+                                old_default: None,
+                                new_default: None,
                                 old_order: 0,
+                                fill: None,
                             }));
                         }
                     }
@@ -293,6 +344,7 @@ where
         Ok(ChangeSet {
             new_hash: Default::default(),
             old_hash: Default::default(),
+            hash_version: HASH_VERSION,
             handler: None,
             new_version: Default::default(),
             old_version: Default::default(),
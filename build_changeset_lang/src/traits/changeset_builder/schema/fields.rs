@@ -1,4 +1,5 @@
 use build_script_lang::schema::Fields;
+use build_script_shared::HASH_VERSION;
 
 use crate::schema::*;
 use crate::traits::ChangeSetBuilder;
@@ -16,6 +17,48 @@ where
     ) -> ChangeSetResult<ChangeSet<I>> {
         let path = path.ok_or_else(|| ChangeSetError::MissingFieldPath)?;
 
+        // Rename the old fields first so the remaining fields can be compared by name
+        let mut renamed_fields = self.clone();
+        let mut renames = Vec::new();
+        for field_value in new_version.iter() {
+            let old_name = match field_value.attributes.get_renamed_from() {
+                Some(old_name) => old_name,
+                None => continue,
+            };
+
+            // The rename happened in an earlier version
+            if renamed_fields.has_field(field_value.name.as_str()) {
+                continue;
+            }
+
+            if new_version.has_field(old_name.as_str()) {
+                return Err(ChangeSetError::InvalidAction {
+                    action: format!("rename {} to {}", path.push(old_name.clone()), field_value.name),
+                    reason: format!("{} still exists in the new version", old_name),
+                });
+            }
+
+            let renamed_field = renamed_fields
+                .get_field_mut(old_name.as_str())
+                .ok_or_else(|| ChangeSetError::MissingRenamedFrom {
+                    old_name: path.push(old_name.clone()).to_string(),
+                    new_name: path.push(field_value.name.clone()).to_string(),
+                })?;
+            renamed_field.name = field_value.name.clone();
+
+            renames.push(SingleChange::RenamedField(RenamedField {
+                field_path: path.push(old_name.clone()),
+                new_name: field_value.name.clone(),
+            }));
+        }
+
+        if !renames.is_empty() {
+            let mut changes = renamed_fields.build_changeset_with_path(new_version, Some(path))?;
+            renames.append(&mut changes.changes);
+            changes.changes = renames;
+            return Ok(changes);
+        }
+
         let mut changes: Vec<_> = new_version
             .iter()
             .filter(|field_value| !self.has_field(field_value.name.as_str()))
@@ -26,7 +69,9 @@ where
                     visibility: field_value.visibility,
                     field_path: path.push(field_value.name.clone()),
                     field_type: field_value.field_type.clone(),
+                    default: field_value.default.clone(),
                     order: field_value.order,
+                    fill: None,
                 })
             })
             .collect();
@@ -57,8 +102,11 @@ where
                     new_visibility: new_type.visibility,
                     old_type: old_type.field_type.clone(),
                     new_type: new_type.field_type.clone(),
+                    old_default: old_type.default.clone(),
+                    new_default: new_type.default.clone(),
                     old_order: old_type.order,
                     new_order: new_type.order,
+                    fill: None,
                 })
             });
 
@@ -68,6 +116,7 @@ where
         Ok(ChangeSet {
             new_hash: Default::default(),
             old_hash: Default::default(),
+            hash_version: HASH_VERSION,
             handler: None,
             new_version: Default::default(),
             old_version: Default::default(),
@@ -0,0 +1,68 @@
+use std::fmt::Debug;
+
+use build_script_lang::schema::GroupExp;
+use build_script_lang::schema::SchemaStmType;
+
+use crate::schema::*;
+use crate::traits::ChangeSetBuilder;
+use crate::ChangeSetError;
+use crate::ChangeSetResult;
+
+impl<I> ChangeSetBuilder<I> for GroupExp<I>
+where
+    I: Clone + Default + PartialEq + Debug,
+{
+    fn build_changeset_with_path(
+        &self,
+        new_version: &Self,
+        path: Option<FieldPath<I>>,
+    ) -> ChangeSetResult<ChangeSet<I>> {
+        if let Some(path) = path {
+            return Err(ChangeSetError::InvalidFieldPath {
+                path: path.to_string(),
+                target: self.name.to_string(),
+            });
+        }
+
+        if self.name != new_version.name {
+            return Err(ChangeSetError::InvalidTypeComparison {
+                type0: self.name.to_string(),
+                type1: new_version.name.to_string(),
+            });
+        }
+
+        let mut changes = ChangeSet::new();
+
+        // The members are part of the added type so the group is recreated when they change
+        if self.members != new_version.members {
+            changes.push(SingleChange::RemovedType(RemovedType {
+                type_type: SchemaStmType::Group,
+                type_name: self.name.clone(),
+            }));
+            changes.push(SingleChange::AddedType(AddedType {
+                comments: new_version.comments.get_doc_comments(),
+                attributes: Default::default(),
+                type_type: AddedTypeData::Group {
+                    members: new_version.members.clone(),
+                },
+                type_name: new_version.name.clone(),
+            }));
+
+            return Ok(changes);
+        }
+
+        let new_doc_comments = new_version.comments.get_doc_comments();
+        if self.comments.get_doc_comments() != new_doc_comments {
+            changes.push(SingleChange::EditedType(EditedType {
+                comments: new_doc_comments,
+                attributes: Default::default(),
+                type_type: SchemaStmType::Group,
+                type_name: self.name.clone(),
+                is_abstract: false,
+                parent: None,
+            }));
+        }
+
+        Ok(changes)
+    }
+}
@@ -40,6 +40,8 @@ where
                 attributes: Default::default(),
                 type_type: SchemaStmType::Import,
                 type_name: self.name.clone(),
+                is_abstract: false,
+                parent: None,
             }));
         }
 
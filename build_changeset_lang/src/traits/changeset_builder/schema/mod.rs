@@ -1,6 +1,8 @@
+mod alias_exp;
 mod edge_exp;
 mod enum_exp;
 mod fields;
+mod group_exp;
 mod import_exp;
 mod node_exp;
 mod schema;
@@ -42,12 +42,16 @@ where
         let new_doc_comments = new_version.comments.get_doc_comments();
         if &new_doc_comments != &self.comments.get_doc_comments()
             || new_version.attributes != self.attributes
+            || new_version.is_abstract != self.is_abstract
+            || new_version.parent != self.parent
         {
             changes.push(SingleChange::EditedType(EditedType {
                 comments: new_doc_comments,
                 attributes: new_version.attributes.clone(),
                 type_type: SchemaStmType::Node,
                 type_name: self.name.clone(),
+                is_abstract: new_version.is_abstract,
+                parent: new_version.parent.clone(),
             }));
         }
 
@@ -2,13 +2,14 @@ use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 
 use build_script_lang::schema::*;
+use build_script_shared::parsers::Types;
 use nom::Err;
 use std::hash::Hash;
 
 use crate::schema::*;
 use crate::traits::ChangeSetBuilder;
 use crate::ChangeSetResult;
-use crate::{ChangeSetError, RemovedType};
+use crate::{ChangeSetError, RemovedType, RenamedType};
 
 impl<I> ChangeSetBuilder<I> for Schema<I>
 where
@@ -26,6 +27,54 @@ where
             });
         }
 
+        // Renames are applied to the old schema first
+        // so the remaining types can be compared by name
+        let mut renamed_schema = self.clone();
+        let mut renames = Vec::new();
+        for stm in new_version.iter() {
+            let old_name = match stm.get_attributes().and_then(|attr| attr.get_renamed_from()) {
+                Some(old_name) => old_name,
+                None => continue,
+            };
+
+            // The rename happened in an earlier version
+            if renamed_schema.get_type(None, stm.get_type()).is_some() {
+                continue;
+            }
+
+            let type_type = stm.get_schema_type();
+            if renamed_schema.get_type(Some(type_type), old_name).is_none() {
+                return Err(ChangeSetError::MissingRenamedFrom {
+                    old_name: old_name.to_string(),
+                    new_name: stm.get_type().to_string(),
+                });
+            }
+
+            if new_version.get_type(None, old_name).is_some() {
+                return Err(ChangeSetError::InvalidAction {
+                    action: format!("rename {} to {}", old_name, stm.get_type()),
+                    reason: format!("{} still exists in the new version", old_name),
+                });
+            }
+
+            let rename = RenamedType {
+                type_type,
+                old_name: old_name.clone(),
+                new_name: stm.get_type().clone(),
+            };
+            rename.apply(&mut renamed_schema)?;
+            renames.push(SingleChange::RenamedType(rename));
+        }
+
+        if !renames.is_empty() {
+            let mut changes = renamed_schema.build_changeset(new_version)?;
+            renames.append(&mut changes.changes);
+            changes.changes = renames;
+            changes.old_hash = self.get_hash();
+            changes.old_version = self.version.clone();
+            return Ok(changes);
+        }
+
         let mut old_types = HashMap::new();
         for stm in self.iter() {
             let type_name = stm.get_type();
@@ -74,29 +123,42 @@ where
                 SchemaStm::Node(n) => {
                     let mut base = NodeExp::default();
                     base.name = n.name.clone();
-                    base.build_changeset_with_path(n, None).unwrap()
+                    base.build_changeset_with_path(n, None)?
                 }
                 SchemaStm::Struct(n) => {
                     let mut base = StructExp::default();
                     base.name = n.name.clone();
-                    base.build_changeset_with_path(n, None).unwrap()
+                    base.build_changeset_with_path(n, None)?
                 }
                 SchemaStm::Edge(n) => {
                     let mut base = EdgeExp::default();
                     base.name = n.name.clone();
                     base.endpoints = n.endpoints.clone();
-                    base.build_changeset_with_path(n, None).unwrap()
+                    base.build_changeset_with_path(n, None)?
                 }
                 SchemaStm::Enum(n) => {
                     let mut base = EnumExp::default();
                     base.name = n.name.clone();
-                    base.build_changeset_with_path(n, None).unwrap()
+                    base.build_changeset_with_path(n, None)?
                 }
                 SchemaStm::Import(n) => {
                     let mut base = ImportExp::default();
                     base.name = n.name.clone();
-                    base.build_changeset_with_path(n, None).unwrap()
+                    base.build_changeset_with_path(n, None)?
                 }
+                SchemaStm::Alias(n) => {
+                    // The type is part of the added type so only the generics are left
+                    let base = AliasExp::new(
+                        Default::default(),
+                        n.name.clone(),
+                        Default::default(),
+                        n.ty.clone(),
+                        Default::default(),
+                    );
+                    base.build_changeset_with_path(n, None)?
+                }
+                // The members are part of the added type
+                SchemaStm::Group(_) => ChangeSet::new(),
             };
 
             added_types.extend(added_fields);
@@ -122,6 +184,12 @@ where
                 (SchemaStm::Import(type0), SchemaStm::Import(type1)) => {
                     edited_types.extend(type0.build_changeset_with_path(type1, None)?)
                 }
+                (SchemaStm::Alias(type0), SchemaStm::Alias(type1)) => {
+                    edited_types.extend(type0.build_changeset_with_path(type1, None)?)
+                }
+                (SchemaStm::Group(type0), SchemaStm::Group(type1)) => {
+                    edited_types.extend(type0.build_changeset_with_path(type1, None)?)
+                }
                 // For all other types we default to just recreating the element
                 (type0, type1) => {
                     removed_types.push(crate::schema::SingleChange::RemovedType(RemovedType {
@@ -138,8 +206,11 @@ where
             }
         }
 
+        let alias_dependents = edit_alias_dependents(self, new_version, &edited_types);
+
         let mut changes = added_types;
         changes.extend(edited_types);
+        changes.extend(alias_dependents);
         changes.extend(removed_types);
 
         let old_doc = self.comments.get_doc_comments();
@@ -198,3 +269,140 @@ where
         Ok(changes)
     }
 }
+
+/// Fields using an alias whose type changed are edited even though the field itself is the same
+///
+/// This makes the alias change visible on every field which has to be migrated because of it
+fn edit_alias_dependents<I>(
+    old_schema: &Schema<I>,
+    new_schema: &Schema<I>,
+    edited_types: &ChangeSet<I>,
+) -> ChangeSet<I>
+where
+    I: Clone + Default + PartialEq + Ord,
+{
+    let mut changed_aliases = HashSet::new();
+    for alias in new_schema.aliases() {
+        if let Some(SchemaStm::Alias(old_alias)) =
+            old_schema.get_type(Some(SchemaStmType::Alias), &alias.name)
+        {
+            let old_type = old_schema.expand_type(&old_alias.ty);
+            let new_type = new_schema.expand_type(&alias.ty);
+            if old_type != new_type || old_alias.generics != alias.generics {
+                changed_aliases.insert(alias.name.to_string());
+            }
+        }
+    }
+
+    let mut changes = ChangeSet::new();
+    if changed_aliases.is_empty() {
+        return changes;
+    }
+
+    let is_edited = |path: &FieldPath<I>| {
+        edited_types
+            .changes
+            .iter()
+            .any(|change| change.get_target().as_ref() == Some(path))
+    };
+
+    for stm in new_schema.iter() {
+        let Some(old_stm) = old_schema.get_type(Some(stm.get_schema_type()), stm.get_type()) else {
+            continue;
+        };
+
+        let mut fields = Vec::new();
+        match (old_stm, stm) {
+            (SchemaStm::Enum(old_enum), SchemaStm::Enum(new_enum)) => {
+                for varient in &new_enum.varients {
+                    let path =
+                        FieldPath::new_path(new_enum.name.clone(), vec![varient.name().clone()]);
+                    match (old_enum.get_varient(varient.name()), varient) {
+                        (
+                            Some(EnumVarient::Struct {
+                                fields: old_fields, ..
+                            }),
+                            EnumVarient::Struct {
+                                fields: new_fields, ..
+                            },
+                        ) => fields.push((path, old_fields, new_fields)),
+                        (
+                            Some(EnumVarient::Opaque { ty: old_ty, .. }),
+                            EnumVarient::Opaque { ty, .. },
+                        ) if old_ty == ty
+                            && uses_alias(ty, &changed_aliases)
+                            && !is_edited(&path) =>
+                        {
+                            changes.push(SingleChange::EditedOpaque(EditedOpaque {
+                                field_path: path,
+                                old_type: old_ty.clone(),
+                                new_type: ty.clone(),
+                            }))
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            (old_stm, stm) => {
+                if let (Some(old_fields), Some(new_fields)) =
+                    (old_stm.get_fields(), stm.get_fields())
+                {
+                    fields.push((
+                        FieldPath::new(stm.get_type().clone()),
+                        old_fields,
+                        new_fields,
+                    ));
+                }
+            }
+        }
+
+        for (path, old_fields, new_fields) in fields {
+            for new_field in new_fields.iter() {
+                let field_path = path.push(new_field.name.clone());
+                let Some(old_field) = old_fields.get_field(new_field.name.as_str()) else {
+                    continue;
+                };
+
+                if old_field.field_type != new_field.field_type
+                    || !uses_alias(&new_field.field_type, &changed_aliases)
+                    || is_edited(&field_path)
+                {
+                    continue;
+                }
+
+                changes.push(SingleChange::EditedFieldType(EditedField {
+                    field_path,
+                    comments: new_field.comments.get_doc_comments(),
+                    attributes: new_field.attributes.clone(),
+                    old_visibility: old_field.visibility,
+                    new_visibility: new_field.visibility,
+                    old_type: old_field.field_type.clone(),
+                    new_type: new_field.field_type.clone(),
+                    old_default: old_field.default.clone(),
+                    new_default: new_field.default.clone(),
+                    old_order: old_field.order,
+                    new_order: new_field.order,
+                    fill: None,
+                }));
+            }
+        }
+    }
+
+    changes
+}
+
+fn uses_alias<I>(ty: &Types<I>, aliases: &HashSet<String>) -> bool {
+    match ty {
+        Types::Reference {
+            inner, generics, ..
+        } => {
+            aliases.contains(&inner.to_string())
+                || generics.iter().any(|generic| uses_alias(generic, aliases))
+        }
+        Types::Option { inner, .. } | Types::List { inner, .. } | Types::Set { inner, .. } => {
+            uses_alias(inner, aliases)
+        }
+        Types::Map { key, value, .. } => uses_alias(key, aliases) || uses_alias(value, aliases),
+        _ => false,
+    }
+}
@@ -47,6 +47,8 @@ where
                 attributes: Attributes::default(),
                 type_type: SchemaStmType::Struct,
                 type_name: self.name.clone(),
+                is_abstract: false,
+                parent: None,
             }));
         }
 
@@ -8,7 +8,9 @@ use fake::Dummy;
 use fake::Faker;
 use nom::character::complete::*;
 use nom::error::context;
+use nom::combinator::*;
 use nom::sequence::pair;
+use nom::sequence::preceded;
 use nom::sequence::tuple;
 use nom::Err;
 use serde::Deserialize;
@@ -23,8 +25,10 @@ const ALLOWED_FUNCTION_KEY_VALUE_ATTRIBUTES: &[(&str, &str)] = &[(JSON, "alias")
 
 const ALLOWED_FUNCTION_ATTRIBUTE_VALUES: &[&str] = &["skip", "default"];
 
-const ALLOWED_FUNCTION_ATTRIBUTES: &[(&str, Option<usize>, Option<&[&str]>)] =
-    &[(JSON, Some(1), Some(ALLOWED_FUNCTION_ATTRIBUTE_VALUES))];
+const ALLOWED_FUNCTION_ATTRIBUTES: &[(&str, Option<usize>, Option<&[&str]>)] = &[
+    (JSON, Some(1), Some(ALLOWED_FUNCTION_ATTRIBUTE_VALUES)),
+    (RENAMED_FROM, Some(1), None),
+];
 
 #[derive(Debug, Clone, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(bound = "I: Default + Clone")]
@@ -34,7 +38,7 @@ pub struct Fields<I> {
     marker: Mark<I>,
 }
 
-#[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Dummy, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Dummy, Serialize, Deserialize)]
 #[serde(bound = "I: Default + Clone")]
 pub struct FieldValue<I> {
     pub name: Ident<I>,
@@ -48,6 +52,10 @@ pub struct FieldValue<I> {
     #[serde(flatten)]
     pub comments: Comments,
     pub field_type: Types<I>,
+    /// Value used when the field is missing
+    // Generated schemas leave it out as the value has to match the type
+    #[dummy(default)]
+    pub default: Option<Literal<I>>,
     /// The order in which the field should be shown.  
     /// Having multiple fields on the same order is undefined behaviour
     #[serde(skip)]
@@ -75,6 +83,10 @@ impl<I> Fields<I> {
         fields.into_iter()
     }
 
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut FieldValue<I>> {
+        self.fields.iter_mut()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.fields.is_empty()
     }
@@ -164,6 +176,7 @@ impl<I> Fields<I> {
     where
         I: Clone,
     {
+        // Default values are checked by the schema as the field type may be an alias
         for field_value in &self.fields {
             field_value.field_type.check_types(reference_types)?;
         }
@@ -247,6 +260,7 @@ impl<I> Fields<I> {
                     attributes: field.attributes.map(f),
                     comments: field.comments,
                     field_type: field.field_type.map(f),
+                    default: field.default.map(|default| default.map(f)),
                     order: field.order,
                 })
                 .collect(),
@@ -278,6 +292,7 @@ impl<I: InputType> ParserDeserialize<I> for Fields<I> {
                             char(':'),
                             Types::parse,
                         ),
+                        opt(preceded(ws(char('=')), cut(Literal::parse))),
                     )),
                     ',',
                 ),
@@ -288,7 +303,7 @@ impl<I: InputType> ParserDeserialize<I> for Fields<I> {
         // Populate the list of fields
         let mut fields: Vec<FieldValue<I>> = Vec::new();
         let fields_iter = fields_raw.into_iter().enumerate();
-        for (order, (comments, attributes, ((visibility, name), ty))) in fields_iter {
+        for (order, (comments, attributes, ((visibility, name), ty), default)) in fields_iter {
             let first_occurance = fields.iter().find(|field| &field.name == &name);
             if let Some(first) = first_occurance {
                 return Err(Err::Failure(
@@ -309,6 +324,7 @@ impl<I: InputType> ParserDeserialize<I> for Fields<I> {
                 visibility,
                 comments,
                 field_type: ty,
+                default,
                 order: order as u64,
             });
         }
@@ -337,6 +353,10 @@ impl<I> ParserSerialize for Fields<I> {
             field_value.name.compose(f, field_ctx.set_indents(0))?;
             write!(f, ": ")?;
             field_value.field_type.compose(f, ctx.set_indents(0))?;
+            if let Some(default) = &field_value.default {
+                write!(f, " = ")?;
+                default.compose(f, ctx.set_indents(0))?;
+            }
         }
         if !first {
             writeln!(f)?;
@@ -366,6 +386,21 @@ impl<I: Hash> Hash for Fields<I> {
     }
 }
 
+impl<I: Hash> Hash for FieldValue<I> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.visibility.hash(state);
+        self.attributes.hash(state);
+        self.comments.hash(state);
+        self.field_type.hash(state);
+        // Fields without a default keep the hash they had before defaults were added
+        if let Some(default) = &self.default {
+            default.hash(state);
+        }
+        self.order.hash(state);
+    }
+}
+
 impl<I: PartialEq> PartialEq for Fields<I> {
     fn eq(&self, other: &Self) -> bool {
         let self_fields: Vec<_> = self.iter().collect();
@@ -12,27 +12,51 @@ use nom::sequence::*;
 use serde::Deserialize;
 use serde::Serialize;
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
 
-#[derive(Debug, Clone, Hash, Dummy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(bound = "I: Default")]
 pub struct Quantifier<I> {
-    // The upper bounds are inclusive
-    pub bounds: Option<(LowerBound, u32)>,
+    // The bounds are inclusive
+    pub lower: u32,
+    // None if there is no limit to how many edges are allowed
+    pub upper: Option<u32>,
     #[serde(skip)]
     marker: Mark<I>,
 }
 
-#[derive(
-    Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Dummy, Serialize, Deserialize,
-)]
-pub enum LowerBound {
+/// How the lower bounds were stored before any lower bound was allowed
+///
+/// Only used to keep the legacy hash of old schemas the same
+#[derive(Hash)]
+enum LegacyLowerBound {
     Zero,
     One,
 }
 
 impl<I> Quantifier<I> {
-    pub fn new(bounds: Option<(LowerBound, u32)>, marker: Mark<I>) -> Self {
-        Quantifier { bounds, marker }
+    pub fn new(lower: u32, upper: Option<u32>, marker: Mark<I>) -> Self {
+        Quantifier {
+            lower,
+            upper,
+            marker,
+        }
+    }
+
+    /// Check if the quantifier allows any amount of edges
+    pub fn is_unbounded(&self) -> bool {
+        self.lower == 0 && self.upper.is_none()
+    }
+
+    /// Check if every amount allowed by other is also allowed by self
+    pub fn contains(&self, other: &Quantifier<I>) -> bool {
+        let upper_contained = match (&self.upper, &other.upper) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(upper), Some(other_upper)) => upper >= other_upper,
+        };
+
+        self.lower <= other.lower && upper_contained
     }
 
     /// Move from one input type to another
@@ -41,7 +65,8 @@ impl<I> Quantifier<I> {
         F: FnMut(I) -> O,
     {
         Quantifier {
-            bounds: self.bounds,
+            lower: self.lower,
+            upper: self.upper,
             marker: self.marker.map(f),
         }
     }
@@ -49,41 +74,54 @@ impl<I> Quantifier<I> {
 
 impl<I: InputType> ParserDeserialize<I> for Quantifier<I> {
     fn parse(s: I) -> ParserResult<I, Self> {
-        // match [ 0 ..= {u32}]
+        // match [ {u32} .. {u32} ] or [ {u32} .. * ] or [ {u32} ]
         let (s, (res, marker)) = context(
             "Parsing Quantifier",
             marked(opt(surrounded(
                 '[',
-                ws(tuple((
-                    context(
-                        "Parsing lower bounds",
-                        alt((
-                            map(char('0'), |_| LowerBound::Zero),
-                            map(char('1'), |_| LowerBound::One),
-                        )),
+                // Once the bracket is found it has to be a valid quantifier
+                cut(ws(context(
+                    "Lower bound cannot be larger than the upper bound",
+                    verify(
+                        map(
+                            pair(
+                                context("Parsing lower bounds", parsers::u32),
+                                opt(preceded(
+                                    ws(pair(char('.'), char('.'))),
+                                    context(
+                                        "Parsing upper bounds",
+                                        alt((map(char('*'), |_| None), map(parsers::u32, Some))),
+                                    ),
+                                )),
+                            ),
+                            // [n] only allows exactly n edges
+                            |(lower, upper)| (lower, upper.unwrap_or(Some(lower))),
+                        ),
+                        |(lower, upper): &(u32, Option<u32>)| !matches!(upper, Some(upper) if upper < lower),
                     ),
-                    ws(pair(char('.'), char('.'))),
-                    context("Parsing upper bounds", parsers::u32),
                 ))),
                 ']',
             ))),
         )(s)?;
 
-        let bounds = res.map(|(lower, _, upper)| (lower, upper));
+        let (lower, upper) = res.unwrap_or((0, None));
 
-        Ok((s, Quantifier { bounds, marker }))
+        Ok((
+            s,
+            Quantifier {
+                lower,
+                upper,
+                marker,
+            },
+        ))
     }
 }
 
 impl<I> ParserSerialize for Quantifier<I> {
     fn compose<W: std::fmt::Write>(&self, f: &mut W, ctx: ComposeContext) -> ComposerResult<()> {
         let indents = ctx.create_indents();
-        match &self.bounds {
-            Some((lower, upper)) => match lower {
-                LowerBound::Zero => write!(f, "{indents}[0..{upper}]")?,
-                LowerBound::One => write!(f, "{indents}[1..{upper}]")?,
-            },
-            None => (),
+        if !self.is_unbounded() {
+            write!(f, "{indents}{self}")?;
         }
         Ok(())
     }
@@ -91,26 +129,64 @@ impl<I> ParserSerialize for Quantifier<I> {
 
 impl<I: Default> Default for Quantifier<I> {
     fn default() -> Self {
-        Quantifier::new(None, Mark::null())
+        Quantifier::new(0, None, Mark::null())
+    }
+}
+
+impl<I: Dummy<Faker>> Dummy<Faker> for Quantifier<I> {
+    fn dummy_with_rng<R: rand::Rng + ?Sized>(config: &Faker, rng: &mut R) -> Self {
+        let lower = rng.gen_range(0..4);
+        let upper = if rng.gen_bool(0.5) {
+            Some(rng.gen_range(lower.max(1)..8))
+        } else {
+            None
+        };
+
+        Quantifier {
+            lower,
+            upper,
+            marker: Mark::dummy_with_rng(config, rng),
+        }
     }
 }
 
 impl<I> Display for Quantifier<I> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self.bounds {
-            Some((lower, upper)) => match lower {
-                LowerBound::Zero => write!(f, "[0..{upper}]")?,
-                LowerBound::One => write!(f, "[0..{upper}]")?,
-            },
-            None => (),
+        if self.is_unbounded() {
+            return Ok(());
+        }
+
+        let lower = self.lower;
+        match self.upper {
+            Some(upper) => write!(f, "[{lower}..{upper}]"),
+            None => write!(f, "[{lower}..*]"),
+        }
+    }
+}
+
+impl<I> Hash for Quantifier<I> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Quantifiers which could be written before keep the hash they had back then
+        let legacy_bounds = match (self.lower, self.upper) {
+            (0, None) => Some(None),
+            (0, Some(upper)) => Some(Some((LegacyLowerBound::Zero, upper))),
+            (1, Some(upper)) => Some(Some((LegacyLowerBound::One, upper))),
+            _ => None,
+        };
+
+        match legacy_bounds {
+            Some(bounds) => bounds.hash(state),
+            None => {
+                self.lower.hash(state);
+                self.upper.hash(state);
+            }
         }
-        Ok(())
     }
 }
 
 impl<I> PartialEq for Quantifier<I> {
     fn eq(&self, other: &Self) -> bool {
-        self.bounds.eq(&other.bounds)
+        (self.lower, self.upper).eq(&(other.lower, other.upper))
     }
 }
 
@@ -124,7 +200,7 @@ impl<I> PartialOrd for Quantifier<I> {
 
 impl<I> Ord for Quantifier<I> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.bounds.cmp(&other.bounds)
+        (self.lower, self.upper).cmp(&(other.lower, other.upper))
     }
 }
 
@@ -135,3 +211,18 @@ impl<I> Marked<I> for Quantifier<I> {
 }
 
 compose_test! {quantity_compose, Quantifier<I>}
+
+#[test]
+fn quantifier_parse_test() {
+    let parse = |s| {
+        let (_, q) = Quantifier::<&str>::parse(s).unwrap();
+        (q.lower, q.upper, q.to_string())
+    };
+
+    assert_eq!(parse("[1..3]"), (1, Some(3), "[1..3]".to_string()));
+    assert_eq!(parse("[ 2 .. * ]"), (2, None, "[2..*]".to_string()));
+    assert_eq!(parse("[4]"), (4, Some(4), "[4..4]".to_string()));
+    assert_eq!(parse("[0..*]"), (0, None, "".to_string()));
+    assert_eq!(parse(""), (0, None, "".to_string()));
+    assert!(Quantifier::<&str>::parse("[3..1]").is_err());
+}
@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use build_script_shared::compose_test;
+use build_script_shared::dependency_graph::DependencyGraph;
+use build_script_shared::error::ParserError;
+use build_script_shared::error::ParserErrorKind;
+use build_script_shared::error::ParserResult;
+use build_script_shared::error::ParserSlimResult;
+use build_script_shared::parsers::*;
+use build_script_shared::InputType;
+use fake::Dummy;
+use fake::Faker;
+use nom::bytes::complete::*;
+use nom::character::complete::*;
+use nom::combinator::*;
+use nom::error::*;
+use nom::multi::*;
+use nom::sequence::*;
+use nom::Err;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// "type \<ident\>\<generics\> = \<type\>"
+#[derive(PartialEq, Eq, Debug, Hash, Clone, PartialOrd, Ord, Dummy, Serialize, Deserialize)]
+#[serde(bound = "I: Default + Clone")]
+pub struct AliasExp<I> {
+    pub name: Ident<I>,
+    #[serde(flatten)]
+    pub comments: Comments,
+    #[serde(flatten)]
+    pub generics: Generics<I>,
+    pub ty: Types<I>,
+    #[serde(skip)]
+    marker: Mark<I>,
+}
+
+impl<I> AliasExp<I> {
+    pub fn new(
+        comments: Comments,
+        name: Ident<I>,
+        generics: Generics<I>,
+        ty: Types<I>,
+        marker: Mark<I>,
+    ) -> Self {
+        AliasExp {
+            comments,
+            name,
+            generics,
+            ty,
+            marker,
+        }
+    }
+
+    pub fn strip_comments(&mut self) {
+        self.comments.strip_comments();
+    }
+
+    /// Move from one input type to another
+    pub fn map<O, F>(self, f: F) -> AliasExp<O>
+    where
+        F: FnMut(I) -> O + Copy,
+    {
+        AliasExp {
+            comments: self.comments,
+            name: self.name.map(f),
+            generics: self.generics.map(f),
+            ty: self.ty.map(f),
+            marker: self.marker.map(f),
+        }
+    }
+
+    pub fn check_types(
+        &self,
+        reference_types: &HashMap<Ident<I>, Vec<String>>,
+    ) -> ParserSlimResult<I, ()>
+    where
+        I: Clone,
+    {
+        let mut local_references = reference_types.clone();
+        for generic in &self.generics.generics {
+            local_references.insert(generic.letter.clone(), Default::default());
+        }
+
+        self.ty.check_types(&local_references)
+    }
+
+    pub fn check_cycle<'a>(
+        &'a self,
+        dependency_graph: &mut DependencyGraph<'a, I>,
+    ) -> ParserSlimResult<I, ()>
+    where
+        I: Clone,
+    {
+        let type_generics = self.generics.get_meta();
+        self.ty
+            .check_cycle(&self.name, &type_generics, dependency_graph)
+    }
+
+    pub fn check_used(&self) -> ParserSlimResult<I, ()>
+    where
+        I: Clone,
+    {
+        let mut local_references = HashSet::new();
+        for generic in &self.generics.generics {
+            local_references.insert(generic.letter.clone());
+        }
+
+        self.ty.remove_used(&mut local_references);
+
+        for generic in &self.generics.generics {
+            if local_references.contains(&generic.letter) {
+                return Err(Err::Failure(ParserError::new_at(
+                    &generic.letter,
+                    ParserErrorKind::UnusedGeneric,
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The type the alias stands for with its generics replaced by the given types
+    ///
+    /// Missing generics are left as references to the letter
+    pub fn instantiate(&self, generics: &[Box<Types<I>>]) -> Types<I>
+    where
+        I: Clone,
+    {
+        let values: HashMap<String, &Types<I>> = self
+            .generics
+            .generics
+            .iter()
+            .zip(generics)
+            .map(|(generic, ty)| (generic.letter.to_string(), ty.as_ref()))
+            .collect();
+
+        replace_generics(&self.ty, &values)
+    }
+}
+
+fn replace_generics<I: Clone>(ty: &Types<I>, values: &HashMap<String, &Types<I>>) -> Types<I> {
+    match ty {
+        Types::Option { inner, marker } => Types::Option {
+            inner: Box::new(replace_generics(inner, values)),
+            marker: marker.clone(),
+        },
+        Types::List { inner, marker } => Types::List {
+            inner: Box::new(replace_generics(inner, values)),
+            marker: marker.clone(),
+        },
+        Types::Set { inner, marker } => Types::Set {
+            inner: Box::new(replace_generics(inner, values)),
+            marker: marker.clone(),
+        },
+        Types::Map { key, value, marker } => Types::Map {
+            key: Box::new(replace_generics(key, values)),
+            value: Box::new(replace_generics(value, values)),
+            marker: marker.clone(),
+        },
+        Types::Reference {
+            inner,
+            generics,
+            marker,
+        } => {
+            if generics.is_empty() {
+                if let Some(value) = values.get(&inner.to_string()) {
+                    return (*value).clone();
+                }
+            }
+
+            Types::Reference {
+                inner: inner.clone(),
+                generics: generics
+                    .iter()
+                    .map(|generic| Box::new(replace_generics(generic, values)))
+                    .collect(),
+                marker: marker.clone(),
+            }
+        }
+        ty => ty.clone(),
+    }
+}
+
+impl<I: InputType> ParserDeserialize<I> for AliasExp<I> {
+    fn parse(s: I) -> ParserResult<I, Self> {
+        let (s, comments) = Comments::parse(s)?;
+        // Parse the name
+        let (s, _) = ws(terminated(tag("type"), many1(multispace1)))(s)?;
+        // Parse the name
+        let (s, (name, marker)) = context("Parsing Alias type", ws(cut(marked(Ident::ident))))(s)?;
+        let (s, generics) = Generics::parse(s)?;
+        // Parse the aliased type
+        let (s, ty) = owned_context(
+            format!("Parsing {}", name),
+            cut(preceded(ws(char('=')), ws(Types::parse))),
+        )(s)?;
+
+        Ok((
+            s,
+            AliasExp {
+                comments,
+                name,
+                generics,
+                ty,
+                marker,
+            },
+        ))
+    }
+}
+
+impl<I> ParserSerialize for AliasExp<I> {
+    fn compose<W: std::fmt::Write>(
+        &self,
+        f: &mut W,
+        ctx: ComposeContext,
+    ) -> build_script_shared::error::ComposerResult<()> {
+        let indents = ctx.create_indents();
+        self.comments.compose(f, ctx)?;
+        write!(f, "{indents}type ")?;
+        self.name.compose(f, ctx.set_indents(0))?;
+        self.generics.compose(f, ctx.set_indents(0))?;
+        write!(f, " = ")?;
+        self.ty.compose(f, ctx.set_indents(0))?;
+
+        Ok(())
+    }
+}
+
+impl<I> Marked<I> for AliasExp<I> {
+    fn marker(&self) -> &Mark<I> {
+        &self.marker
+    }
+}
+
+pub(crate) struct AliasExpOfType<I> {
+    pub name: Ident<I>,
+    pub generic_count: usize,
+    pub ref_types: TypeReferenceMap,
+}
+
+impl<I: Dummy<Faker> + Clone> Dummy<AliasExpOfType<I>> for AliasExp<I> {
+    fn dummy_with_rng<R: rand::prelude::Rng + ?Sized>(
+        config: &AliasExpOfType<I>,
+        rng: &mut R,
+    ) -> Self {
+        let generics = Generics::dummy_with_rng(&GenericsOfSize(config.generic_count), rng);
+
+        // Create a list of all valid references
+        let mut local_ref_types = config.ref_types.clone();
+        for generic in &generics.generics {
+            local_ref_types.insert(generic.letter.to_string(), 0);
+        }
+
+        let mut ty = Types::dummy_with_rng(&Faker, rng);
+        local_ref_types.pick_valid_reference_type(&mut ty, rng);
+
+        // Use a map with every generic as the key when some of them are not in use
+        let mut all_generics = generics.generics.iter().map(|g| g.letter.clone()).collect();
+        ty.remove_used(&mut all_generics);
+        for generic in generics.generics.iter().rev() {
+            if !all_generics.contains(&generic.letter) {
+                continue;
+            }
+
+            ty = Types::Map {
+                key: Box::new(Types::Reference {
+                    inner: generic.letter.clone(),
+                    generics: Default::default(),
+                    marker: Mark::dummy_with_rng(&Faker, rng),
+                }),
+                value: Box::new(ty),
+                marker: Mark::dummy_with_rng(&Faker, rng),
+            };
+        }
+
+        AliasExp {
+            name: config.name.clone(),
+            comments: Dummy::dummy_with_rng(&Faker, rng),
+            generics,
+            ty,
+            marker: Dummy::dummy_with_rng(&Faker, rng),
+        }
+    }
+}
+
+compose_test! {alias_compose, AliasExp<I>}
+
+#[test]
+fn alias_instantiate_test() {
+    let (_, alias) =
+        AliasExp::<&str>::parse("type Lookup<K, V> = Map<K, List<Option<V>>>").unwrap();
+    let (_, key) = Types::<&str>::parse("String").unwrap();
+    let (_, value) = Types::<&str>::parse("f64").unwrap();
+
+    let ty = alias.instantiate(&[Box::new(key), Box::new(value)]);
+    assert_eq!(ty.to_string(), "Map<String, List<Option<f64>>>");
+}
@@ -29,7 +29,10 @@ const ALLOWED_KEY_ATTRIBUTES: &[&str] = &[RENAME_INC, RENAME_OUT];
 const DERIVE: &str = "derive";
 
 const ALLOWED_FUNCTION_ATTRIBUTES: &[(&str, Option<usize>, Option<&[&str]>)] =
-    &[(DERIVE, None, None)];
+    &[(DERIVE, None, None), (RENAMED_FROM, Some(1), None)];
+
+/// The endpoints of an edge keyed by their source and target
+pub type EndPoints<I> = BTreeMap<(Ident<I>, Ident<I>), EndPoint<I>>;
 
 #[derive(
     PartialEq, Eq, Debug, Hash, Clone, Default, PartialOrd, Ord, Dummy, Serialize, Deserialize,
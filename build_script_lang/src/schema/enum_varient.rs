@@ -8,7 +8,7 @@ use build_script_shared::parsers::{
     marked, surrounded, ws, AllowedAttributes, AllowedFunctionAttribute,
     AllowedFunctionKeyValueAttribute, AllowedKeyValueAttribute, Attributes, Comments,
     ComposeContext, Ident, Mark, Marked, ParserDeserialize, ParserSerialize, TypeReferenceMap,
-    Types,
+    Types, RENAMED_FROM,
 };
 use build_script_shared::{compose_test, InputType};
 use fake::{Dummy, Fake, Faker};
@@ -35,6 +35,7 @@ const ALLOWED_FUNCTION_ATTRIBUTES: &[(&str, Option<usize>, Option<&[&str]>)] = &
         None,
         Some(ALLOWED_DERIVED_FUNCTION_ATTRIBUTE_VALUES),
     ),
+    (RENAMED_FROM, Some(1), None),
 ];
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone, PartialOrd, Ord, Serialize, Deserialize)]
@@ -82,6 +83,14 @@ impl<I> EnumVarient<I> {
         }
     }
 
+    pub fn name_mut(&mut self) -> &mut Ident<I> {
+        match self {
+            EnumVarient::Struct { name, .. } => name,
+            EnumVarient::Unit { name, .. } => name,
+            EnumVarient::Opaque { name, .. } => name,
+        }
+    }
+
     pub fn comments(&self) -> &Comments {
         match self {
             EnumVarient::Struct { comments, .. } => comments,
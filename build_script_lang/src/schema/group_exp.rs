@@ -0,0 +1,224 @@
+use std::collections::HashSet;
+
+use build_script_shared::compose_test;
+use build_script_shared::error::ParserError;
+use build_script_shared::error::ParserErrorKind;
+use build_script_shared::error::ParserResult;
+use build_script_shared::error::ParserSlimResult;
+use build_script_shared::parsers::*;
+use build_script_shared::InputType;
+use fake::Dummy;
+use fake::Faker;
+use nom::bytes::complete::*;
+use nom::character::complete::*;
+use nom::combinator::*;
+use nom::error::*;
+use nom::multi::*;
+use nom::sequence::*;
+use nom::Err;
+use rand::seq::IteratorRandom;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// "group \<ident\> = \<ident\> (| \<ident\>)*"
+///
+/// A named set of nodes which can be used on either side of an endpoint
+#[derive(PartialEq, Eq, Debug, Hash, Clone, PartialOrd, Ord, Dummy, Serialize, Deserialize)]
+#[serde(bound = "I: Default + Clone")]
+pub struct GroupExp<I> {
+    pub name: Ident<I>,
+    #[serde(flatten)]
+    pub comments: Comments,
+    #[dummy(faker = "(Faker, 1..5)")]
+    pub members: Vec<Ident<I>>,
+    #[serde(skip)]
+    marker: Mark<I>,
+}
+
+impl<I> GroupExp<I> {
+    pub fn new(
+        comments: Comments,
+        name: Ident<I>,
+        members: Vec<Ident<I>>,
+        marker: Mark<I>,
+    ) -> Self {
+        GroupExp {
+            comments,
+            name,
+            members,
+            marker,
+        }
+    }
+
+    pub fn strip_comments(&mut self) {
+        self.comments.strip_comments();
+    }
+
+    /// Move from one input type to another
+    pub fn map<O, F>(self, f: F) -> GroupExp<O>
+    where
+        F: FnMut(I) -> O + Copy,
+    {
+        GroupExp {
+            comments: self.comments,
+            name: self.name.map(f),
+            members: self.members.into_iter().map(|m| m.map(f)).collect(),
+            marker: self.marker.map(f),
+        }
+    }
+
+    /// Check that every member is a node and that no node is listed twice
+    ///
+    /// Abstract nodes never hold any data so they can not be members
+    pub fn check_types(
+        &self,
+        node_reference_types: &HashSet<Ident<I>>,
+        abstract_node_types: &HashSet<Ident<I>>,
+    ) -> ParserSlimResult<I, ()>
+    where
+        I: Clone,
+    {
+        let mut seen: Vec<&Ident<I>> = Vec::new();
+        for member in &self.members {
+            if abstract_node_types.contains(member) {
+                return Err(Err::Failure(ParserError::new_at(
+                    member,
+                    ParserErrorKind::AbstractReference(member.to_string()),
+                )));
+            }
+
+            if !node_reference_types.contains(member) {
+                return Err(Err::Failure(ParserError::new_at(
+                    member,
+                    ParserErrorKind::UnknownReference(member.to_string()),
+                )));
+            }
+
+            if let Some(first) = seen.iter().find(|m| **m == member) {
+                return Err(Err::Failure(
+                    vec![
+                        (
+                            member.marker(),
+                            ParserErrorKind::DuplicateDefinition(member.to_string()),
+                        ),
+                        (first.marker(), ParserErrorKind::FirstOccurance),
+                    ]
+                    .into_iter()
+                    .collect(),
+                ));
+            }
+            seen.push(member);
+        }
+
+        Ok(())
+    }
+}
+
+impl<I: InputType> ParserDeserialize<I> for GroupExp<I> {
+    fn parse(s: I) -> ParserResult<I, Self> {
+        let (s, comments) = Comments::parse(s)?;
+        // Parse group keyword
+        let (s, _) = ws(terminated(tag("group"), many1(multispace1)))(s)?;
+        // Parse the name
+        let (s, (name, marker)) = context("Parsing Group type", ws(cut(marked(Ident::ident))))(s)?;
+        // Parse the nodes in the group
+        let (s, members) = owned_context(
+            format!("Parsing {}", name),
+            cut(preceded(
+                ws(char('=')),
+                context(
+                    "Expected node",
+                    verify(punctuated(Ident::ident, '|'), |members: &Vec<_>| {
+                        !members.is_empty()
+                    }),
+                ),
+            )),
+        )(s)?;
+
+        Ok((
+            s,
+            GroupExp {
+                comments,
+                name,
+                members,
+                marker,
+            },
+        ))
+    }
+}
+
+impl<I> ParserSerialize for GroupExp<I> {
+    fn compose<W: std::fmt::Write>(
+        &self,
+        f: &mut W,
+        ctx: ComposeContext,
+    ) -> build_script_shared::error::ComposerResult<()> {
+        let indents = ctx.create_indents();
+        self.comments.compose(f, ctx)?;
+        write!(f, "{indents}group ")?;
+        self.name.compose(f, ctx.set_indents(0))?;
+        write!(f, " =")?;
+        let mut first = true;
+        for member in &self.members {
+            if !first {
+                write!(f, " |")?;
+            } else {
+                first = false;
+            }
+            write!(f, " ")?;
+            member.compose(f, ctx.set_indents(0))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<I> Marked<I> for GroupExp<I> {
+    fn marker(&self) -> &Mark<I> {
+        &self.marker
+    }
+}
+
+pub(crate) struct GroupExpOfType<I> {
+    pub name: Ident<I>,
+    pub node_types: HashSet<String>,
+}
+
+impl<I: Dummy<Faker> + Clone> Dummy<GroupExpOfType<I>> for GroupExp<I> {
+    fn dummy_with_rng<R: rand::prelude::Rng + ?Sized>(
+        config: &GroupExpOfType<I>,
+        rng: &mut R,
+    ) -> Self {
+        // Pick a few distinct nodes to be part of the group
+        let count = rng.gen_range(1..=config.node_types.len().clamp(1, 4));
+        let members = config
+            .node_types
+            .iter()
+            .choose_multiple(rng, count)
+            .into_iter()
+            .map(|name| Ident::new(name, Mark::dummy_with_rng(&Faker, rng)))
+            .collect();
+
+        GroupExp {
+            name: config.name.clone(),
+            comments: Dummy::dummy_with_rng(&Faker, rng),
+            members,
+            marker: Dummy::dummy_with_rng(&Faker, rng),
+        }
+    }
+}
+
+compose_test! {group_compose, GroupExp<I>}
+
+#[test]
+fn group_parse_test() {
+    let (_, group) = GroupExp::<&str>::parse("group Asset = Building | Vehicle|Equipment").unwrap();
+    let members: Vec<_> = group.members.iter().map(|m| m.to_string()).collect();
+
+    assert_eq!(members, vec!["Building", "Vehicle", "Equipment"]);
+    assert_eq!(
+        group.serialize_to_string().unwrap(),
+        "group Asset = Building | Vehicle | Equipment"
+    );
+    assert!(GroupExp::<&str>::parse("group Asset = ").is_err());
+}
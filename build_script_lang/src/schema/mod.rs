@@ -1,20 +1,26 @@
+mod alias_exp;
 mod edge_exp;
 mod enum_exp;
 mod enum_varient;
+mod group_exp;
 mod import_exp;
 mod node_exp;
 mod schema;
+mod schema_merge;
 mod schema_stm;
 mod schema_stm_type;
 mod struct_exp;
 
 pub use crate::generic::*;
+pub use alias_exp::*;
 pub use edge_exp::*;
 pub use enum_exp::*;
 pub use enum_varient::*;
+pub use group_exp::*;
 pub use import_exp::*;
 pub use node_exp::*;
 pub use schema::*;
+pub use schema_merge::*;
 pub use schema_stm::*;
 pub use schema_stm_type::*;
 pub use struct_exp::*;
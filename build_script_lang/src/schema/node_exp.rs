@@ -1,8 +1,13 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
 
+use super::FieldValue;
 use super::FieldWithReferences;
 use super::Fields;
+use super::Schema;
+use super::SchemaStm;
+use super::SchemaStmType;
 use build_script_shared::compose_test;
 use build_script_shared::dependency_graph::DependencyGraph;
 use build_script_shared::error::ParserError;
@@ -32,14 +37,19 @@ const ALLOWED_ATTRIBUTES: &[(&str, Option<usize>, Option<&[&str]>)] = &[
     (RENAME_INC, Some(2), None),
     (RENAME_OUT, Some(2), None),
     (DERIVE, None, None),
+    (RENAMED_FROM, Some(1), None),
 ];
 
-#[derive(
-    PartialEq, Eq, Debug, Hash, Clone, Default, PartialOrd, Ord, Dummy, Serialize, Deserialize,
-)]
+#[derive(PartialEq, Eq, Debug, Clone, Default, PartialOrd, Ord, Dummy, Serialize, Deserialize)]
 #[serde(bound = "I: Default + Clone")]
 pub struct NodeExp<I> {
     pub name: Ident<I>,
+    /// Abstract nodes only exist to be inherited from
+    #[serde(default)]
+    pub is_abstract: bool,
+    /// The node the fields are inherited from
+    #[serde(default)]
+    pub parent: Option<Ident<I>>,
     #[dummy(faker = "AllowedFunctionAttribute(ALLOWED_ATTRIBUTES)")]
     #[serde(flatten)]
     pub attributes: Attributes<I>,
@@ -63,6 +73,8 @@ impl<I> NodeExp<I> {
             comments,
             attributes,
             name,
+            is_abstract: false,
+            parent: None,
             fields,
             marker,
         }
@@ -77,6 +89,8 @@ impl<I> NodeExp<I> {
             comments: self.comments,
             attributes: self.attributes.map(f),
             name: self.name.map(f),
+            is_abstract: self.is_abstract,
+            parent: self.parent.map(|parent| parent.map(f)),
             fields: self.fields.map(f),
             marker: self.marker.map(f),
         }
@@ -156,16 +170,94 @@ impl<I> NodeExp<I> {
         self.fields
             .check_cycle(&self.name, &type_generics, dependency_graph)
     }
+
+    /// The chain of nodes this node inherits from starting with the root
+    pub fn ancestors<'a>(
+        &'a self,
+        schema: &'a Schema<I>,
+    ) -> ParserSlimResult<I, Vec<&'a NodeExp<I>>>
+    where
+        I: Clone,
+    {
+        let mut ancestors: Vec<&NodeExp<I>> = Vec::new();
+        let mut current = self;
+        while let Some(parent) = &current.parent {
+            let Some(SchemaStm::Node(parent_node)) =
+                schema.get_type(Some(SchemaStmType::Node), parent)
+            else {
+                return Err(Err::Failure(ParserError::new_at(
+                    parent,
+                    ParserErrorKind::UnknownReference(parent.to_string()),
+                )));
+            };
+
+            if parent_node.name == self.name || ancestors.iter().any(|n| n.name == parent_node.name)
+            {
+                return Err(Err::Failure(ParserError::new_at(
+                    parent,
+                    ParserErrorKind::CyclicReference,
+                )));
+            }
+
+            ancestors.push(parent_node);
+            current = parent_node;
+        }
+
+        ancestors.reverse();
+        Ok(ancestors)
+    }
+
+    /// All fields of the node including the ones inherited from its ancestors
+    ///
+    /// The inherited fields come first and the order of the fields further down the chain is offset to follow them
+    pub fn resolve_fields(&self, schema: &Schema<I>) -> ParserSlimResult<I, Fields<I>>
+    where
+        I: Clone,
+    {
+        let mut fields: Vec<FieldValue<I>> = Vec::new();
+        let mut offset = 0;
+        for node in self.ancestors(schema)?.into_iter().chain([self]) {
+            for field in node.fields.iter() {
+                if let Some(first) = fields.iter().find(|f| f.name == field.name) {
+                    return Err(Err::Failure(
+                        vec![
+                            (
+                                field.name.marker(),
+                                ParserErrorKind::DuplicateDefinition(field.name.to_string()),
+                            ),
+                            (first.name.marker(), ParserErrorKind::FirstOccurance),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    ));
+                }
+
+                let mut field = field.clone();
+                field.order += offset;
+                fields.push(field);
+            }
+
+            offset = fields.iter().map(|f| f.order + 1).max().unwrap_or(offset);
+        }
+
+        Ok(Fields::new(fields, self.fields.marker().clone()))
+    }
 }
 
 impl<I: InputType> ParserDeserialize<I> for NodeExp<I> {
     fn parse(s: I) -> ParserResult<I, Self> {
         let (s, comments) = Comments::parse(s)?;
         let (s, attributes) = Attributes::parse(s)?;
+        let (s, is_abstract) = map(
+            opt(ws(terminated(tag("abstract"), many1(multispace1)))),
+            |abs| abs.is_some(),
+        )(s)?;
         // Parse the name
         let (s, _) = ws(terminated(tag("node"), many1(multispace1)))(s)?;
         // Parse the name
         let (s, (name, marker)) = context("Parsing Node type", ws(cut(marked(Ident::ident))))(s)?;
+        // Parse the node it inherits from
+        let (s, parent) = opt(preceded(ws(char(':')), cut(ws(Ident::ident))))(s)?;
         // Parse the list of fields
         let (s, fields) = owned_context(format!("Parsing {}", name), cut(Fields::parse))(s)?;
 
@@ -182,6 +274,8 @@ impl<I: InputType> ParserDeserialize<I> for NodeExp<I> {
                 comments,
                 attributes,
                 name,
+                is_abstract,
+                parent,
                 fields,
                 marker,
             },
@@ -198,8 +292,16 @@ impl<I> ParserSerialize for NodeExp<I> {
         let indents = ctx.create_indents();
         self.comments.compose(f, ctx)?;
         self.attributes.compose(f, ctx)?;
-        write!(f, "{indents}node ")?;
+        if self.is_abstract {
+            write!(f, "{indents}abstract node ")?;
+        } else {
+            write!(f, "{indents}node ")?;
+        }
         self.name.compose(f, ctx)?;
+        if let Some(parent) = &self.parent {
+            write!(f, ": ")?;
+            parent.compose(f, ctx)?;
+        }
         write!(f, " ")?;
         self.fields.compose(f, ctx)?;
 
@@ -207,6 +309,22 @@ impl<I> ParserSerialize for NodeExp<I> {
     }
 }
 
+impl<I: Hash> Hash for NodeExp<I> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        // Nodes without inheritance keep the hash they had before inheritance was added
+        if self.is_abstract {
+            self.is_abstract.hash(state);
+        }
+        if let Some(parent) = &self.parent {
+            parent.hash(state);
+        }
+        self.attributes.hash(state);
+        self.comments.hash(state);
+        self.fields.hash(state);
+    }
+}
+
 impl<I> Marked<I> for NodeExp<I> {
     fn marker(&self) -> &Mark<I> {
         &self.marker
@@ -229,6 +347,10 @@ impl<I: Dummy<Faker> + Clone> Dummy<NodeExpOfType<I>> for NodeExp<I> {
         // Set the name to the expected value
         exp.name = config.name.clone();
 
+        // Inheritance is left out as the inherited fields must not collide with the fields of the node
+        exp.is_abstract = false;
+        exp.parent = None;
+
         // Make sure all type references point to existing types
         exp.fields = Fields::dummy_with_rng(&FieldWithReferences(config.ref_types.clone()), rng);
 
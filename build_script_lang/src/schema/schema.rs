@@ -15,6 +15,7 @@ use nom::Err;
 use serde::Deserialize;
 use serde::Serialize;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt::Debug;
@@ -124,6 +125,32 @@ impl<I> Schema<I> {
         })
     }
 
+    pub fn aliases(&self) -> impl Iterator<Item = &AliasExp<I>>
+    where
+        I: Ord,
+    {
+        self.iter().filter_map(|stm| {
+            if let SchemaStm::Alias(n) = stm {
+                Some(n)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn groups(&self) -> impl Iterator<Item = &GroupExp<I>>
+    where
+        I: Ord,
+    {
+        self.iter().filter_map(|stm| {
+            if let SchemaStm::Group(n) = stm {
+                Some(n)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &SchemaStm<I>>
     where
         I: Ord,
@@ -175,7 +202,7 @@ impl<I> Schema<I> {
                 SchemaStm::Node(n) => n.check_attributes(),
                 SchemaStm::Struct(s) => s.check_attributes(),
                 SchemaStm::Enum(e) => e.check_attributes(),
-                SchemaStm::Import(_) => Ok(()),
+                SchemaStm::Import(_) | SchemaStm::Alias(_) | SchemaStm::Group(_) => Ok(()),
             }?;
         }
 
@@ -189,6 +216,8 @@ impl<I> Schema<I> {
     {
         let mut all_reference_types = HashSet::new();
         let mut node_reference_types = HashSet::new();
+        let mut abstract_node_types = HashSet::new();
+        let mut group_reference_types = HashSet::new();
         let mut data_reference_types = HashMap::new();
 
         for stm in &self.content {
@@ -214,7 +243,18 @@ impl<I> Schema<I> {
                 ));
             }
 
+            if PRIMITIVE_TYPES.contains(&type_name.as_str()) {
+                return Err(Err::Failure(ParserError::new_at(
+                    type_name,
+                    ParserErrorKind::PrimitiveTypeName(type_name.to_string()),
+                )));
+            }
+
             match stm {
+                // Abstract nodes can never be created so nothing can refer to them
+                SchemaStm::Node(n) if n.is_abstract => {
+                    abstract_node_types.insert(type_name.clone());
+                }
                 SchemaStm::Node(_) => {
                     node_reference_types.insert(type_name.clone());
                     data_reference_types.insert(type_name.clone(), Default::default());
@@ -231,9 +271,21 @@ impl<I> Schema<I> {
                 SchemaStm::Edge(_) => {
                     data_reference_types.insert(type_name.clone(), Default::default());
                 }
+                SchemaStm::Alias(a) => {
+                    data_reference_types.insert(type_name.clone(), a.generics.get_meta());
+                }
+                SchemaStm::Group(_) => {
+                    group_reference_types.insert(type_name.clone());
+                }
             }
         }
 
+        // Groups can only be used as endpoints
+        let endpoint_reference_types: HashSet<_> = node_reference_types
+            .union(&group_reference_types)
+            .cloned()
+            .collect();
+
         for stm in &self.content {
             match stm {
                 SchemaStm::Node(n) => {
@@ -241,26 +293,116 @@ impl<I> Schema<I> {
                 }
                 SchemaStm::Struct(s) => s.check_types(&data_reference_types)?,
                 SchemaStm::Edge(e) => {
-                    e.check_types(&data_reference_types, &node_reference_types)?
+                    e.check_types(&data_reference_types, &endpoint_reference_types)?
                 }
                 SchemaStm::Import(_) => (),
                 SchemaStm::Enum(e) => e.check_types(&data_reference_types)?,
+                SchemaStm::Alias(a) => a.check_types(&data_reference_types)?,
+                SchemaStm::Group(g) => {
+                    g.check_types(&node_reference_types, &abstract_node_types)?
+                }
+            }
+        }
+
+        // The groups must not make an edge connect the same two nodes twice
+        for stm in &self.content {
+            if let SchemaStm::Edge(e) = stm {
+                self.expand_endpoints(e)?;
+            }
+        }
+
+        // Default values can only be checked once we know which enums and aliases exists
+        for stm in &self.content {
+            match stm {
+                SchemaStm::Node(n) => self.check_defaults(&n.fields)?,
+                SchemaStm::Struct(s) => self.check_defaults(&s.fields)?,
+                SchemaStm::Edge(e) => self.check_defaults(&e.fields)?,
+                SchemaStm::Enum(e) => {
+                    for varient in &e.varients {
+                        if let EnumVarient::Struct { fields, .. } = varient {
+                            self.check_defaults(fields)?;
+                        }
+                    }
+                }
+                SchemaStm::Import(_) | SchemaStm::Alias(_) | SchemaStm::Group(_) => (),
+            }
+        }
+
+        // The inherited fields must not collide with the fields of the node
+        let mut resolved_fields = Vec::new();
+        for stm in &self.content {
+            if let SchemaStm::Node(n) = stm {
+                resolved_fields.push(n.resolve_fields(self)?);
             }
         }
 
         let mut node_name_type: Option<(&Types<I>, String)> = None;
         let mut edge_name_type: Option<(&Types<I>, String)> = None;
+        for fields in &resolved_fields {
+            fields.check_field_type("name", &mut node_name_type)?;
+        }
+
         for stm in &self.content {
             match stm {
-                SchemaStm::Node(n) => {
-                    n.fields.check_field_type("name", &mut node_name_type)?;
-                }
+                SchemaStm::Node(_) => (),
                 SchemaStm::Edge(e) => {
                     e.fields.check_field_type("name", &mut edge_name_type)?;
                 }
                 SchemaStm::Struct(_) => (),
                 SchemaStm::Import(_) => (),
                 SchemaStm::Enum(_) => (),
+                SchemaStm::Alias(_) => (),
+                SchemaStm::Group(_) => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check that default values fit the field type with any aliases replaced
+    /// and that default varients refers to a unit varient of the enum used as field type
+    fn check_defaults(&self, fields: &Fields<I>) -> ParserSlimResult<I, ()>
+    where
+        I: Clone,
+    {
+        for field_value in fields.iter() {
+            let Some(default) = &field_value.default else {
+                continue;
+            };
+
+            let expanded_type = self.expand_type(&field_value.field_type);
+            default.check_type(&expanded_type)?;
+
+            let varient_name = match default {
+                Literal::Varient { name } => name,
+                _ => continue,
+            };
+
+            let mut field_type = &expanded_type;
+            while let Types::Option { inner, .. } = field_type {
+                field_type = inner;
+            }
+
+            let enum_exp = match field_type {
+                Types::Reference { inner, .. } => self.get_type(Some(SchemaStmType::Enum), inner),
+                _ => None,
+            };
+
+            let is_unit_varient = match enum_exp {
+                Some(SchemaStm::Enum(e)) => e.varients.iter().any(|varient| {
+                    matches!(varient, EnumVarient::Unit { .. }) && varient.name() == varient_name
+                }),
+                _ => false,
+            };
+
+            if !is_unit_varient {
+                return Err(Err::Failure(ParserError::new_at(
+                    varient_name,
+                    ParserErrorKind::InvalidDefaultValue(
+                        varient_name.to_string(),
+                        field_value.field_type.to_string(),
+                    ),
+                )));
             }
         }
 
@@ -293,7 +435,10 @@ impl<I> Schema<I> {
                 SchemaStm::Enum(e) => {
                     e.check_cycle(&mut dependency_graph)?;
                 }
-                SchemaStm::Import(_) => (),
+                SchemaStm::Alias(a) => {
+                    a.check_cycle(&mut dependency_graph)?;
+                }
+                SchemaStm::Import(_) | SchemaStm::Group(_) => (),
             }
         }
 
@@ -309,13 +454,226 @@ impl<I> Schema<I> {
             match stm {
                 SchemaStm::Enum(e) => e.check_used()?,
                 SchemaStm::Struct(s) => s.check_used()?,
-                SchemaStm::Node(_) | SchemaStm::Edge(_) | SchemaStm::Import(_) => (),
+                SchemaStm::Alias(a) => a.check_used()?,
+                SchemaStm::Node(_)
+                | SchemaStm::Edge(_)
+                | SchemaStm::Import(_)
+                | SchemaStm::Group(_) => (),
             }
         }
 
         Ok(())
     }
 
+    /// Replace every reference to an alias with the type it stands for
+    ///
+    /// The schema must have passed the integrity check as cyclic aliases never stop expanding
+    pub fn expand_type(&self, ty: &Types<I>) -> Types<I>
+    where
+        I: Clone,
+    {
+        match ty {
+            Types::Option { inner, marker } => Types::Option {
+                inner: Box::new(self.expand_type(inner)),
+                marker: marker.clone(),
+            },
+            Types::List { inner, marker } => Types::List {
+                inner: Box::new(self.expand_type(inner)),
+                marker: marker.clone(),
+            },
+            Types::Set { inner, marker } => Types::Set {
+                inner: Box::new(self.expand_type(inner)),
+                marker: marker.clone(),
+            },
+            Types::Map { key, value, marker } => Types::Map {
+                key: Box::new(self.expand_type(key)),
+                value: Box::new(self.expand_type(value)),
+                marker: marker.clone(),
+            },
+            Types::Reference {
+                inner,
+                generics,
+                marker,
+            } => {
+                let generics: Vec<_> = generics
+                    .iter()
+                    .map(|generic| Box::new(self.expand_type(generic)))
+                    .collect();
+
+                match self.get_type(Some(SchemaStmType::Alias), inner) {
+                    Some(SchemaStm::Alias(a)) => self.expand_type(&a.instantiate(&generics)),
+                    _ => Types::Reference {
+                        inner: inner.clone(),
+                        generics,
+                        marker: marker.clone(),
+                    },
+                }
+            }
+            ty => ty.clone(),
+        }
+    }
+
+    /// Copy of the schema where all field types have their aliases expanded
+    ///
+    /// The aliases are kept but they no longer refer to each other
+    pub fn expand_aliases(&self) -> Schema<I>
+    where
+        I: Clone,
+    {
+        let mut schema = self.clone();
+        for stm in &mut schema.content {
+            match stm {
+                SchemaStm::Node(n) => self.expand_fields(&mut n.fields),
+                SchemaStm::Struct(s) => self.expand_fields(&mut s.fields),
+                SchemaStm::Edge(e) => self.expand_fields(&mut e.fields),
+                SchemaStm::Enum(e) => {
+                    for varient in &mut e.varients {
+                        match varient {
+                            EnumVarient::Struct { fields, .. } => self.expand_fields(fields),
+                            EnumVarient::Opaque { ty, .. } => *ty = self.expand_type(ty),
+                            EnumVarient::Unit { .. } => (),
+                        }
+                    }
+                }
+                SchemaStm::Alias(a) => a.ty = self.expand_type(&a.ty),
+                SchemaStm::Import(_) | SchemaStm::Group(_) => (),
+            }
+        }
+
+        schema
+    }
+
+    /// Copy of the schema where every node holds the fields inherited from its ancestors
+    ///
+    /// The nodes keep their parent so the inheritance can still be looked up
+    pub fn resolve_inheritance(&self) -> Schema<I>
+    where
+        I: Clone,
+    {
+        let mut schema = self.clone();
+        for stm in &mut schema.content {
+            if let SchemaStm::Node(n) = stm {
+                if let Ok(fields) = n.resolve_fields(self) {
+                    n.fields = fields;
+                }
+            }
+        }
+
+        schema
+    }
+
+    /// Copy of the schema as seen by the data
+    ///
+    /// Every node holds its inherited fields and abstract nodes are left out as they can never be created
+    pub fn concrete_schema(&self) -> Schema<I>
+    where
+        I: Clone,
+    {
+        let mut schema = self.resolve_inheritance();
+        schema
+            .content
+            .retain(|stm| !matches!(stm, SchemaStm::Node(n) if n.is_abstract));
+        schema
+    }
+
+    /// All nodes that can be created
+    pub fn concrete_nodes(&self) -> impl Iterator<Item = &NodeExp<I>>
+    where
+        I: Ord,
+    {
+        self.nodes().filter(|n| !n.is_abstract)
+    }
+
+    /// All nodes inheriting from the given node either directly or through other nodes
+    pub fn descendants<'a>(&'a self, node: &'a NodeExp<I>) -> impl Iterator<Item = &'a NodeExp<I>>
+    where
+        I: Clone + Ord,
+    {
+        self.nodes().filter(move |n| {
+            n.ancestors(self)
+                .map(|ancestors| ancestors.iter().any(|a| a.name == node.name))
+                .unwrap_or_default()
+        })
+    }
+
+    /// The nodes in the group with the given name or the name itself if it is not a group
+    pub fn group_members<'a>(&'a self, name: &'a Ident<I>) -> Vec<&'a Ident<I>> {
+        match self.get_type(Some(SchemaStmType::Group), name) {
+            Some(SchemaStm::Group(g)) => g.members.iter().collect(),
+            _ => vec![name],
+        }
+    }
+
+    /// The endpoints of the edge with every group replaced by the nodes in it
+    ///
+    /// Every pair of nodes gets a copy of the endpoint it came from
+    pub fn expand_endpoints(
+        &self,
+        edge: &EdgeExp<I>,
+    ) -> ParserSlimResult<I, EndPoints<I>>
+    where
+        I: Clone,
+    {
+        let mut expanded: EndPoints<I> = BTreeMap::new();
+        for endpoint in edge.endpoints.values() {
+            for source in self.group_members(&endpoint.source) {
+                for target in self.group_members(&endpoint.target) {
+                    let key = (source.clone(), target.clone());
+                    if let Some(first) = expanded.get(&key) {
+                        return Err(Err::Failure(
+                            vec![
+                                (
+                                    endpoint.marker(),
+                                    ParserErrorKind::DuplicateDefinition(format!(
+                                        "{source} -> {target}"
+                                    )),
+                                ),
+                                (first.marker(), ParserErrorKind::FirstOccurance),
+                            ]
+                            .into_iter()
+                            .collect(),
+                        ));
+                    }
+
+                    let mut pair = endpoint.clone();
+                    pair.source = source.clone();
+                    pair.target = target.clone();
+                    expanded.insert(key, pair);
+                }
+            }
+        }
+
+        Ok(expanded)
+    }
+
+    /// Copy of the schema where the endpoints only refer to nodes
+    ///
+    /// The groups are kept but no edge uses them anymore
+    pub fn expand_groups(&self) -> Schema<I>
+    where
+        I: Clone,
+    {
+        let mut schema = self.clone();
+        for stm in &mut schema.content {
+            if let SchemaStm::Edge(e) = stm {
+                if let Ok(endpoints) = self.expand_endpoints(e) {
+                    e.endpoints = endpoints;
+                }
+            }
+        }
+
+        schema
+    }
+
+    fn expand_fields(&self, fields: &mut Fields<I>)
+    where
+        I: Clone,
+    {
+        for field_value in fields.iter_mut() {
+            field_value.field_type = self.expand_type(&field_value.field_type);
+        }
+    }
+
     pub fn strip_comments(&mut self) {
         for stm in &mut self.content {
             stm.strip_comments();
@@ -376,7 +734,19 @@ impl<I> Schema<I> {
         }
     }
 
+    /// Hash of the canonical form of the schema
+    ///
+    /// The hash is stable across platforms and rust versions
     pub fn get_hash(&self) -> u64
+    where
+        I: Clone + Ord,
+    {
+        stable_hash(self.get_canonical_form().as_bytes())
+    }
+
+    /// Hash of the schema as it was before HASH_VERSION 1  
+    /// This is only used to check old changesets
+    pub fn get_legacy_hash(&self) -> u64
     where
         I: Hash + Ord + Debug,
     {
@@ -385,6 +755,37 @@ impl<I> Schema<I> {
         s.finish()
     }
 
+    /// Write the schema with the types in sorted order
+    ///
+    /// Only doc comments are part of the canonical form
+    /// as the other comments are not part of the schema
+    pub fn get_canonical_form(&self) -> String
+    where
+        I: Clone + Ord,
+    {
+        let mut schema = self.clone();
+        schema.comments.strip_comments();
+        schema.strip_comments();
+
+        let mut s = String::new();
+        let ctx = ComposeContext::default();
+
+        // Writing to a string does not fail
+        let _ = schema.comments.compose(&mut s, ctx);
+        s.push_str(&format!("<{}", schema.version));
+        if let Some(handler) = &schema.handler {
+            s.push_str(&format!(", handler={}", handler));
+        }
+        s.push_str(">\n");
+
+        for stm in schema.iter() {
+            let _ = stm.compose(&mut s, ctx);
+            s.push('\n');
+        }
+
+        s
+    }
+
     /// Parse a schema without doing performing and integrity check
     pub fn parse_no_check(requires_header: bool) -> impl Fn(I) -> ParserResult<I, Self>
     where
@@ -517,6 +918,16 @@ impl<I: Dummy<Faker> + Clone> Dummy<Faker> for Schema<I> {
         while let Some((ident, order, generic_count, ty)) = content_type.get_mut(i) {
             let name = ident.to_string();
             match ty {
+                SchemaStmType::Group => {
+                    // Make sure the name is globally unique
+                    if all_types.contains(&*name) {
+                        // Update the name for next time we check
+                        *ident = Ident::dummy_with_rng(&Faker, rng);
+                        continue;
+                    } else {
+                        all_types.insert(name);
+                    }
+                }
                 SchemaStmType::Node => {
                     // Make sure the name is globally unique
                     if all_types.contains(&*name) {
@@ -535,7 +946,7 @@ impl<I: Dummy<Faker> + Clone> Dummy<Faker> for Schema<I> {
                         node_type_names.insert(name);
                     }
                 }
-                SchemaStmType::Struct | SchemaStmType::Enum => {
+                SchemaStmType::Struct | SchemaStmType::Enum | SchemaStmType::Alias => {
                     // Make sure the name is globally unique
                     if all_types.contains(&*name) {
                         // Update the name for next time we check
@@ -591,6 +1002,8 @@ impl<I: Dummy<Faker> + Clone> Dummy<Faker> for Schema<I> {
             },
             content: content_type
                 .into_iter()
+                // A group needs at least one node
+                .filter(|(_, _, _, ty)| ty != &SchemaStmType::Group || !node_type_names.is_empty())
                 .map(|(name, order, generic_count, ty)| {
                     SchemaStm::dummy_with_rng(
                         &SchemaStmOfType {
@@ -662,6 +1075,82 @@ fn schema_reorder_hash_test() {
     assert_eq!(hash0, hash1);
 }
 
+#[test]
+fn schema_stable_hash_test() {
+    let s0 = "
+    <V0>
+    node A {
+        name: String
+    };
+    enum B {
+        C
+    };";
+
+    // Same schema with the types in another order and different whitespace
+    let s1 = "<V0>
+    enum B { C };
+    node A { name: String };";
+
+    let (_, schema0) = Schema::parse(s0).unwrap();
+    let (_, schema1) = Schema::parse(s1).unwrap();
+
+    assert_eq!(schema0.get_hash(), schema1.get_hash());
+
+    // The hash must never change for the same HASH_VERSION
+    assert_eq!(schema0.get_hash(), 0xf542f585f09260ca);
+}
+
+#[test]
+fn schema_legacy_hash_test() {
+    let s0 = "
+    <V0>
+    node A {
+        b: String
+    };";
+
+    let s1 = "
+    <V0.1, handler=Handler>
+    /// A person
+    node Person {
+        /// Full name
+        pub name: String,
+        @json(alias = nick)
+        nickname: Option<String>,
+        age: u8,
+        scores: List<f64>,
+        tags: Map<String, i64>,
+        home: Address<Country>
+    };
+    node Car {
+        wheels: usize
+    };
+    /// Ownership
+    @derive(Hash)
+    edge Owns(Person[0..3] => Car[1..1], Car => Person) {
+        since: u32
+    };
+    @derive(Hash)
+    struct Address<T> {
+        street: String,
+        country: T
+    };
+    @json(untagged)
+    enum Country {
+        Denmark,
+        /// Others
+        Other { name: String },
+        Code(u16)
+    };";
+
+    let (_, schema0) = Schema::parse(s0).unwrap();
+    let (_, schema1) = Schema::parse(s1).unwrap();
+
+    // Hashes written by get_hash before HASH_VERSION 1
+    // Old changesets can only be rehashed as long as these stay the same
+    assert_eq!(schema0.get_legacy_hash(), 0xe1fed95fe7d7b5f4);
+    assert_eq!(schema1.get_legacy_hash(), 0xf6c9be53236f6b8d);
+}
+
 #[test]
 fn cycle_test() {
     // This has the cycle A -> B -> C -> D -> E -> A
@@ -765,3 +1254,307 @@ fn cycle_proxy_test() {
         )))
     );
 }
+
+#[test]
+fn default_value_test() {
+    let s0 = "
+    <Defaults>
+    node A {
+        count: u8 = 3,
+        ratio: f64 = -0.5,
+        label: String = \"none\",
+        active: bool = true,
+        parent: Option<String> = None,
+        tags: List<String> = [],
+        lookup: Map<String, u8> = {},
+        state: State = Idle,
+        aliased: Current = Idle,
+        maybe: MaybeState = Idle
+    };
+
+    type Current = State;
+    type MaybeState = Option<Current>;
+
+    enum State {
+        Idle,
+        Busy(String)
+    };";
+
+    let (_, schema) = Schema::parse(s0).unwrap();
+    let s = schema.serialize_to_string().unwrap();
+    assert_eq!(Schema::parse(s.as_str()).map(|(_, s)| s), Ok(schema));
+
+    // Value does not fit in the type
+    let s1 = "
+    <Defaults1>
+    node A {
+        count: u8 = 300
+    };";
+
+    // Only unit varients can be used as defaults
+    let s2 = "
+    <Defaults2>
+    node A {
+        state: State = Busy
+    };
+
+    enum State {
+        Idle,
+        Busy(String)
+    };";
+
+    assert_eq!(
+        Schema::parse(s1),
+        ParserResult::<_, _>::Err(Err::Failure(ParserError::new(
+            "300",
+            ParserErrorKind::InvalidDefaultValue("300".to_string(), "u8".to_string())
+        )))
+    );
+    assert_eq!(
+        Schema::parse(s2),
+        ParserResult::<_, _>::Err(Err::Failure(ParserError::new(
+            "Busy",
+            ParserErrorKind::InvalidDefaultValue("Busy".to_string(), "State".to_string())
+        )))
+    );
+
+    // Aliases are resolved before looking up the varient
+    let s3 = "
+    <Defaults3>
+    node A {
+        state: MaybeState = Busy
+    };
+
+    type MaybeState = Option<State>;
+
+    enum State {
+        Idle,
+        Busy(String)
+    };";
+
+    assert_eq!(
+        Schema::parse(s3),
+        ParserResult::<_, _>::Err(Err::Failure(ParserError::new(
+            "Busy",
+            ParserErrorKind::InvalidDefaultValue("Busy".to_string(), "MaybeState".to_string())
+        )))
+    );
+}
+
+#[test]
+fn alias_test() {
+    let s0 = "
+    <V0>
+    type Scores<K> = Map<K, Grade>;
+    type Grade = u16;
+    node Student {
+        grades: Scores<String>,
+        best: Option<Grade>
+    };";
+
+    // Has cycle A -> B -> A through the aliases
+    let s1 = "
+    <V1>
+    type A = Option<B>;
+    type B = List<A>;";
+
+    let (_, schema0) = Schema::parse(s0).unwrap();
+    let expanded = schema0.expand_aliases();
+    let student = expanded.nodes().next().unwrap();
+    let types: Vec<_> = student
+        .fields
+        .iter()
+        .map(|field| field.field_type.to_string())
+        .collect();
+
+    assert_eq!(types, vec!["Map<String, u16>", "Option<u16>"]);
+    assert_eq!(
+        Schema::parse(s1),
+        ParserResult::<_, _>::Err(Err::Failure(ParserError::new(
+            "A",
+            ParserErrorKind::CyclicReference
+        )))
+    );
+}
+
+#[test]
+fn inheritance_test() {
+    let s0 = "
+    <V0>
+    abstract node Person {
+        name: String,
+        owner: String
+    };
+    node Teacher: Person {
+        subject: String
+    };
+    edge Teaches(Teacher => Teacher) {};";
+
+    // Has cycle A -> B -> A through the parents
+    let s1 = "
+    <V1>
+    node A: B {
+        a: u8
+    };
+    node B: A {
+        b: u8
+    };";
+
+    // Teacher already has a name from Person
+    let s2 = "
+    <V2>
+    node Person {
+        name: String
+    };
+    node Teacher: Person {
+        name: String
+    };";
+
+    // Abstract nodes cannot be used as endpoints
+    let s3 = "
+    <V3>
+    abstract node Person {
+        name: String
+    };
+    edge Knows(Person => Person) {};";
+
+    let (_, schema0) = Schema::parse(s0).unwrap();
+    let resolved = schema0.resolve_inheritance();
+    let teacher = resolved.concrete_nodes().next().unwrap();
+    let fields: Vec<_> = teacher
+        .fields
+        .iter()
+        .map(|field| format!("{}: {}", field.name, field.order))
+        .collect();
+
+    assert_eq!(fields, vec!["name: 0", "owner: 1", "subject: 2"]);
+    assert_eq!(
+        Schema::parse(s1),
+        ParserResult::<_, _>::Err(Err::Failure(ParserError::new(
+            "A",
+            ParserErrorKind::CyclicReference
+        )))
+    );
+    let mut duplicate = ParserError::new(
+        "name",
+        ParserErrorKind::DuplicateDefinition("name".to_string()),
+    );
+    duplicate.push("name", ParserErrorKind::FirstOccurance);
+    assert_eq!(
+        Schema::parse(s2),
+        ParserResult::<_, _>::Err(Err::Failure(duplicate))
+    );
+    assert_eq!(
+        Schema::parse(s3),
+        ParserResult::<_, _>::Err(Err::Failure(ParserError::new(
+            "Person",
+            ParserErrorKind::UnknownReference("Person".to_string())
+        )))
+    );
+}
+
+#[test]
+fn group_test() {
+    let s0 = "
+    <V0>
+    node Person {};
+    node Building {};
+    node Vehicle {};
+    group Asset = Building | Vehicle;
+    edge Owns(Person => Asset, Asset => Person) {};";
+
+    // Person => Building is already part of Person => Asset
+    let s1 = "
+    <V1>
+    node Person {};
+    node Building {};
+    group Asset = Building;
+    edge Owns(Person => Asset, Person => Building) {};";
+
+    // Groups can only hold nodes
+    let s2 = "
+    <V2>
+    node Person {};
+    struct Address {};
+    group Asset = Person | Address;";
+
+    // Abstract nodes are never created so they can not be members
+    let s3 = "
+    <V3>
+    abstract node Owner {};
+    node Person: Owner {};
+    group Owners = Person | Owner;";
+
+    let (_, schema0) = Schema::parse(s0).unwrap();
+    let expanded = schema0.expand_groups();
+    let owns = expanded.edges().next().unwrap();
+    let pairs: Vec<_> = owns
+        .endpoints
+        .keys()
+        .map(|(source, target)| format!("{source} => {target}"))
+        .collect();
+
+    assert_eq!(
+        pairs,
+        vec![
+            "Building => Person",
+            "Person => Building",
+            "Person => Vehicle",
+            "Vehicle => Person"
+        ]
+    );
+
+    let mut duplicate = ParserError::new(
+        "Person => Building",
+        ParserErrorKind::DuplicateDefinition("Person -> Building".to_string()),
+    );
+    duplicate.push("Person => Asset", ParserErrorKind::FirstOccurance);
+    assert_eq!(
+        Schema::parse(s1),
+        ParserResult::<_, _>::Err(Err::Failure(duplicate))
+    );
+    assert_eq!(
+        Schema::parse(s2),
+        ParserResult::<_, _>::Err(Err::Failure(ParserError::new(
+            "Address",
+            ParserErrorKind::UnknownReference("Address".to_string())
+        )))
+    );
+    assert_eq!(
+        Schema::parse(s3),
+        ParserResult::<_, _>::Err(Err::Failure(ParserError::new(
+            "Owner",
+            ParserErrorKind::AbstractReference("Owner".to_string())
+        )))
+    );
+}
+
+#[test]
+fn primitive_type_name_test() {
+    // Uuid used to be imported before it became a primitive
+    let s0 = "
+    <V0>
+    import Uuid;
+    node A {
+        key: Uuid
+    };";
+
+    let s1 = "
+    <V1>
+    struct Date {};";
+
+    assert_eq!(
+        Schema::parse(s0),
+        ParserResult::<_, _>::Err(Err::Failure(ParserError::new(
+            "Uuid",
+            ParserErrorKind::PrimitiveTypeName("Uuid".to_string())
+        )))
+    );
+    assert_eq!(
+        Schema::parse(s1),
+        ParserResult::<_, _>::Err(Err::Failure(ParserError::new(
+            "Date",
+            ParserErrorKind::PrimitiveTypeName("Date".to_string())
+        )))
+    );
+}
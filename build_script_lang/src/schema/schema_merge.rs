@@ -0,0 +1,608 @@
+use super::*;
+use build_script_shared::parsers::*;
+use std::collections::BTreeMap;
+
+/// A part of the schema which both sides of a merge changed differently
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict<I> {
+    /// Path to the conflicting type, varient, field or endpoint
+    pub path: String,
+    /// Where the conflict is in our schema, none if we removed it
+    pub ours: Option<Mark<I>>,
+    /// Where the conflict is in their schema, none if they removed it
+    pub theirs: Option<Mark<I>>,
+}
+
+/// Pick the side which changed the value
+///
+/// Returns None if both sides changed it differently
+fn merge_value<T: PartialEq + Clone>(
+    base: Option<&T>,
+    ours: Option<&T>,
+    theirs: Option<&T>,
+) -> Option<Option<T>> {
+    if ours == theirs || base == theirs {
+        Some(ours.cloned())
+    } else if base == ours {
+        Some(theirs.cloned())
+    } else {
+        None
+    }
+}
+
+/// Keep track of the conflicts found while merging
+struct Merger<I> {
+    conflicts: Vec<MergeConflict<I>>,
+}
+
+impl<I> Merger<I>
+where
+    I: Clone + PartialEq,
+{
+    /// Merge a value where the conflicts are reported on the given idents
+    /// Our value is kept if there is a conflict
+    fn merge<T: PartialEq + Clone>(
+        &mut self,
+        path: String,
+        base: Option<&T>,
+        ours: (&T, &Ident<I>),
+        theirs: (&T, &Ident<I>),
+    ) -> T {
+        match merge_value(base, Some(ours.0), Some(theirs.0)) {
+            Some(Some(merged)) => merged,
+            _ => {
+                self.conflicts.push(MergeConflict {
+                    path,
+                    ours: Some(ours.1.marker().clone()),
+                    theirs: Some(theirs.1.marker().clone()),
+                });
+                ours.0.clone()
+            }
+        }
+    }
+
+    /// Merge a list of named items
+    /// The merged list has our order with their new items at the end
+    fn merge_named<T, F, M>(
+        &mut self,
+        parent: &str,
+        base: &[T],
+        ours: &[T],
+        theirs: &[T],
+        name: F,
+        mut merge_item: M,
+    ) -> Vec<T>
+    where
+        T: PartialEq + Clone,
+        F: Fn(&T) -> &Ident<I>,
+        M: FnMut(&mut Self, Option<&T>, &T, &T) -> Option<T>,
+    {
+        let find = |items: &'_ [T], item_name: &Ident<I>| -> Option<usize> {
+            items.iter().position(|item| name(item) == item_name)
+        };
+
+        let mut names: Vec<&Ident<I>> = ours.iter().map(&name).collect();
+        for item in theirs {
+            if find(ours, name(item)).is_none() {
+                names.push(name(item));
+            }
+        }
+        for item in base {
+            if !names.contains(&name(item)) {
+                names.push(name(item));
+            }
+        }
+
+        let mut merged = Vec::new();
+        for item_name in names {
+            let base_item = find(base, item_name).map(|i| &base[i]);
+            let our_item = find(ours, item_name).map(|i| &ours[i]);
+            let their_item = find(theirs, item_name).map(|i| &theirs[i]);
+
+            if let Some(item) = merge_value(base_item, our_item, their_item) {
+                merged.extend(item);
+                continue;
+            }
+
+            // Both sides have changed the item so we try to merge the content of it
+            if let (Some(our_item), Some(their_item)) = (our_item, their_item) {
+                if let Some(item) = merge_item(self, base_item, our_item, their_item) {
+                    merged.push(item);
+                    continue;
+                }
+            }
+
+            self.conflicts.push(MergeConflict {
+                path: format!("{}{}", parent, item_name),
+                ours: our_item.map(|item| name(item).marker().clone()),
+                theirs: their_item.map(|item| name(item).marker().clone()),
+            });
+            merged.extend(our_item.cloned());
+        }
+
+        merged
+    }
+
+    fn merge_fields(
+        &mut self,
+        parent: &Ident<I>,
+        base: Option<&Fields<I>>,
+        ours: &Fields<I>,
+        theirs: &Fields<I>,
+    ) -> Fields<I>
+    where
+        I: Default,
+    {
+        let base_fields: Vec<_> = base.into_iter().flat_map(|f| f.iter()).cloned().collect();
+        let our_fields: Vec<_> = ours.iter().cloned().collect();
+        let their_fields: Vec<_> = theirs.iter().cloned().collect();
+
+        let merged_fields = self.merge_named(
+            &format!("{}.", parent),
+            &base_fields,
+            &our_fields,
+            &their_fields,
+            |f| &f.name,
+            |_, _, _, _| None,
+        );
+
+        // Fields added by both sides may have been given the same order
+        let mut fields = Fields::new(Vec::new(), ours.marker().clone());
+        let mut next_order = merged_fields.iter().map(|f| f.order + 1).max().unwrap_or(0);
+        for mut field in merged_fields {
+            if fields.iter().any(|f| f.order == field.order) {
+                field.order = next_order;
+                next_order += 1;
+            }
+            fields.insert_field(field);
+        }
+
+        fields
+    }
+
+    fn merge_stm(
+        &mut self,
+        base: Option<&SchemaStm<I>>,
+        ours: &SchemaStm<I>,
+        theirs: &SchemaStm<I>,
+    ) -> Option<SchemaStm<I>>
+    where
+        I: Default,
+    {
+        let stm = match (base, ours, theirs) {
+            (None | Some(SchemaStm::Node(_)), SchemaStm::Node(o), SchemaStm::Node(t)) => {
+                let b = match base {
+                    Some(SchemaStm::Node(b)) => Some(b),
+                    _ => None,
+                };
+                let mut merged = o.clone();
+                let path = o.name.to_string();
+                merged.comments = self.merge(
+                    format!("{path} comments"),
+                    b.map(|b| &b.comments),
+                    (&o.comments, &o.name),
+                    (&t.comments, &t.name),
+                );
+                merged.attributes = self.merge(
+                    format!("{path} attributes"),
+                    b.map(|b| &b.attributes),
+                    (&o.attributes, &o.name),
+                    (&t.attributes, &t.name),
+                );
+                merged.is_abstract = self.merge(
+                    format!("{path} abstract"),
+                    b.map(|b| &b.is_abstract),
+                    (&o.is_abstract, &o.name),
+                    (&t.is_abstract, &t.name),
+                );
+                merged.parent = self.merge(
+                    format!("{path} parent"),
+                    b.map(|b| &b.parent),
+                    (&o.parent, &o.name),
+                    (&t.parent, &t.name),
+                );
+                merged.fields =
+                    self.merge_fields(&o.name, b.map(|b| &b.fields), &o.fields, &t.fields);
+                SchemaStm::Node(merged)
+            }
+            (None | Some(SchemaStm::Struct(_)), SchemaStm::Struct(o), SchemaStm::Struct(t)) => {
+                let b = match base {
+                    Some(SchemaStm::Struct(b)) => Some(b),
+                    _ => None,
+                };
+                let mut merged = o.clone();
+                let path = o.name.to_string();
+                merged.comments = self.merge(
+                    format!("{path} comments"),
+                    b.map(|b| &b.comments),
+                    (&o.comments, &o.name),
+                    (&t.comments, &t.name),
+                );
+                merged.attributes = self.merge(
+                    format!("{path} attributes"),
+                    b.map(|b| &b.attributes),
+                    (&o.attributes, &o.name),
+                    (&t.attributes, &t.name),
+                );
+                merged.generics = self.merge(
+                    format!("{path} generics"),
+                    b.map(|b| &b.generics),
+                    (&o.generics, &o.name),
+                    (&t.generics, &t.name),
+                );
+                merged.fields =
+                    self.merge_fields(&o.name, b.map(|b| &b.fields), &o.fields, &t.fields);
+                SchemaStm::Struct(merged)
+            }
+            (None | Some(SchemaStm::Edge(_)), SchemaStm::Edge(o), SchemaStm::Edge(t)) => {
+                let b = match base {
+                    Some(SchemaStm::Edge(b)) => Some(b),
+                    _ => None,
+                };
+                let mut merged = o.clone();
+                let path = o.name.to_string();
+                merged.comments = self.merge(
+                    format!("{path} comments"),
+                    b.map(|b| &b.comments),
+                    (&o.comments, &o.name),
+                    (&t.comments, &t.name),
+                );
+                merged.attributes = self.merge(
+                    format!("{path} attributes"),
+                    b.map(|b| &b.attributes),
+                    (&o.attributes, &o.name),
+                    (&t.attributes, &t.name),
+                );
+                merged.fields =
+                    self.merge_fields(&o.name, b.map(|b| &b.fields), &o.fields, &t.fields);
+
+                // Endpoints are named by the types they go between
+                let base_endpoints: Vec<_> = b
+                    .into_iter()
+                    .flat_map(|b| b.endpoints.values())
+                    .cloned()
+                    .collect();
+                let our_endpoints: Vec<_> = o.endpoints.values().cloned().collect();
+                let their_endpoints: Vec<_> = t.endpoints.values().cloned().collect();
+                let mut endpoints = BTreeMap::new();
+                for (source, endpoints_from) in
+                    group_by_source(&base_endpoints, &our_endpoints, &their_endpoints)
+                {
+                    let (base_targets, our_targets, their_targets) = endpoints_from;
+                    let merged_targets = self.merge_named(
+                        &format!("{} {} => ", o.name, source),
+                        &base_targets,
+                        &our_targets,
+                        &their_targets,
+                        |e| &e.target,
+                        |_, _, _, _| None,
+                    );
+                    for endpoint in merged_targets {
+                        endpoints
+                            .insert((endpoint.source.clone(), endpoint.target.clone()), endpoint);
+                    }
+                }
+                merged.endpoints = endpoints;
+                SchemaStm::Edge(merged)
+            }
+            (None | Some(SchemaStm::Enum(_)), SchemaStm::Enum(o), SchemaStm::Enum(t)) => {
+                let b = match base {
+                    Some(SchemaStm::Enum(b)) => Some(b),
+                    _ => None,
+                };
+                let mut merged = o.clone();
+                let path = o.name.to_string();
+                merged.comments = self.merge(
+                    format!("{path} comments"),
+                    b.map(|b| &b.comments),
+                    (&o.comments, &o.name),
+                    (&t.comments, &t.name),
+                );
+                merged.attributes = self.merge(
+                    format!("{path} attributes"),
+                    b.map(|b| &b.attributes),
+                    (&o.attributes, &o.name),
+                    (&t.attributes, &t.name),
+                );
+                merged.generics = self.merge(
+                    format!("{path} generics"),
+                    b.map(|b| &b.generics),
+                    (&o.generics, &o.name),
+                    (&t.generics, &t.name),
+                );
+                merged.varients = self.merge_named(
+                    &format!("{}.", o.name),
+                    b.map(|b| b.varients.as_slice()).unwrap_or_default(),
+                    &o.varients,
+                    &t.varients,
+                    |v| v.name(),
+                    |_, _, _, _| None,
+                );
+                SchemaStm::Enum(merged)
+            }
+            (None | Some(SchemaStm::Import(_)), SchemaStm::Import(o), SchemaStm::Import(t)) => {
+                let b = match base {
+                    Some(SchemaStm::Import(b)) => Some(b),
+                    _ => None,
+                };
+                let mut merged = o.clone();
+                merged.comments = self.merge(
+                    format!("{} comments", o.name),
+                    b.map(|b| &b.comments),
+                    (&o.comments, &o.name),
+                    (&t.comments, &t.name),
+                );
+                SchemaStm::Import(merged)
+            }
+            (None | Some(SchemaStm::Alias(_)), SchemaStm::Alias(o), SchemaStm::Alias(t)) => {
+                let b = match base {
+                    Some(SchemaStm::Alias(b)) => Some(b),
+                    _ => None,
+                };
+                let mut merged = o.clone();
+                let path = o.name.to_string();
+                merged.comments = self.merge(
+                    format!("{path} comments"),
+                    b.map(|b| &b.comments),
+                    (&o.comments, &o.name),
+                    (&t.comments, &t.name),
+                );
+                merged.generics = self.merge(
+                    format!("{path} generics"),
+                    b.map(|b| &b.generics),
+                    (&o.generics, &o.name),
+                    (&t.generics, &t.name),
+                );
+                merged.ty = self.merge(
+                    format!("{path} type"),
+                    b.map(|b| &b.ty),
+                    (&o.ty, &o.name),
+                    (&t.ty, &t.name),
+                );
+                SchemaStm::Alias(merged)
+            }
+            (None | Some(SchemaStm::Group(_)), SchemaStm::Group(o), SchemaStm::Group(t)) => {
+                let b = match base {
+                    Some(SchemaStm::Group(b)) => Some(b),
+                    _ => None,
+                };
+                let mut merged = o.clone();
+                merged.comments = self.merge(
+                    format!("{} comments", o.name),
+                    b.map(|b| &b.comments),
+                    (&o.comments, &o.name),
+                    (&t.comments, &t.name),
+                );
+                merged.members = self.merge_named(
+                    &format!("{}.", o.name),
+                    b.map(|b| b.members.as_slice()).unwrap_or_default(),
+                    &o.members,
+                    &t.members,
+                    |m| m,
+                    |_, _, _, _| None,
+                );
+                SchemaStm::Group(merged)
+            }
+            _ => return None,
+        };
+
+        Some(stm)
+    }
+}
+
+/// Group the endpoints of each version by their source
+#[allow(clippy::type_complexity)]
+fn group_by_source<I: Clone + PartialEq>(
+    base: &[EndPoint<I>],
+    ours: &[EndPoint<I>],
+    theirs: &[EndPoint<I>],
+) -> Vec<(
+    Ident<I>,
+    (Vec<EndPoint<I>>, Vec<EndPoint<I>>, Vec<EndPoint<I>>),
+)> {
+    let mut groups: Vec<(Ident<I>, (Vec<_>, Vec<_>, Vec<_>))> = Vec::new();
+    let versions = [base, ours, theirs];
+    for (version, endpoints) in versions.iter().enumerate() {
+        for endpoint in endpoints.iter() {
+            let idx = match groups
+                .iter()
+                .position(|(source, _)| source == &endpoint.source)
+            {
+                Some(idx) => idx,
+                None => {
+                    groups.push((endpoint.source.clone(), Default::default()));
+                    groups.len() - 1
+                }
+            };
+
+            let group = &mut groups[idx].1;
+            match version {
+                0 => group.0.push(endpoint.clone()),
+                1 => group.1.push(endpoint.clone()),
+                _ => group.2.push(endpoint.clone()),
+            }
+        }
+    }
+
+    groups
+}
+
+impl<I> Schema<I> {
+    /// Merge two schemas which both originate from self
+    ///
+    /// Parts changed by only one side are taken from that side.
+    /// Parts changed differently by both sides are returned as conflicts and our version of them is kept
+    pub fn merge(&self, ours: &Schema<I>, theirs: &Schema<I>) -> (Schema<I>, Vec<MergeConflict<I>>)
+    where
+        I: Clone + Ord + Default,
+    {
+        let mut merger = Merger {
+            conflicts: Vec::new(),
+        };
+
+        let comments = merger.merge(
+            "schema comments".to_string(),
+            Some(&self.comments),
+            (&ours.comments, &ours.version),
+            (&theirs.comments, &theirs.version),
+        );
+        let handler = merger.merge(
+            "schema handler".to_string(),
+            Some(&self.handler),
+            (&ours.handler, &ours.version),
+            (&theirs.handler, &theirs.version),
+        );
+
+        let base_content: Vec<_> = self.iter().cloned().collect();
+        let our_content: Vec<_> = ours.iter().cloned().collect();
+        let their_content: Vec<_> = theirs.iter().cloned().collect();
+        let content = merger.merge_named(
+            "",
+            &base_content,
+            &our_content,
+            &their_content,
+            |stm| stm.get_type(),
+            |merger, base, ours, theirs| merger.merge_stm(base, ours, theirs),
+        );
+
+        let merged = Schema::new(
+            comments,
+            ours.version.clone(),
+            handler,
+            content,
+            ours.marker().clone(),
+        );
+
+        (merged, merger.conflicts)
+    }
+}
+
+#[test]
+fn merge_schema_test() {
+    use build_script_shared::parsers::ParserDeserialize;
+
+    let base = "
+    <V0>
+    node Student {
+        name: String,
+        grade: u8
+    };
+    enum State {
+        Idle,
+        Busy
+    };";
+
+    let ours = "
+    <V1>
+    node Student {
+        name: String,
+        grade: u16,
+        age: u8
+    };
+    enum State {
+        Idle,
+        Busy,
+        Away
+    };";
+
+    let theirs = "
+    <V1_beta>
+    node Student {
+        name: String,
+        grade: u8,
+        nickname: String
+    };
+    node Teacher {
+        name: String
+    };
+    enum State {
+        Idle
+    };";
+
+    let merged = "
+    <V1>
+    node Student {
+        name: String,
+        grade: u16,
+        age: u8,
+        nickname: String
+    };
+    node Teacher {
+        name: String
+    };
+    enum State {
+        Idle,
+        Away
+    };";
+
+    let (_, base) = Schema::parse(base).unwrap();
+    let (_, ours) = Schema::parse(ours).unwrap();
+    let (_, theirs) = Schema::parse(theirs).unwrap();
+    let (_, expected) = Schema::parse(merged).unwrap();
+
+    let (merged, conflicts) = base.merge(&ours, &theirs);
+    assert_eq!(conflicts, vec![]);
+    assert_eq!(merged, expected);
+
+    // Both sides changing the grade is a conflict
+    let theirs = "
+    <V1_beta>
+    node Student {
+        name: String,
+        grade: u32
+    };
+    enum State {
+        Idle,
+        Busy
+    };";
+
+    let (_, theirs) = Schema::parse(theirs).unwrap();
+    let (merged, conflicts) = base.merge(&ours, &theirs);
+    let paths: Vec<_> = conflicts.iter().map(|c| c.path.as_str()).collect();
+    assert_eq!(paths, vec!["Student.grade"]);
+    assert_eq!(merged, ours);
+
+    // Groups, aliases and imports changed by both sides are merged like the rest
+    let base = "
+    <V0>
+    node A {};
+    node B {};
+    node C {};
+    group G = A;";
+
+    let ours = "
+    <V1>
+    node A {};
+    node B {};
+    node C {};
+    group G = A | B;
+    type Key = u64;
+    import Ext;";
+
+    let theirs = "
+    <V1_beta>
+    node A {};
+    node B {};
+    node C {};
+    group G = A | C;
+    type Key = u64;
+    import Ext;";
+
+    let merged = "
+    <V1>
+    node A {};
+    node B {};
+    node C {};
+    group G = A | B | C;
+    type Key = u64;
+    import Ext;";
+
+    let (_, base) = Schema::parse(base).unwrap();
+    let (_, ours) = Schema::parse(ours).unwrap();
+    let (_, theirs) = Schema::parse(theirs).unwrap();
+    let (_, expected) = Schema::parse(merged).unwrap();
+
+    let (merged, conflicts) = base.merge(&ours, &theirs);
+    assert_eq!(conflicts, vec![]);
+    assert_eq!(merged, expected);
+}
@@ -22,6 +22,8 @@ pub enum SchemaStm<I> {
     Enum(EnumExp<I>),
     Struct(StructExp<I>),
     Import(ImportExp<I>),
+    Alias(AliasExp<I>),
+    Group(GroupExp<I>),
 }
 
 impl<I> SchemaStm<I> {
@@ -32,6 +34,20 @@ impl<I> SchemaStm<I> {
             SchemaStm::Enum(n) => &n.name,
             SchemaStm::Struct(n) => &n.name,
             SchemaStm::Import(n) => &n.name,
+            SchemaStm::Alias(n) => &n.name,
+            SchemaStm::Group(n) => &n.name,
+        }
+    }
+
+    pub fn get_type_mut(&mut self) -> &mut Ident<I> {
+        match self {
+            SchemaStm::Node(n) => &mut n.name,
+            SchemaStm::Edge(n) => &mut n.name,
+            SchemaStm::Enum(n) => &mut n.name,
+            SchemaStm::Struct(n) => &mut n.name,
+            SchemaStm::Import(n) => &mut n.name,
+            SchemaStm::Alias(n) => &mut n.name,
+            SchemaStm::Group(n) => &mut n.name,
         }
     }
 
@@ -42,6 +58,8 @@ impl<I> SchemaStm<I> {
             SchemaStm::Enum(_n) => SchemaStmType::Enum,
             SchemaStm::Struct(_n) => SchemaStmType::Struct,
             SchemaStm::Import(_n) => SchemaStmType::Import,
+            SchemaStm::Alias(_n) => SchemaStmType::Alias,
+            SchemaStm::Group(_n) => SchemaStmType::Group,
         }
     }
 
@@ -52,6 +70,8 @@ impl<I> SchemaStm<I> {
             SchemaStm::Struct(n) => Some(&n.fields),
             SchemaStm::Enum(_) => None,
             SchemaStm::Import(_) => None,
+            SchemaStm::Alias(_) => None,
+            SchemaStm::Group(_) => None,
         }
     }
 
@@ -63,6 +83,8 @@ impl<I> SchemaStm<I> {
             // We do not know which fields to choose so we choose none
             SchemaStm::Enum(_) => None,
             SchemaStm::Import(_) => None,
+            SchemaStm::Alias(_) => None,
+            SchemaStm::Group(_) => None,
         }
     }
 
@@ -73,6 +95,8 @@ impl<I> SchemaStm<I> {
             SchemaStm::Struct(n) => &n.comments,
             SchemaStm::Enum(n) => &n.comments,
             SchemaStm::Import(n) => &n.comments,
+            SchemaStm::Alias(n) => &n.comments,
+            SchemaStm::Group(n) => &n.comments,
         }
     }
 
@@ -83,6 +107,8 @@ impl<I> SchemaStm<I> {
             SchemaStm::Struct(s) => Some(&s.attributes),
             SchemaStm::Enum(e) => Some(&e.attributes),
             SchemaStm::Import(_) => None,
+            SchemaStm::Alias(_) => None,
+            SchemaStm::Group(_) => None,
         }
     }
 
@@ -97,6 +123,8 @@ impl<I> SchemaStm<I> {
             SchemaStm::Enum(n) => SchemaStm::Enum(n.map(f)),
             SchemaStm::Struct(n) => SchemaStm::Struct(n.map(f)),
             SchemaStm::Import(n) => SchemaStm::Import(n.map(f)),
+            SchemaStm::Alias(n) => SchemaStm::Alias(n.map(f)),
+            SchemaStm::Group(n) => SchemaStm::Group(n.map(f)),
         }
     }
 
@@ -107,6 +135,8 @@ impl<I> SchemaStm<I> {
             SchemaStm::Enum(n) => n.strip_comments(),
             SchemaStm::Struct(n) => n.strip_comments(),
             SchemaStm::Import(n) => n.strip_comments(),
+            SchemaStm::Alias(n) => n.strip_comments(),
+            SchemaStm::Group(n) => n.strip_comments(),
         }
     }
 }
@@ -122,6 +152,8 @@ impl<I: InputType> ParserDeserialize<I> for SchemaStm<I> {
                     map(EnumExp::parse, SchemaStm::Enum),
                     map(StructExp::parse, SchemaStm::Struct),
                     map(ImportExp::parse, SchemaStm::Import),
+                    map(AliasExp::parse, SchemaStm::Alias),
+                    map(GroupExp::parse, SchemaStm::Group),
                     fail,
                 )),
                 cut(char(';')),
@@ -138,6 +170,8 @@ impl<I> ParserSerialize for SchemaStm<I> {
             SchemaStm::Enum(n) => n.compose(f, ctx),
             SchemaStm::Struct(n) => n.compose(f, ctx),
             SchemaStm::Import(n) => n.compose(f, ctx),
+            SchemaStm::Alias(n) => n.compose(f, ctx),
+            SchemaStm::Group(n) => n.compose(f, ctx),
         }?;
         write!(f, ";")?;
         Ok(())
@@ -152,6 +186,8 @@ impl<I> Marked<I> for SchemaStm<I> {
             SchemaStm::Enum(n) => n.marker(),
             SchemaStm::Struct(n) => n.marker(),
             SchemaStm::Import(n) => n.marker(),
+            SchemaStm::Alias(n) => n.marker(),
+            SchemaStm::Group(n) => n.marker(),
         }
     }
 }
@@ -208,6 +244,21 @@ impl<I: Dummy<Faker> + Clone> Dummy<SchemaStmOfType<I>> for SchemaStm<I> {
                 },
                 rng,
             )),
+            SchemaStmType::Alias => SchemaStm::Alias(AliasExp::dummy_with_rng(
+                &AliasExpOfType {
+                    name: config.name.clone(),
+                    generic_count: config.generic_count,
+                    ref_types: config.ref_types.clone(),
+                },
+                rng,
+            )),
+            SchemaStmType::Group => SchemaStm::Group(GroupExp::dummy_with_rng(
+                &GroupExpOfType {
+                    name: config.name.clone(),
+                    node_types: config.node_types.clone(),
+                },
+                rng,
+            )),
         }
     }
 }
@@ -15,6 +15,8 @@ pub enum SchemaStmType {
     Enum,
     Struct,
     Import,
+    Alias,
+    Group,
 }
 
 impl<I: InputType> ParserDeserialize<I> for SchemaStmType {
@@ -27,6 +29,8 @@ impl<I: InputType> ParserDeserialize<I> for SchemaStmType {
                 value(SchemaStmType::Enum, tag("enum")),
                 value(SchemaStmType::Struct, tag("struct")),
                 value(SchemaStmType::Import, tag("import")),
+                value(SchemaStmType::Alias, tag("type")),
+                value(SchemaStmType::Group, tag("group")),
             )),
         )(s)?;
 
@@ -42,6 +46,8 @@ impl ParserSerialize for SchemaStmType {
             SchemaStmType::Enum => write!(f, "enum"),
             SchemaStmType::Struct => write!(f, "struct"),
             SchemaStmType::Import => write!(f, "import"),
+            SchemaStmType::Alias => write!(f, "type"),
+            SchemaStmType::Group => write!(f, "group"),
         }?;
         Ok(())
     }
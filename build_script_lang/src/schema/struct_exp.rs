@@ -28,8 +28,11 @@ const JSON: &str = "json";
 
 const JSON_ATTRIBUTES: &[&'static str] = &["untagged"];
 
-const ALLOWED_FUNCTION_ATTRIBUTES: &[(&str, Option<usize>, Option<&[&str]>)] =
-    &[(DERIVE, None, None), (JSON, Some(1), Some(JSON_ATTRIBUTES))];
+const ALLOWED_FUNCTION_ATTRIBUTES: &[(&str, Option<usize>, Option<&[&str]>)] = &[
+    (DERIVE, None, None),
+    (JSON, Some(1), Some(JSON_ATTRIBUTES)),
+    (RENAMED_FROM, Some(1), None),
+];
 
 #[derive(
     PartialEq, Eq, Debug, Hash, Clone, Default, PartialOrd, Ord, Dummy, Serialize, Deserialize,
@@ -57,37 +57,56 @@ where
     }
 }
 
+impl OwnedError {
+    /// Name of the file the error was found in
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn kind(&self) -> &ParserErrorKind {
+        &self.kind
+    }
+}
+
+impl OwnedParserError {
+    /// Retrieve the errors worth showing to the user
+    ///
+    /// Nom errors are omitted as they do not provide much information
+    pub fn iter_displayed(&self) -> impl Iterator<Item = &OwnedError> {
+        self.errors.iter().filter(|e| {
+            !matches!(
+                e.kind,
+                ParserErrorKind::ErrorKind(_) | ParserErrorKind::EndOfFile
+            )
+        })
+    }
+
+    /// Create a preview of the code causing the error
+    pub fn get_preview(&self, e: &OwnedError) -> Option<CodePreview> {
+        let data = self.data.get(&e.source)?;
+        let caret_len = if e.offset + e.len >= self.data.len() {
+            1
+        } else {
+            e.len
+        };
+
+        Some(CodePreview::new(data, e.offset, caret_len, 2, 2, true))
+    }
+}
+
 impl Display for OwnedParserError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         const ERROR_COUNT: usize = 3;
 
-        // Nom errors are omitted as they do not provide much information
-        let displayed_errors = self
-            .errors
-            .iter()
-            .filter(|e| {
-                !matches!(
-                    e.kind,
-                    ParserErrorKind::ErrorKind(_) | ParserErrorKind::EndOfFile
-                )
-            })
-            .take(ERROR_COUNT);
-
-        for e in displayed_errors {
+        for e in self.iter_displayed().take(ERROR_COUNT) {
             let source = if e.source.is_empty() {
                 "String"
             } else {
                 e.source.as_str()
             };
 
-            let preview = if let Some(data) = self.data.get(&e.source) {
-                let caret_len = if e.offset + e.len >= self.data.len() {
-                    1
-                } else {
-                    e.len
-                };
-
-                let preview = CodePreview::new(data, e.offset, caret_len, 2, 2, true);
+            let preview = self.get_preview(e);
+            if let Some(preview) = &preview {
                 write!(
                     f,
                     "{}:{}:{}: ",
@@ -95,11 +114,9 @@ impl Display for OwnedParserError {
                     preview.caret_line_number() + 1,
                     preview.caret_offset() + 1
                 )?;
-                Some(preview)
             } else {
                 write!(f, "{}:1:1: ", source)?;
-                None
-            };
+            }
 
             writeln!(f, "{}", &e.kind)?;
 
@@ -32,8 +32,11 @@ pub enum ParserErrorKind {
     ErrorKind(ErrorKind),
     ExpectedChar(char, Option<char>),
     UnknownReference(String),
+    AbstractReference(String),
+    PrimitiveTypeName(String),
     UnexpectedGenericCount(String, usize, usize),
     InvalidTypeConvertion(String, String),
+    InvalidDefaultValue(String, String),
     UnusedGeneric,
 }
 
@@ -151,6 +154,18 @@ impl Display for ParserErrorKind {
             ParserErrorKind::UnknownReference(field_type) => {
                 write!(f, "Unknown reference {field_type}\n")?
             }
+            ParserErrorKind::AbstractReference(name) => {
+                write!(
+                    f,
+                    "{name} is abstract, use the nodes inheriting from it instead"
+                )?;
+            }
+            ParserErrorKind::PrimitiveTypeName(name) => {
+                write!(
+                    f,
+                    "{name} is a primitive type and can not be declared or imported"
+                )?;
+            }
             ParserErrorKind::UnexpectedGenericCount(field_type, expected, actual) => write!(
                 f,
                 "{field_type} takes {expected} generic argument(s) but {actual} was provided"
@@ -161,6 +176,9 @@ impl Display for ParserErrorKind {
             ParserErrorKind::InvalidTypeConvertion(old, new) => {
                 write!(f, "Invalid type convertion from {old} to {new}")?;
             }
+            ParserErrorKind::InvalidDefaultValue(value, ty) => {
+                write!(f, "{value} is not a valid default value for {ty}")?;
+            }
             ParserErrorKind::EndOfFile => {}
         }
 
@@ -4,8 +4,10 @@ pub mod error;
 mod input_marker;
 pub mod parsers;
 pub mod serde_parsers;
+mod stable_hash;
 pub mod tests;
 
 pub use code_preview::CodePreview;
 pub use error::{BUILDScriptError, BUILDScriptResult};
 pub use input_marker::*;
+pub use stable_hash::*;
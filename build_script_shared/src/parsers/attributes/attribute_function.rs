@@ -96,7 +96,13 @@ pub struct AllowedFunctionAttribute(
 );
 impl<I: Dummy<Faker>> Dummy<AllowedFunctionAttribute> for AttributeFunction<I> {
     fn dummy_with_rng<R: Rng + ?Sized>(config: &AllowedFunctionAttribute, rng: &mut R) -> Self {
-        let (key, len, allowed_values) = config.0.choose(rng).unwrap();
+        // renamed_from has to point into a previous schema, so it is left out of fake schemas
+        let allowed: Vec<_> = config
+            .0
+            .iter()
+            .filter(|(key, _, _)| *key != RENAMED_FROM)
+            .collect();
+        let (key, len, allowed_values) = allowed.choose(rng).unwrap();
         AttributeFunction {
             key: Ident::new(key.to_string(), Faker.fake_with_rng(rng)),
             values: (0..len.unwrap_or_else(|| 5))
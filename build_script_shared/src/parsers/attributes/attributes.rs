@@ -8,6 +8,9 @@ use nom::{multi::*, Err};
 use serde::{Deserialize, Serialize};
 use std::hash::Hash;
 
+/// Attribute pointing to the name an item had in the previous schema
+pub const RENAMED_FROM: &str = "renamed_from";
+
 #[derive(
     Debug, Clone, Default, PartialOrd, Ord, PartialEq, Eq, Hash, Dummy, Serialize, Deserialize,
 )]
@@ -53,6 +56,13 @@ impl<I> Attributes<I> {
             .collect()
     }
 
+    /// Retrieve the name given in @renamed_from(\<ident\>)
+    pub fn get_renamed_from(&self) -> Option<&Ident<I>> {
+        self.get_functions(RENAMED_FROM)
+            .into_iter()
+            .find_map(|attr| attr.values.first())
+    }
+
     pub fn get_key_value(&self, key: &str) -> Option<&AttributeKeyValue<I>> {
         self.iter()
             .filter_map(|attr| {
@@ -0,0 +1,264 @@
+use super::*;
+use crate::compose_test;
+use crate::error::{ParserError, ParserErrorKind, ParserResult, ParserSlimResult};
+use crate::input_marker::InputType;
+use fake::faker::lorem::en::Word;
+use fake::{Dummy, Fake, Faker, Rng};
+use nom::branch::*;
+use nom::bytes::complete::*;
+use nom::character::complete::{char, digit1};
+use nom::combinator::*;
+use nom::error::context;
+use nom::sequence::{pair, preceded, terminated, tuple};
+use nom::Err;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// A constant value written directly in the schema
+///
+/// This is used to give fields a default value
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(bound = "I: Default + Clone")]
+#[serde(tag = "type")]
+pub enum Literal<I> {
+    /// Both integers and floats are stored as they were written
+    /// so they can be checked against the type they are assigned to
+    Number {
+        value: String,
+        #[serde(skip)]
+        marker: Mark<I>,
+    },
+    String {
+        value: String,
+        #[serde(skip)]
+        marker: Mark<I>,
+    },
+    Bool {
+        value: bool,
+        #[serde(skip)]
+        marker: Mark<I>,
+    },
+    None {
+        #[serde(skip)]
+        marker: Mark<I>,
+    },
+    /// An empty list or set
+    List {
+        #[serde(skip)]
+        marker: Mark<I>,
+    },
+    /// An empty map
+    Map {
+        #[serde(skip)]
+        marker: Mark<I>,
+    },
+    /// A unit varient of the enum used as the type
+    Varient { name: Ident<I> },
+}
+
+impl<I> Literal<I> {
+    /// Check that the literal is a valid value for the given type
+    ///
+    /// Varients are only checked to be assigned to a reference type
+    /// as the referenced enum is not known here
+    pub fn check_type(&self, ty: &Types<I>) -> ParserSlimResult<I, ()>
+    where
+        I: Clone,
+    {
+        let is_valid = match (self, ty) {
+            (Literal::None { .. }, Types::Option { .. }) => true,
+            (literal, Types::Option { inner, .. }) => return literal.check_type(inner),
+            (Literal::Bool { .. }, Types::Bool(_)) => true,
+            (Literal::String { .. }, Types::String(_)) => true,
+            (Literal::Number { value, .. }, Types::F64(_) | Types::F32(_)) => {
+                value.parse::<f64>().is_ok()
+            }
+            (Literal::Number { value, .. }, Types::Usize(_) | Types::U64(_)) => {
+                value.parse::<u64>().is_ok()
+            }
+            (Literal::Number { value, .. }, Types::U32(_)) => value.parse::<u32>().is_ok(),
+            (Literal::Number { value, .. }, Types::U16(_)) => value.parse::<u16>().is_ok(),
+            (Literal::Number { value, .. }, Types::U8(_)) => value.parse::<u8>().is_ok(),
+            (Literal::Number { value, .. }, Types::Isize(_) | Types::I64(_)) => {
+                value.parse::<i64>().is_ok()
+            }
+            (Literal::Number { value, .. }, Types::I32(_)) => value.parse::<i32>().is_ok(),
+            (Literal::Number { value, .. }, Types::I16(_)) => value.parse::<i16>().is_ok(),
+            (Literal::Number { value, .. }, Types::I8(_)) => value.parse::<i8>().is_ok(),
+            (Literal::Number { value, .. }, Types::U128(_)) => value.parse::<u128>().is_ok(),
+            (Literal::Number { value, .. }, Types::I128(_)) => value.parse::<i128>().is_ok(),
+            (Literal::String { value, .. }, Types::Char(_)) => value.chars().count() == 1,
+            (Literal::List { .. }, Types::List { .. } | Types::Set { .. }) => true,
+            (Literal::Map { .. }, Types::Map { .. }) => true,
+            (Literal::Varient { .. }, Types::Reference { .. }) => true,
+            _ => false,
+        };
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(Err::Failure(ParserError::new_at(
+                self,
+                ParserErrorKind::InvalidDefaultValue(self.to_string(), ty.to_string()),
+            )))
+        }
+    }
+
+    /// Move from one input type to another
+    pub fn map<O, F>(self, f: F) -> Literal<O>
+    where
+        F: FnMut(I) -> O + Copy,
+    {
+        match self {
+            Literal::Number { value, marker } => Literal::Number {
+                value,
+                marker: marker.map(f),
+            },
+            Literal::String { value, marker } => Literal::String {
+                value,
+                marker: marker.map(f),
+            },
+            Literal::Bool { value, marker } => Literal::Bool {
+                value,
+                marker: marker.map(f),
+            },
+            Literal::None { marker } => Literal::None {
+                marker: marker.map(f),
+            },
+            Literal::List { marker } => Literal::List {
+                marker: marker.map(f),
+            },
+            Literal::Map { marker } => Literal::Map {
+                marker: marker.map(f),
+            },
+            Literal::Varient { name } => Literal::Varient { name: name.map(f) },
+        }
+    }
+}
+
+impl<I: InputType> ParserDeserialize<I> for Literal<I> {
+    fn parse(s: I) -> ParserResult<I, Self> {
+        context(
+            "Parsing Literal",
+            alt((
+                map(
+                    marked(recognize(tuple((
+                        opt(char('-')),
+                        digit1,
+                        opt(pair(char('.'), digit1)),
+                    )))),
+                    |(value, marker): (I, _)| Literal::Number {
+                        value: value.to_string(),
+                        marker,
+                    },
+                ),
+                map(marked(string_data), |(value, marker): (I, _)| {
+                    Literal::String {
+                        value: value.to_string(),
+                        marker,
+                    }
+                }),
+                map(
+                    marked(terminated(tag("true"), not(Ident::ident_full))),
+                    |(_, marker)| Literal::Bool {
+                        value: true,
+                        marker,
+                    },
+                ),
+                map(
+                    marked(terminated(tag("false"), not(Ident::ident_full))),
+                    |(_, marker)| Literal::Bool {
+                        value: false,
+                        marker,
+                    },
+                ),
+                map(
+                    marked(terminated(tag("None"), not(Ident::ident_full))),
+                    |(_, marker)| Literal::None { marker },
+                ),
+                map(
+                    marked(preceded(char('['), ws(char(']')))),
+                    |(_, marker)| Literal::List { marker },
+                ),
+                map(
+                    marked(preceded(char('{'), ws(char('}')))),
+                    |(_, marker)| Literal::Map { marker },
+                ),
+                map(Ident::ident, |name| Literal::Varient { name }),
+            )),
+        )(s)
+    }
+}
+
+impl<I> ParserSerialize for Literal<I> {
+    fn compose<W: std::fmt::Write>(
+        &self,
+        f: &mut W,
+        ctx: ComposeContext,
+    ) -> crate::error::ComposerResult<()> {
+        let indents = ctx.create_indents();
+        match self {
+            Literal::Number { value, .. } => write!(f, "{indents}{value}")?,
+            Literal::String { value, .. } => write!(f, "{indents}\"{value}\"")?,
+            Literal::Bool { value, .. } => write!(f, "{indents}{value}")?,
+            Literal::None { .. } => write!(f, "{indents}None")?,
+            Literal::List { .. } => write!(f, "{indents}[]")?,
+            Literal::Map { .. } => write!(f, "{indents}{{}}")?,
+            Literal::Varient { name } => write!(f, "{indents}{name}")?,
+        };
+        Ok(())
+    }
+}
+
+impl<I> Display for Literal<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ser = self.serialize_to_string().map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", ser)
+    }
+}
+
+impl<I> Marked<I> for Literal<I> {
+    fn marker(&self) -> &Mark<I> {
+        match self {
+            Literal::Number { marker, .. }
+            | Literal::String { marker, .. }
+            | Literal::Bool { marker, .. }
+            | Literal::None { marker }
+            | Literal::List { marker }
+            | Literal::Map { marker } => marker,
+            Literal::Varient { name } => name.marker(),
+        }
+    }
+}
+
+impl<I: Dummy<Faker>> Dummy<Faker> for Literal<I> {
+    fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
+        let marker = Mark::dummy_with_rng(&Faker, rng);
+        match rng.gen_range(0..8) {
+            0 => Literal::Number {
+                value: rng.gen_range(-1000..1000).to_string(),
+                marker,
+            },
+            1 => Literal::Number {
+                value: format!("{}.{}", rng.gen_range(-1000..1000), rng.gen_range(0..100)),
+                marker,
+            },
+            2 => Literal::String {
+                value: Word().fake_with_rng(rng),
+                marker,
+            },
+            3 => Literal::Bool {
+                value: rng.gen(),
+                marker,
+            },
+            4 => Literal::None { marker },
+            5 => Literal::List { marker },
+            6 => Literal::Map { marker },
+            7 | _ => Literal::Varient {
+                name: Ident::dummy_with_rng(&Faker, rng),
+            },
+        }
+    }
+}
+
+compose_test! {literal_compose, Literal<I>}
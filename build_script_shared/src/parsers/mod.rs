@@ -3,6 +3,7 @@ mod comment;
 mod generic_parsers;
 mod generics;
 mod ident;
+mod literal;
 mod mark;
 mod parser_traits;
 mod types;
@@ -12,6 +13,7 @@ pub use comment::*;
 pub use generic_parsers::*;
 pub use generics::*;
 pub use ident::*;
+pub use literal::*;
 pub use mark::*;
 pub use parser_traits::*;
 pub use types::*;
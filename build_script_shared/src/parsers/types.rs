@@ -17,6 +17,13 @@ use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::ops::{Deref, DerefMut};
 
+/// Names parsed as primitive types  
+/// A type declared or imported with one of these names could never be referenced
+pub const PRIMITIVE_TYPES: &[&str] = &[
+    "String", "bool", "f64", "f32", "usize", "u64", "u32", "u16", "u8", "isize", "i64", "i32",
+    "i16", "i8", "u128", "i128", "char", "DateTime", "Date", "Duration", "Uuid", "Bytes",
+];
+
 #[derive(Debug, Clone, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(bound = "I: Default + Clone")]
 #[serde(tag = "type")]
@@ -75,6 +82,18 @@ pub enum Types<I> {
         #[serde(skip)]
         marker: Mark<I>,
     },
+    // Newer types are kept last so the legacy hash of the existing types stays the same
+    #[serde(rename = "u128")]
+    U128(#[serde(skip)] Mark<I>),
+    #[serde(rename = "i128")]
+    I128(#[serde(skip)] Mark<I>),
+    #[serde(rename = "char")]
+    Char(#[serde(skip)] Mark<I>),
+    DateTime(#[serde(skip)] Mark<I>),
+    Date(#[serde(skip)] Mark<I>),
+    Duration(#[serde(skip)] Mark<I>),
+    Uuid(#[serde(skip)] Mark<I>),
+    Bytes(#[serde(skip)] Mark<I>),
 }
 
 impl<I> Types<I> {
@@ -99,7 +118,15 @@ impl<I> Types<I> {
             | Types::I64(_)
             | Types::I32(_)
             | Types::I16(_)
-            | Types::I8(_) => Ok(()),
+            | Types::I8(_)
+            | Types::U128(_)
+            | Types::I128(_)
+            | Types::Char(_)
+            | Types::DateTime(_)
+            | Types::Date(_)
+            | Types::Duration(_)
+            | Types::Uuid(_)
+            | Types::Bytes(_) => Ok(()),
             Types::Option { inner, .. } => inner.check_types(reference_types),
             Types::List { inner, .. } => inner.check_types(reference_types),
             Types::Set { inner, .. } => inner.check_types(reference_types),
@@ -174,7 +201,15 @@ impl<I> Types<I> {
             | Types::I64(_)
             | Types::I32(_)
             | Types::I16(_)
-            | Types::I8(_) => Ok(()),
+            | Types::I8(_)
+            | Types::U128(_)
+            | Types::I128(_)
+            | Types::Char(_)
+            | Types::DateTime(_)
+            | Types::Date(_)
+            | Types::Duration(_)
+            | Types::Uuid(_)
+            | Types::Bytes(_) => Ok(()),
             Types::Option { inner, .. } => {
                 inner.check_cycle(type_name, type_generics, dependency_graph)
             }
@@ -233,7 +268,15 @@ impl<I> Types<I> {
             | Types::I64(_)
             | Types::I32(_)
             | Types::I16(_)
-            | Types::I8(_) => (),
+            | Types::I8(_)
+            | Types::U128(_)
+            | Types::I128(_)
+            | Types::Char(_)
+            | Types::DateTime(_)
+            | Types::Date(_)
+            | Types::Duration(_)
+            | Types::Uuid(_)
+            | Types::Bytes(_) => (),
             Types::Option { inner, .. } => inner.remove_used(reference_types),
             Types::List { inner, .. } => inner.remove_used(reference_types),
             Types::Set { inner, .. } => inner.remove_used(reference_types),
@@ -272,6 +315,14 @@ impl<I> Types<I> {
             Types::I32(s) => Types::I32(s),
             Types::I16(s) => Types::I16(s),
             Types::I8(s) => Types::I8(s),
+            Types::U128(s) => Types::U128(s),
+            Types::I128(s) => Types::I128(s),
+            Types::Char(s) => Types::Char(s),
+            Types::DateTime(s) => Types::DateTime(s),
+            Types::Date(s) => Types::Date(s),
+            Types::Duration(s) => Types::Duration(s),
+            Types::Uuid(s) => Types::Uuid(s),
+            Types::Bytes(s) => Types::Bytes(s),
             Types::Option { inner, marker } => Types::Option {
                 inner: inner.map_reference(f).into(),
                 marker,
@@ -324,6 +375,14 @@ impl<I> Types<I> {
             Types::I32(s) => Types::I32(s.map(f)),
             Types::I16(s) => Types::I16(s.map(f)),
             Types::I8(s) => Types::I8(s.map(f)),
+            Types::U128(s) => Types::U128(s.map(f)),
+            Types::I128(s) => Types::I128(s.map(f)),
+            Types::Char(s) => Types::Char(s.map(f)),
+            Types::DateTime(s) => Types::DateTime(s.map(f)),
+            Types::Date(s) => Types::Date(s.map(f)),
+            Types::Duration(s) => Types::Duration(s.map(f)),
+            Types::Uuid(s) => Types::Uuid(s.map(f)),
+            Types::Bytes(s) => Types::Bytes(s.map(f)),
             Types::Option { inner, marker } => Types::Option {
                 inner: inner.map(f).into(),
                 marker: marker.map(f),
@@ -391,7 +450,27 @@ impl<I> Types<I> {
             | (Types::I32(_), Types::I32(_))
             | (Types::I32(_), Types::I64(_))
             // i64
-            | (Types::I64(_), Types::I64(_)) => true,
+            | (Types::I64(_), Types::I64(_))
+            // u128
+            | (Types::U8(_), Types::U128(_))
+            | (Types::U16(_), Types::U128(_))
+            | (Types::U32(_), Types::U128(_))
+            | (Types::U64(_), Types::U128(_))
+            | (Types::U128(_), Types::U128(_))
+            // i128
+            | (Types::I8(_), Types::I128(_))
+            | (Types::I16(_), Types::I128(_))
+            | (Types::I32(_), Types::I128(_))
+            | (Types::I64(_), Types::I128(_))
+            | (Types::I128(_), Types::I128(_))
+            // char is stored as a string with a single character
+            | (Types::Char(_), Types::Char(_))
+            | (Types::Char(_), Types::String(_))
+            | (Types::DateTime(_), Types::DateTime(_))
+            | (Types::Date(_), Types::Date(_))
+            | (Types::Duration(_), Types::Duration(_))
+            | (Types::Uuid(_), Types::Uuid(_))
+            | (Types::Bytes(_), Types::Bytes(_)) => true,
 
             // Reference types only works if their inner types can be converted
             (Types::Option{inner: linner, .. }, Types::Option{inner: rinner, .. })
@@ -491,7 +570,15 @@ impl<I> Types<I> {
             | Types::I64(_)
             | Types::I32(_)
             | Types::I16(_)
-            | Types::I8(_) => false,
+            | Types::I8(_)
+            | Types::U128(_)
+            | Types::I128(_)
+            | Types::Char(_)
+            | Types::DateTime(_)
+            | Types::Date(_)
+            | Types::Duration(_)
+            | Types::Uuid(_)
+            | Types::Bytes(_) => false,
             Types::Option { inner, .. } => inner.has_external_ref(),
             Types::List { inner, .. } => inner.has_external_ref(),
             Types::Set { inner, .. } => inner.has_external_ref(),
@@ -554,62 +641,99 @@ impl<I: InputType> ParserDeserialize<I> for Types<I> {
                         marker,
                     },
                 ),
-                map(
-                    marked(terminated(tag("String"), not(Ident::ident_full))),
-                    |(_, marker)| Types::String(marker),
-                ),
-                map(
-                    marked(terminated(tag("bool"), not(Ident::ident_full))),
-                    |(_, marker)| Types::Bool(marker),
-                ),
-                map(
-                    marked(terminated(tag("f64"), not(Ident::ident_full))),
-                    |(_, marker)| Types::F64(marker),
-                ),
-                map(
-                    marked(terminated(tag("f32"), not(Ident::ident_full))),
-                    |(_, marker)| Types::F32(marker),
-                ),
-                map(
-                    marked(terminated(tag("usize"), not(Ident::ident_full))),
-                    |(_, marker)| Types::Usize(marker),
-                ),
-                map(
-                    marked(terminated(tag("u64"), not(Ident::ident_full))),
-                    |(_, marker)| Types::U64(marker),
-                ),
-                map(
-                    marked(terminated(tag("u32"), not(Ident::ident_full))),
-                    |(_, marker)| Types::U32(marker),
-                ),
-                map(
-                    marked(terminated(tag("u16"), not(Ident::ident_full))),
-                    |(_, marker)| Types::U16(marker),
-                ),
-                map(
-                    marked(terminated(tag("u8"), not(Ident::ident_full))),
-                    |(_, marker)| Types::U8(marker),
-                ),
-                map(
-                    marked(terminated(tag("isize"), not(Ident::ident_full))),
-                    |(_, marker)| Types::Isize(marker),
-                ),
-                map(
-                    marked(terminated(tag("i64"), not(Ident::ident_full))),
-                    |(_, marker)| Types::I64(marker),
-                ),
-                map(
-                    marked(terminated(tag("i32"), not(Ident::ident_full))),
-                    |(_, marker)| Types::I32(marker),
-                ),
-                map(
-                    marked(terminated(tag("i16"), not(Ident::ident_full))),
-                    |(_, marker)| Types::I16(marker),
-                ),
-                map(
-                    marked(terminated(tag("i8"), not(Ident::ident_full))),
-                    |(_, marker)| Types::I8(marker),
-                ),
+                // alt only supports 21 parsers so the primitives are grouped
+                alt((
+                    map(
+                        marked(terminated(tag("String"), not(Ident::ident_full))),
+                        |(_, marker)| Types::String(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("bool"), not(Ident::ident_full))),
+                        |(_, marker)| Types::Bool(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("f64"), not(Ident::ident_full))),
+                        |(_, marker)| Types::F64(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("f32"), not(Ident::ident_full))),
+                        |(_, marker)| Types::F32(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("usize"), not(Ident::ident_full))),
+                        |(_, marker)| Types::Usize(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("u64"), not(Ident::ident_full))),
+                        |(_, marker)| Types::U64(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("u32"), not(Ident::ident_full))),
+                        |(_, marker)| Types::U32(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("u16"), not(Ident::ident_full))),
+                        |(_, marker)| Types::U16(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("u8"), not(Ident::ident_full))),
+                        |(_, marker)| Types::U8(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("isize"), not(Ident::ident_full))),
+                        |(_, marker)| Types::Isize(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("i64"), not(Ident::ident_full))),
+                        |(_, marker)| Types::I64(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("i32"), not(Ident::ident_full))),
+                        |(_, marker)| Types::I32(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("i16"), not(Ident::ident_full))),
+                        |(_, marker)| Types::I16(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("i8"), not(Ident::ident_full))),
+                        |(_, marker)| Types::I8(marker),
+                    ),
+                )),
+                alt((
+                    map(
+                        marked(terminated(tag("u128"), not(Ident::ident_full))),
+                        |(_, marker)| Types::U128(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("i128"), not(Ident::ident_full))),
+                        |(_, marker)| Types::I128(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("char"), not(Ident::ident_full))),
+                        |(_, marker)| Types::Char(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("DateTime"), not(Ident::ident_full))),
+                        |(_, marker)| Types::DateTime(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("Date"), not(Ident::ident_full))),
+                        |(_, marker)| Types::Date(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("Duration"), not(Ident::ident_full))),
+                        |(_, marker)| Types::Duration(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("Uuid"), not(Ident::ident_full))),
+                        |(_, marker)| Types::Uuid(marker),
+                    ),
+                    map(
+                        marked(terminated(tag("Bytes"), not(Ident::ident_full))),
+                        |(_, marker)| Types::Bytes(marker),
+                    ),
+                )),
                 map(
                     marked(pair(
                         Ident::ident,
@@ -651,6 +775,14 @@ impl<I> ParserSerialize for Types<I> {
             Types::I32(_) => write!(f, "i32")?,
             Types::I16(_) => write!(f, "i16")?,
             Types::I8(_) => write!(f, "i8")?,
+            Types::U128(_) => write!(f, "u128")?,
+            Types::I128(_) => write!(f, "i128")?,
+            Types::Char(_) => write!(f, "char")?,
+            Types::DateTime(_) => write!(f, "DateTime")?,
+            Types::Date(_) => write!(f, "Date")?,
+            Types::Duration(_) => write!(f, "Duration")?,
+            Types::Uuid(_) => write!(f, "Uuid")?,
+            Types::Bytes(_) => write!(f, "Bytes")?,
             Types::Option { inner, .. } => write!(f, "Option<{inner}>")?,
             Types::List { inner, .. } => write!(f, "List<{inner}>")?,
             Types::Set { inner, .. } => write!(f, "Set<{inner}>")?,
@@ -694,7 +826,15 @@ impl<I> PartialEq for Types<I> {
             | (Types::I64(_), Types::I64(_))
             | (Types::I32(_), Types::I32(_))
             | (Types::I16(_), Types::I16(_))
-            | (Types::I8(_), Types::I8(_)) => true,
+            | (Types::I8(_), Types::I8(_))
+            | (Types::U128(_), Types::U128(_))
+            | (Types::I128(_), Types::I128(_))
+            | (Types::Char(_), Types::Char(_))
+            | (Types::DateTime(_), Types::DateTime(_))
+            | (Types::Date(_), Types::Date(_))
+            | (Types::Duration(_), Types::Duration(_))
+            | (Types::Uuid(_), Types::Uuid(_))
+            | (Types::Bytes(_), Types::Bytes(_)) => true,
 
             (Types::Option { inner: inner0, .. }, Types::Option { inner: inner1, .. })
             | (Types::List { inner: inner0, .. }, Types::List { inner: inner1, .. })
@@ -749,6 +889,14 @@ impl<I> Display for Types<I> {
             Types::I32(_) => write!(f, "i32"),
             Types::I16(_) => write!(f, "i16"),
             Types::I8(_) => write!(f, "i8"),
+            Types::U128(_) => write!(f, "u128"),
+            Types::I128(_) => write!(f, "i128"),
+            Types::Char(_) => write!(f, "char"),
+            Types::DateTime(_) => write!(f, "DateTime"),
+            Types::Date(_) => write!(f, "Date"),
+            Types::Duration(_) => write!(f, "Duration"),
+            Types::Uuid(_) => write!(f, "Uuid"),
+            Types::Bytes(_) => write!(f, "Bytes"),
             Types::Option { inner, .. } => write!(f, "Option<{inner}>"),
             Types::List { inner, .. } => write!(f, "List<{inner}>"),
             Types::Set { inner, .. } => write!(f, "Set<{inner}>"),
@@ -794,6 +942,14 @@ impl<I> Marked<I> for Types<I> {
             | Types::I32(marker)
             | Types::I16(marker)
             | Types::I8(marker)
+            | Types::U128(marker)
+            | Types::I128(marker)
+            | Types::Char(marker)
+            | Types::DateTime(marker)
+            | Types::Date(marker)
+            | Types::Duration(marker)
+            | Types::Uuid(marker)
+            | Types::Bytes(marker)
             | Types::Option { marker, .. }
             | Types::List { marker, .. }
             | Types::Set { marker, .. }
@@ -827,7 +983,15 @@ impl TypeReferenceMap {
             | Types::I64(_)
             | Types::I32(_)
             | Types::I16(_)
-            | Types::I8(_) => (),
+            | Types::I8(_)
+            | Types::U128(_)
+            | Types::I128(_)
+            | Types::Char(_)
+            | Types::DateTime(_)
+            | Types::Date(_)
+            | Types::Duration(_)
+            | Types::Uuid(_)
+            | Types::Bytes(_) => (),
             Types::Option { inner, .. }
             | Types::List { inner, .. }
             | Types::Set { inner, .. } => self.pick_valid_reference_type(inner, rng),
@@ -863,13 +1027,13 @@ impl TypeReferenceMap {
 
 impl<I: Dummy<Faker>> Dummy<Faker> for Types<I> {
     fn dummy_with_rng<R: Rng + ?Sized>(_: &Faker, rng: &mut R) -> Self {
-        match rng.gen_range(0..5) {
+        match rng.gen_range(0..6) {
             0 => Types::String(Mark::dummy_with_rng(&Faker, rng)),
             1 => match rng.gen_range(0..2) {
                 0 => Types::F64(Mark::dummy_with_rng(&Faker, rng)),
                 1 | _ => Types::F32(Mark::dummy_with_rng(&Faker, rng)),
             },
-            2 => match rng.gen_range(0..10) {
+            2 => match rng.gen_range(0..12) {
                 0 => Types::Usize(Mark::dummy_with_rng(&Faker, rng)),
                 1 => Types::U64(Mark::dummy_with_rng(&Faker, rng)),
                 2 => Types::U32(Mark::dummy_with_rng(&Faker, rng)),
@@ -879,7 +1043,9 @@ impl<I: Dummy<Faker>> Dummy<Faker> for Types<I> {
                 6 => Types::I64(Mark::dummy_with_rng(&Faker, rng)),
                 7 => Types::I32(Mark::dummy_with_rng(&Faker, rng)),
                 8 => Types::I16(Mark::dummy_with_rng(&Faker, rng)),
-                9 | _ => Types::I8(Mark::dummy_with_rng(&Faker, rng)),
+                9 => Types::I8(Mark::dummy_with_rng(&Faker, rng)),
+                10 => Types::U128(Mark::dummy_with_rng(&Faker, rng)),
+                11 | _ => Types::I128(Mark::dummy_with_rng(&Faker, rng)),
             },
             3 => match rng.gen_range(0..5) {
                 0 => Types::Map {
@@ -907,7 +1073,15 @@ impl<I: Dummy<Faker>> Dummy<Faker> for Types<I> {
                     marker: Mark::dummy_with_rng(&Faker, rng),
                 },
             },
-            4 | _ => Types::Bool(Mark::dummy_with_rng(&Faker, rng)),
+            4 => match rng.gen_range(0..6) {
+                0 => Types::Char(Mark::dummy_with_rng(&Faker, rng)),
+                1 => Types::DateTime(Mark::dummy_with_rng(&Faker, rng)),
+                2 => Types::Date(Mark::dummy_with_rng(&Faker, rng)),
+                3 => Types::Duration(Mark::dummy_with_rng(&Faker, rng)),
+                4 => Types::Uuid(Mark::dummy_with_rng(&Faker, rng)),
+                5 | _ => Types::Bytes(Mark::dummy_with_rng(&Faker, rng)),
+            },
+            5 | _ => Types::Bool(Mark::dummy_with_rng(&Faker, rng)),
         }
     }
 }
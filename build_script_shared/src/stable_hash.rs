@@ -0,0 +1,27 @@
+/// Version of the algorithm used by stable_hash
+///
+/// Version 0 is the std DefaultHasher used before hashes were stable
+pub const HASH_VERSION: u32 = 1;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// 64 bit FNV-1a hash of the data
+///
+/// Unlike DefaultHasher the result is the same across platforms and rust versions
+pub fn stable_hash(data: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in data {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+#[test]
+fn stable_hash_test() {
+    // Test vectors from the FNV specification
+    assert_eq!(stable_hash(b""), 0xcbf29ce484222325);
+    assert_eq!(stable_hash(b"a"), 0xaf63dc4c8601ec8c);
+    assert_eq!(stable_hash(b"foobar"), 0x85944171f73967e8);
+}
@@ -6,7 +6,7 @@ use mdbook::{Config, MDBook};
 use tera::{Context, Tera};
 
 use crate::{GenResult, Project};
-use super::{DocBookContext, populate_schema_context};
+use super::{DocBookContext, populate_history, populate_schema_context};
 
 const BOOK: &'static [u8] = include_bytes!("../../book_template/book.toml");
 
@@ -18,6 +18,8 @@ const TEMPLATES: &'static [(&'static str, &'static str)] = &[
 
     ("src/SUMMARY.md", include_str!("../../book_template/src/SUMMARY.md")),
 
+    ("history.md", include_str!("../../book_template/templates/history.md")),
+
     ("section_headers/schema.md", include_str!("../../book_template/templates/section_headers/schema.md")),
     ("section_headers/edges.md", include_str!("../../book_template/templates/section_headers/edges.md")),
     ("section_headers/imports.md", include_str!("../../book_template/templates/section_headers/imports.md")),
@@ -62,6 +64,7 @@ pub fn create_doc_book(prj: &Project, out_dir: impl AsRef<Path>) -> GenResult<()
 
     let tmpl = build_tera()?;
 
+    populate_history(&book_gen, &tmpl, prj)?;
     populate_schema_context(&mut book_gen, &tmpl, prj)?;
 
     book_gen.add_to_context(&mut ctx);
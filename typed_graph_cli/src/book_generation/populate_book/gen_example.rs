@@ -84,6 +84,14 @@ pub fn gen_type_example(ty: &Types<InputMarker<String>>, escaped: bool, indent:
         Types::I32(_) => Ok(format!("{quotes}-1234{quotes}")),
         Types::I16(_) => Ok(format!("{quotes}-1234{quotes}")),
         Types::I8(_) => Ok(format!("{quotes}123{quotes}")),
+        Types::U128(_) => Ok(format!("{quotes}1234{quotes}")),
+        Types::I128(_) => Ok(format!("{quotes}-1234{quotes}")),
+        Types::Char(_) => Ok("\"a\"".to_string()),
+        Types::DateTime(_) => Ok("\"2024-01-31T12:30:00Z\"".to_string()),
+        Types::Date(_) => Ok("\"2024-01-31\"".to_string()),
+        Types::Duration(_) => Ok("{ \"secs\": 90, \"nanos\": 0 }".to_string()),
+        Types::Uuid(_) => Ok("\"67e55044-10b1-426f-9247-bb680e5fe0c8\"".to_string()),
+        Types::Bytes(_) => Ok("[104, 105]".to_string()),
         Types::Option { inner, .. } => Ok(gen_type_example(inner, false, indent, schema, scope)?),
         Types::List { inner, .. }
         | Types::Set { inner, .. } => {
@@ -117,7 +125,9 @@ pub fn gen_type_example(ty: &Types<InputMarker<String>>, escaped: bool, indent:
                     SchemaStm::Node(_) => None,
                     SchemaStm::Struct(expr) => Some(&expr.generics),
                     SchemaStm::Enum(expr) => Some(&expr.generics),
-                    SchemaStm::Import(_) => None
+                    SchemaStm::Import(_) => None,
+                    SchemaStm::Alias(expr) => Some(&expr.generics),
+                    SchemaStm::Group(_) => None,
                 };
 
                 // Create a new scope using the generics provided to the refence
@@ -168,6 +178,14 @@ pub fn expand_type(ty: &Types<InputMarker<String>>, scope: &HashMap<String, Opti
         Types::I32(v) => Ok(Types::I32(v.clone())),
         Types::I16(v) => Ok(Types::I16(v.clone())),
         Types::I8(v) => Ok(Types::I8(v.clone())),
+        Types::U128(v) => Ok(Types::U128(v.clone())),
+        Types::I128(v) => Ok(Types::I128(v.clone())),
+        Types::Char(v) => Ok(Types::Char(v.clone())),
+        Types::DateTime(v) => Ok(Types::DateTime(v.clone())),
+        Types::Date(v) => Ok(Types::Date(v.clone())),
+        Types::Duration(v) => Ok(Types::Duration(v.clone())),
+        Types::Uuid(v) => Ok(Types::Uuid(v.clone())),
+        Types::Bytes(v) => Ok(Types::Bytes(v.clone())),
         Types::Option { inner, marker } => Ok(Types::Option { inner: Box::new(expand_type(&inner, scope)?), marker: marker.clone() }),
         Types::List { inner, marker } => Ok(Types::List { inner: Box::new(expand_type(&inner, scope)?), marker: marker.clone() }),
         Types::Set { inner, marker } => Ok(Types::Set { inner: Box::new(expand_type(&inner, scope)?), marker: marker.clone() }),
@@ -199,6 +217,12 @@ pub fn gen_schema_example(stm: &SchemaStm<InputMarker<String>>, indent: usize, s
         SchemaStm::Import(expr) => {
             Ok(format!("/* {} body */", expr.name))
         },
+        SchemaStm::Alias(expr) => {
+            gen_type_example(&expr.ty, false, indent, schema, scope)
+        },
+        SchemaStm::Group(expr) => {
+            Ok(format!("/* {} */", expr.members.iter().map(|m| m.to_string()).collect::<Vec<_>>().join(" | ")))
+        },
         SchemaStm::Edge(expr) => {
             let weight = gen_keyed_field_example(expr.name.to_string(), gen_field_example(&expr.fields, indent + 2, Some("EdgeId".to_string()), schema, scope)?, indent + 1)?;
             
@@ -11,16 +11,24 @@ pub fn gen_type_def(ty: &Types<InputMarker<String>>, ref_name: &str, schema: &Sc
         Types::Bool(_) => Ok("<a href=\"../../primitives.md#bool\">bool</a>".to_string()),
         Types::F64(_) => Ok("<a href=\"../../primitives.md#f32-f64\">f64</a>".to_string()),
         Types::F32(_) => Ok("<a href=\"../../primitives.md#f32-f64\">f32</a>".to_string()),
-        Types::Usize(_) => Ok("<a href=\"../../primitives.md#u8-u16-u32-u64-usize\">usize</a>".to_string()),
-        Types::U64(_) => Ok("<a href=\"../../primitives.md#u8-u16-u32-u64-usize\">u64</a>".to_string()),
-        Types::U32(_) => Ok("<a href=\"../../primitives.md#u8-u16-u32-u64-usize\">u32</a>".to_string()),
-        Types::U16(_) => Ok("<a href=\"../../primitives.md#u8-u16-u32-u64-usize\">u16</a>".to_string()),
-        Types::U8(_) => Ok("<a href=\"../../primitives.md#u8-u16-u32-u64-usize\">u8</a>".to_string()),
-        Types::Isize(_) => Ok("<a href=\"../../primitives.md#i8-i16-i32-i64-isize\">isize</a>".to_string()),
-        Types::I64(_) => Ok("<a href=\"../../primitives.md#i8-i16-i32-i64-isize\">i64</a>".to_string()),
-        Types::I32(_) => Ok("<a href=\"../../primitives.md#i8-i16-i32-i64-isize\">i32</a>".to_string()),
-        Types::I16(_) => Ok("<a href=\"../../primitives.md#i8-i16-i32-i64-isize\">i16</a>".to_string()),
-        Types::I8(_) => Ok("<a href=\"../../primitives.md#i8-i16-i32-i64-isize\">i8</a>".to_string()),
+        Types::Usize(_) => Ok("<a href=\"../../primitives.md#u8-u16-u32-u64-u128-usize\">usize</a>".to_string()),
+        Types::U64(_) => Ok("<a href=\"../../primitives.md#u8-u16-u32-u64-u128-usize\">u64</a>".to_string()),
+        Types::U32(_) => Ok("<a href=\"../../primitives.md#u8-u16-u32-u64-u128-usize\">u32</a>".to_string()),
+        Types::U16(_) => Ok("<a href=\"../../primitives.md#u8-u16-u32-u64-u128-usize\">u16</a>".to_string()),
+        Types::U8(_) => Ok("<a href=\"../../primitives.md#u8-u16-u32-u64-u128-usize\">u8</a>".to_string()),
+        Types::Isize(_) => Ok("<a href=\"../../primitives.md#i8-i16-i32-i64-i128-isize\">isize</a>".to_string()),
+        Types::I64(_) => Ok("<a href=\"../../primitives.md#i8-i16-i32-i64-i128-isize\">i64</a>".to_string()),
+        Types::I32(_) => Ok("<a href=\"../../primitives.md#i8-i16-i32-i64-i128-isize\">i32</a>".to_string()),
+        Types::I16(_) => Ok("<a href=\"../../primitives.md#i8-i16-i32-i64-i128-isize\">i16</a>".to_string()),
+        Types::I8(_) => Ok("<a href=\"../../primitives.md#i8-i16-i32-i64-i128-isize\">i8</a>".to_string()),
+        Types::U128(_) => Ok("<a href=\"../../primitives.md#u8-u16-u32-u64-u128-usize\">u128</a>".to_string()),
+        Types::I128(_) => Ok("<a href=\"../../primitives.md#i8-i16-i32-i64-i128-isize\">i128</a>".to_string()),
+        Types::Char(_) => Ok("<a href=\"../../primitives.md#char\">char</a>".to_string()),
+        Types::DateTime(_) => Ok("<a href=\"../../primitives.md#datetime\">DateTime</a>".to_string()),
+        Types::Date(_) => Ok("<a href=\"../../primitives.md#date\">Date</a>".to_string()),
+        Types::Duration(_) => Ok("<a href=\"../../primitives.md#duration\">Duration</a>".to_string()),
+        Types::Uuid(_) => Ok("<a href=\"../../primitives.md#uuid\">Uuid</a>".to_string()),
+        Types::Bytes(_) => Ok("<a href=\"../../primitives.md#bytes\">Bytes</a>".to_string()),
         Types::Option { inner, .. } => Ok(format!("<a href=\"../../primitives.md#optiont\">Option</a><{}>", gen_type_def(inner, ref_name, schema)?)),
         Types::List { inner, .. } => Ok(format!("<a href=\"../../primitives.md#listt\">List</a><{}>", gen_type_def(inner, ref_name, schema)?)),
         Types::Set { inner, .. } => Ok(format!("<a href=\"../../primitives.md#sett\">List</a><{}>", gen_type_def(inner, ref_name, schema)?)),
@@ -38,6 +46,8 @@ pub fn gen_type_def(ty: &Types<InputMarker<String>>, ref_name: &str, schema: &Sc
                 Some(SchemaStm::Enum(_)) => format!("<a href=\"../types/{inner_name}.md\">{inner_name}</a>"),
                 Some(SchemaStm::Import(_)) => format!("<a href=\"../imports.md#{}\">{inner_name}</a>", inner_name.to_lowercase()),
                 Some(SchemaStm::Struct(_)) => format!("<a href=\"../structs/{inner_name}.md\">{inner_name}</a>"),
+                Some(SchemaStm::Alias(_)) => format!("<a href=\"../types.md#{}\">{inner_name}</a>", inner_name.to_lowercase()),
+                Some(SchemaStm::Group(_)) => inner_name.clone(),
                 None => format!("<a href=\"#{ref_name}\">{inner_name}</a>"),
             };
 
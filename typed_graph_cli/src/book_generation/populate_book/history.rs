@@ -0,0 +1,29 @@
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+
+use tera::{Context, Tera};
+
+use crate::book_generation::DocBookContext;
+use crate::{GenResult, Project, VersionHistory};
+
+/// Create the front page of the book showing the version tree of the project
+pub fn populate_history(book_gen: &DocBookContext, tmpl: &Tera, prj: &Project) -> GenResult<()> {
+    let svg_path = book_gen.book_res_path.join("diagrams");
+    if !svg_path.exists() {
+        create_dir_all(&svg_path)?;
+    }
+
+    let abs_out_path = svg_path.join("history.svg");
+    let history = VersionHistory::new(prj)?;
+    let mut f = File::create(&abs_out_path)?;
+    write!(f, "{}", history.to_svg()?)?;
+
+    let out_path = abs_out_path.strip_prefix(&book_gen.book_src_path)?;
+    let mut ctx = Context::new();
+    ctx.insert("svg_path", &out_path);
+
+    let content = tmpl.render("history.md", &ctx)?;
+    let mut f = File::create(book_gen.book_src_path.join("history.md"))?;
+    write!(f, "{}", content)?;
+    Ok(())
+}
@@ -1,3 +1,4 @@
+mod history;
 mod schema;
 mod section_headers;
 mod statement;
@@ -6,6 +7,7 @@ mod gen_example;
 
 pub use gen_example::*;
 pub use gen_type_info::*;
+pub use history::*;
 pub use schema::*;
 pub use section_headers::*;
 pub use statement::*;
\ No newline at end of file
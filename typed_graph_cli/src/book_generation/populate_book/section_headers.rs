@@ -79,6 +79,19 @@ pub fn populate_types_section_header(tmpl: &Tera, section: &mut SchemaDocContext
         types.push(e.name.to_string());
     }
     ctx.insert("types", &types);
+
+    let mut aliases = Vec::new();
+    for alias in schema.aliases() {
+        let generics = alias.generics.generics.iter().map(|g| g.letter.to_string()).collect::<Vec<_>>();
+        aliases.push(AliasData {
+            name: alias.name.to_string(),
+            generics: if generics.is_empty() { String::new() } else { format!("<{}>", generics.join(", ")) },
+            ty: alias.ty.to_string(),
+            doc_comments: alias.comments.iter_doc().cloned().collect::<Vec<_>>().join("  \n"),
+            comments: alias.comments.iter_non_doc().cloned().collect::<Vec<_>>().join("  \n"),
+        })
+    }
+    ctx.insert("aliases", &aliases);
     
     let content = tmpl.render("section_headers/types.md", &ctx)?;
     let mut f = File::create(section.schema_path.join("types.md"))?;
@@ -86,6 +99,15 @@ pub fn populate_types_section_header(tmpl: &Tera, section: &mut SchemaDocContext
     Ok(())
 }
 
+#[derive(Serialize)]
+struct AliasData {
+    name: String,
+    generics: String,
+    ty: String,
+    doc_comments: String,
+    comments: String,
+}
+
 #[derive(Serialize)]
 struct ImportData {
     name: String,
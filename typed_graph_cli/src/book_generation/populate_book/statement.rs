@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use build_script_lang::schema::{EdgeExp, EnumExp, EnumVarient, NodeExp, Schema, SchemaStm, StructExp};
+use build_script_lang::schema::{EdgeExp, EnumExp, EnumVarient, NodeExp, Schema, SchemaStm, SchemaStmType, StructExp};
 use build_script_shared::InputMarker;
 use serde::Serialize;
 use tera::{Context, Tera};
@@ -32,6 +32,10 @@ pub fn populate_statement_context(section: &mut SchemaDocContext, schema: &Schem
             },
             // Imports are all stored in the imports section_header
             SchemaStm::Import(_) => {}
+            // Aliases are all stored in the types section_header
+            SchemaStm::Alias(_) => {}
+            // Groups are expanded into the endpoints of each node
+            SchemaStm::Group(_) => {}
         }
     }
     
@@ -78,7 +82,9 @@ pub fn populate_node(tmpl: &Tera, section: &mut SchemaDocContext, expr: &NodeExp
     }
     ctx.insert("fields", &fields);
 
-    let outgoing_edges: Vec<_> = schema.iter().filter_map(|stm| if let SchemaStm::Edge(e) = stm {
+    // Edges going through a group is listed on each of the members
+    let expanded = schema.expand_groups();
+    let outgoing_edges: Vec<_> = expanded.iter().filter_map(|stm| if let SchemaStm::Edge(e) = stm {
         let endpoints: Vec<_> = e.endpoints.iter().filter(|((source, _), _)| source == &expr.name).map(|(st, data)| (st, data, &e.name)).collect();
         if !endpoints.is_empty() {
             Some(endpoints)
@@ -101,7 +107,7 @@ pub fn populate_node(tmpl: &Tera, section: &mut SchemaDocContext, expr: &NodeExp
     }
     ctx.insert("outgoing_endpoints", &outgoing_endpoints);
 
-    let incoming_edges: Vec<_> = schema.iter().filter_map(|stm| if let SchemaStm::Edge(e) = stm {
+    let incoming_edges: Vec<_> = expanded.iter().filter_map(|stm| if let SchemaStm::Edge(e) = stm {
         let endpoints: Vec<_> = e.endpoints.iter().filter(|((_, target), _)| target == &expr.name).map(|(st, data)| (st, data, &e.name)).collect();
         if !endpoints.is_empty() {
             Some(endpoints)
@@ -163,8 +169,15 @@ pub fn populate_edge(tmpl: &Tera, section: &mut SchemaDocContext, expr: &EdgeExp
     }
     ctx.insert("fields", &fields);
 
+    // List the endpoints with the groups expanded so every link points to a node
+    let expanded = schema.expand_groups();
+    let expanded_endpoints = match expanded.get_type(Some(SchemaStmType::Edge), &expr.name) {
+        Some(SchemaStm::Edge(e)) => &e.endpoints,
+        _ => &expr.endpoints,
+    };
+
     let mut endpoints = Vec::new();
-    for ((source, target), data) in expr.endpoints.iter() {
+    for ((source, target), data) in expanded_endpoints.iter() {
         endpoints.push(EndpointData {
             source: format!("[{source}](../nodes/{source}.md)"),
             target: format!("[{target}](../nodes/{target}.md)"),
@@ -19,7 +19,10 @@ pub enum ArgsType {
     Migration(Migration),
     Export(Export),
     Schema(Schema),
+    Data(Data),
     List(List),
+    Check(Check),
+    Watch(Watch),
     New(NewProject),
 }
 
@@ -28,7 +31,10 @@ impl Process<()> for Args {
         match &self.cmd {
             ArgsType::Migration(migration) => migration.process(&self.settings),
             ArgsType::Schema(schema) => schema.process(&self.settings),
+            ArgsType::Data(data) => data.process(&self.settings),
             ArgsType::List(list) => list.process(&self.settings),
+            ArgsType::Check(check) => check.process(&self.settings),
+            ArgsType::Watch(watch) => watch.process(&self.settings),
             ArgsType::Export(export) => export.process(&self.settings),
             ArgsType::New(new) => new.process(&self.settings),
         }
@@ -0,0 +1,52 @@
+use crate::cli::*;
+use crate::{GenError, GenResult, Project};
+use clap::{Parser, ValueEnum};
+
+/// Check the whole project for problems
+///
+/// Unlike other commands every problem is reported instead of stopping at the first one
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct Check {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    /// How the problems should be printed
+    #[clap(long, value_enum, default_value_t = CheckFormat::Text)]
+    pub format: CheckFormat,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckFormat {
+    Text,
+    Json,
+}
+
+impl Process<ProjectSettings> for Check {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+
+        let problems = Project::check_project(p)?;
+
+        match self.format {
+            CheckFormat::Text => {
+                for problem in &problems {
+                    println!("{}", problem);
+                }
+            }
+            CheckFormat::Json => println!("{}", serde_json::to_string_pretty(&problems)?),
+        }
+
+        // Warnings are reported but does not fail the check
+        let error_count = problems.iter().filter(|problem| problem.is_error()).count();
+        if error_count != 0 {
+            return Err(GenError::InvalidProject { count: error_count });
+        }
+
+        if self.format == CheckFormat::Text && problems.is_empty() {
+            println!("Found no problems");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{cli::*, GenResult};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser, Debug)]
+pub struct Data {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    #[clap(subcommand)]
+    pub cmd: DataType,
+}
+
+/// Work with graphs serialized as json
+#[derive(Subcommand, Debug)]
+pub enum DataType {
+    Migrate(MigrateData),
+    Validate(ValidateData),
+    Generate(GenerateData),
+}
+
+impl Process<ProjectSettings> for Data {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        match &self.cmd {
+            DataType::Migrate(m) => m.process(&self.settings.chain(settings)),
+            DataType::Validate(v) => v.process(&self.settings.chain(settings)),
+            DataType::Generate(g) => g.process(&self.settings.chain(settings)),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::generate_graph;
+use crate::GenResult;
+use crate::Project;
+use clap::Parser;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::fs::File;
+use std::path::PathBuf;
+
+use crate::cli::*;
+
+/// Generate a random graph following a schema
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct GenerateData {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    /// Name of the schema the graph should follow
+    #[clap()]
+    pub schema: String,
+
+    /// Number of nodes in the graph
+    #[clap(long, default_value_t = 10)]
+    pub nodes: usize,
+
+    /// Seed used to generate the graph
+    ///
+    /// Using the same seed and schema will always produce the same graph
+    #[clap(long)]
+    pub seed: Option<u64>,
+
+    /// Where to save the graph
+    ///
+    /// Defaults to printing the graph
+    #[clap(long, short)]
+    pub output: Option<PathBuf>,
+}
+
+impl Process<ProjectSettings> for GenerateData {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+
+        let project = Project::open_project(p)?;
+        let schema = project.get_schema(&self.schema)?;
+
+        let seed = self.seed.unwrap_or_else(rand::random);
+        let graph = generate_graph(schema, self.nodes, &mut StdRng::seed_from_u64(seed))?;
+
+        match &self.output {
+            Some(output) => {
+                let mut f = File::create(output)?;
+                serde_json::to_writer_pretty(&mut f, &graph)?;
+                println!("Generated {:?} using seed {}", output, seed);
+            }
+            None => println!("{}", serde_json::to_string_pretty(&graph)?),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use crate::GenError;
+use crate::GenResult;
+use crate::Project;
+use clap::Parser;
+use serde_json::Value;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use crate::cli::*;
+
+/// Migrate a graph serialized as json from one schema to another
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct MigrateData {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    /// Name of the schema the graph is using
+    ///
+    /// Defaults to the schema stored in the graph
+    #[clap(long)]
+    pub from: Option<String>,
+
+    /// Name of the schema to migrate the graph to
+    #[clap(long)]
+    pub to: String,
+
+    /// Path to the graph
+    #[clap()]
+    pub input: PathBuf,
+
+    /// Where to save the migrated graph
+    #[clap()]
+    pub output: PathBuf,
+}
+
+impl Process<ProjectSettings> for MigrateData {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+
+        let project = Project::open_project(p)?;
+        let mut graph: Value = serde_json::from_reader(BufReader::new(File::open(&self.input)?))?;
+
+        let from = match &self.from {
+            Some(from) => from.clone(),
+            None => graph
+                .get("schema")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    GenError::DataMigrationFailed(
+                        "the graph does not say which schema it is using, use --from to provide it"
+                            .to_string(),
+                    )
+                })?,
+        };
+
+        let dropped = project.migrate_data(&from, &self.to, &mut graph)?;
+        for dropped_data in &dropped {
+            println!("{}", dropped_data);
+        }
+
+        let mut f = File::create(&self.output)?;
+        serde_json::to_writer_pretty(&mut f, &graph)?;
+
+        println!(
+            "Migrated {:?} from {} to {} dropping {} values",
+            self.input,
+            from,
+            self.to,
+            dropped.len()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+mod data;
+mod generate_data;
+mod migrate_data;
+mod validate_data;
+
+pub use data::*;
+pub use generate_data::*;
+pub use migrate_data::*;
+pub use validate_data::*;
@@ -0,0 +1,52 @@
+use crate::validate_graph;
+use crate::GenError;
+use crate::GenResult;
+use crate::Project;
+use clap::Parser;
+use serde_json::Value;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use crate::cli::*;
+
+/// Check that a graph serialized as json follows a schema
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct ValidateData {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    /// Name of the schema to validate against
+    #[clap()]
+    pub schema: String,
+
+    /// Path to the graph
+    #[clap()]
+    pub input: PathBuf,
+}
+
+impl Process<ProjectSettings> for ValidateData {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+
+        let project = Project::open_project(p)?;
+        let schema = project.get_schema(&self.schema)?;
+        let graph: Value = serde_json::from_reader(BufReader::new(File::open(&self.input)?))?;
+
+        let violations = validate_graph(&graph, schema);
+        for violation in &violations {
+            println!("{}", violation);
+        }
+
+        if !violations.is_empty() {
+            return Err(GenError::InvalidGraph {
+                schema: self.schema.clone(),
+                count: violations.len(),
+            });
+        }
+
+        println!("{:?} follows {}", self.input, self.schema);
+        Ok(())
+    }
+}
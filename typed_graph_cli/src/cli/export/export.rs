@@ -1,4 +1,4 @@
-use crate::{cli::*, GenResult};
+use crate::{cli::*, GenError, GenResult, Project, CONFIG_FILE};
 use clap::{Parser, Subcommand};
 
 #[derive(Parser, Debug)]
@@ -6,8 +6,9 @@ pub struct Export {
     #[clap(flatten)]
     pub settings: ProjectSettings,
 
+    /// Leave out to export every target declared in typed_graph.toml
     #[clap(subcommand)]
-    pub cmd: ExportType,
+    pub cmd: Option<ExportType>,
 }
 
 /// Export the a project to different languages
@@ -18,16 +19,95 @@ pub enum ExportType {
     Json(Json),
     Svg(Svg),
     Doc(Doc),
+    History(History),
 }
 
 impl Process<ProjectSettings> for Export {
     fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let settings = self.settings.chain(settings);
         match &self.cmd {
-            ExportType::Rust(r) => r.process(&self.settings.chain(settings)),
-            ExportType::Python(py) => py.process(&self.settings.chain(settings)),
-            ExportType::Json(json) => json.process(&self.settings.chain(settings)),
-            ExportType::Svg(svg) => svg.process(&self.settings.chain(settings)),
-            ExportType::Doc(doc) => doc.process(&self.settings.chain(settings)),
+            Some(ExportType::Rust(r)) => r.process(&settings),
+            Some(ExportType::Python(py)) => py.process(&settings),
+            Some(ExportType::Json(json)) => json.process(&settings),
+            Some(ExportType::Svg(svg)) => svg.process(&settings),
+            Some(ExportType::Doc(doc)) => doc.process(&settings),
+            Some(ExportType::History(history)) => history.process(&settings),
+            None => export_all(&settings),
         }
     }
 }
+
+/// Run every export declared in the project config  
+/// Paths in the config are relative to the project folder
+fn export_all(settings: &ProjectSettings) -> GenResult<()> {
+    let p = settings.get_project_path();
+    let project = Project::open_project(&p)?;
+    let export = &project.get_config().export;
+
+    if export.is_empty() {
+        return Err(GenError::ExportFailed(format!(
+            "no targets are declared in {:?}",
+            p.join(CONFIG_FILE)
+        )));
+    }
+
+    if let Some(rust) = &export.rust {
+        Rust {
+            settings: settings.clone(),
+            output: p.join(&rust.output),
+            preview: ExportPreview {
+                check: rust.check,
+                ..Default::default()
+            },
+        }
+        .process(settings)?;
+    }
+
+    if let Some(python) = &export.python {
+        Python {
+            settings: settings.clone(),
+            output: p.join(&python.output),
+            preview: ExportPreview {
+                check: python.check,
+                ..Default::default()
+            },
+        }
+        .process(settings)?;
+    }
+
+    if let Some(json) = &export.json {
+        Json {
+            settings: settings.clone(),
+            output: p.join(&json.output),
+        }
+        .process(settings)?;
+    }
+
+    if let Some(svg) = &export.svg {
+        Svg {
+            settings: settings.clone(),
+            target_dir: p.join(&svg.output),
+        }
+        .process(settings)?;
+    }
+
+    if let Some(doc) = &export.doc {
+        Doc {
+            settings: settings.clone(),
+            out_dir: doc.out_dir.as_ref().map(|out_dir| p.join(out_dir)),
+            target_dir: doc.target_dir.as_ref().map(|target_dir| p.join(target_dir)),
+        }
+        .process(settings)?;
+    }
+
+    if let Some(history) = &export.history {
+        History {
+            settings: settings.clone(),
+            output: Some(p.join(&history.output)),
+            format: history.format,
+        }
+        .process(settings)?;
+    }
+
+    Ok(())
+}
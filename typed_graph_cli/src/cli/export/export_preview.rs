@@ -0,0 +1,59 @@
+use crate::{FileStatus, GenError, GenResult, GeneratedCode};
+use clap::Parser;
+use std::path::Path;
+
+/// Ways to see what an export would change without writing anything
+#[derive(Parser, Debug, Default, Clone)]
+pub struct ExportPreview {
+    /// List the files which would be created, changed, removed or left alone
+    #[clap(long, conflicts_with_all = ["diff", "check"])]
+    pub dry_run: bool,
+
+    /// Print a unified diff of every file which would change
+    #[clap(long, conflicts_with = "check")]
+    pub diff: bool,
+
+    /// Fail if the generated code is out of date
+    #[clap(long)]
+    pub check: bool,
+}
+
+impl ExportPreview {
+    pub fn is_enabled(&self) -> bool {
+        self.dry_run || self.diff || self.check
+    }
+
+    /// Show what exporting the code to the output folder would change
+    pub fn process(&self, code: &GeneratedCode, output: &Path) -> GenResult<()> {
+        if self.diff {
+            print!("{}", code.diff(output)?);
+            return Ok(());
+        }
+
+        let files = code.compare(output)?;
+        let mut outdated = 0;
+        for (p, status) in &files {
+            if status != &FileStatus::Unchanged {
+                outdated += 1;
+            } else if self.check {
+                continue;
+            }
+
+            let name = p.strip_prefix(output).unwrap_or(p);
+            println!("{:<9} {}", status.to_string(), name.to_string_lossy());
+        }
+
+        if self.check && outdated != 0 {
+            return Err(GenError::OutdatedExport {
+                output: output.to_path_buf(),
+                count: outdated,
+            });
+        }
+
+        if self.check {
+            println!("{:?} is up to date", output);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use crate::cli::*;
+use crate::{GenResult, Project, VersionHistory};
+use clap::Parser;
+use std::fs::{create_dir_all, write};
+use std::path::PathBuf;
+
+pub use crate::HistoryFormat;
+
+/// Exports the version tree of the project as a diagram
+///
+/// Each schema is a node and each changeset an edge labelled with the number of added (+), removed (-) and edited (~) items
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct History {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    /// File to save the diagram in, leave out to print it
+    #[clap()]
+    pub output: Option<PathBuf>,
+
+    /// Format of the diagram, svg requires graphviz to be installed
+    #[clap(short, long, value_enum, default_value_t = HistoryFormat::Svg)]
+    pub format: HistoryFormat,
+}
+
+impl Process<ProjectSettings> for History {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+        let prj = Project::open_project(p)?;
+
+        let history = VersionHistory::new(&prj)?;
+        let content = match self.format {
+            HistoryFormat::Dot => history.to_dot(),
+            HistoryFormat::Mermaid => history.to_mermaid(),
+            HistoryFormat::Svg => history.to_svg()?,
+        };
+
+        match &self.output {
+            Some(output) => {
+                if let Some(parent) = output.parent() {
+                    if !parent.as_os_str().is_empty() && !parent.exists() {
+                        create_dir_all(parent)?;
+                    }
+                }
+                write(output, content)?;
+                println!("Done exporting to {:?}", output);
+            }
+            None => print!("{}", content),
+        }
+
+        Ok(())
+    }
+}
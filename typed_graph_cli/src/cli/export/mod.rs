@@ -1,4 +1,6 @@
 mod export;
+mod export_preview;
+mod history;
 mod json;
 mod python;
 mod rust;
@@ -7,6 +9,8 @@ mod doc;
 
 pub use doc::*;
 pub use export::*;
+pub use export_preview::*;
+pub use history::*;
 pub use json::*;
 pub use python::*;
 pub use rust::*;
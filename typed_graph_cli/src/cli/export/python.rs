@@ -19,18 +19,29 @@ pub struct Python {
     /// Where to output the interface
     #[clap()]
     pub output: PathBuf,
+
+    #[clap(flatten)]
+    pub preview: ExportPreview,
 }
 
 impl Process<ProjectSettings> for Python {
     fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
         let p = self.settings.clone().chain(settings).get_project_path();
 
+        let project = Project::open_project(&p)?;
+        if self.preview.is_enabled() {
+            let code = CodeGenerator::<targets::Python>::aggregate_content(&project, &self.output)?;
+            return self.preview.process(&code, &self.output);
+        }
+
         if !self.output.is_dir() {
             create_dir_all(&self.output)?;
         }
 
-        let project = Project::open_project(&p)?;
-        CodeGenerator::<targets::Python>::write_to_file(&project, &self.output)?;
+        let removed = CodeGenerator::<targets::Python>::write_to_file(&project, &self.output)?;
+        for stale_file in removed {
+            println!("Removed {:?}", stale_file);
+        }
 
         println!("Done exporting to {:?}", &self.output);
         Ok(())
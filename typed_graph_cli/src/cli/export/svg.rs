@@ -1,6 +1,6 @@
 use crate::cli::*;
 use crate::{GenResult, Project};
-use build_script_lang::schema::{LowerBound, Schema, SchemaStm};
+use build_script_lang::schema::{Quantifier, Schema, SchemaStm};
 use clap::Parser;
 use graphviz_rust::cmd::{CommandArg, Format, Layout};
 use graphviz_rust::dot_generator::{attr, edge, graph, id, node, node_id, stmt};
@@ -49,10 +49,25 @@ impl Process<ProjectSettings> for Svg {
     }
 }
 
+/// Label shown next to the end of an edge, nothing is shown if any amount is allowed
+fn quantity_label<I>(quantity: &Quantifier<I>) -> Vec<String> {
+    if quantity.is_unbounded() {
+        return Default::default();
+    }
+
+    let lower = quantity.lower;
+    match quantity.upper {
+        Some(upper) => vec![format!("{lower}..{upper}")],
+        None => vec![format!("{lower}..*")],
+    }
+}
+
 pub(crate) fn export_svg<I>(schema: &Schema<I>, target_dir: &PathBuf) -> GenResult<PathBuf>
 where
-    I: Ord,
+    I: Ord + Clone,
 {
+    // Edges through a group are drawn to each of its nodes
+    let schema = &schema.expand_groups();
     let mut g = graph!(
         id!(esc schema.version);
         attr!("mode", "hier"),
@@ -92,13 +107,7 @@ where
                     name = format!("<{name}>");
                     attributes.push(attr!("xlabel", name));
 
-                    let mut head_label = match &endpoint.incoming_quantity.bounds {
-                        Some((lower, upper)) => match lower {
-                            LowerBound::Zero => vec![format!("0..{upper}")],
-                            LowerBound::One => vec![format!("1..{upper}")],
-                        },
-                        None => Default::default(),
-                    };
+                    let mut head_label = quantity_label(&endpoint.incoming_quantity);
 
                     if let Some(out) = endpoint.get_rename_inc() {
                         head_label.push(format!("<FONT COLOR=\"GREY\">{out}</FONT>"));
@@ -106,13 +115,7 @@ where
                     let head_label = format!("<{}>", head_label.join(" - "));
                     attributes.push(attr!("headlabel", head_label));
 
-                    let mut tail_label = match &endpoint.outgoing_quantity.bounds {
-                        Some((lower, upper)) => match lower {
-                            LowerBound::Zero => vec![format!("0..{upper}")],
-                            LowerBound::One => vec![format!("1..{upper}")],
-                        },
-                        None => Default::default(),
-                    };
+                    let mut tail_label = quantity_label(&endpoint.outgoing_quantity);
                     if let Some(out) = endpoint.get_rename_out() {
                         tail_label.push(format!("<FONT COLOR=\"GREY\">{out}</FONT>"));
                     }
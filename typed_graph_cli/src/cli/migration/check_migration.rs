@@ -0,0 +1,63 @@
+use crate::GenError;
+use crate::GenResult;
+use crate::Project;
+use build_changeset_lang::Compatibility;
+use clap::Parser;
+
+use crate::cli::*;
+
+/// Check if the changes between two schemas are backward or forward compatible
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct CheckMigration {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    /// Name of the old schema
+    #[clap()]
+    pub old: String,
+
+    /// Name of the new schema
+    #[clap()]
+    pub new: String,
+}
+
+impl Process<ProjectSettings> for CheckMigration {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+
+        let project = Project::open_project(p)?;
+        for schema in [&self.old, &self.new] {
+            if !project.has_schema(schema) {
+                println!("Failed to find schema {}", schema);
+                println!("Possible schemas are:");
+                for schema in project.iter_schema() {
+                    println!(" - {}", schema);
+                }
+                return Ok(());
+            }
+        }
+
+        let classified_changes = project.check_compatibility(&self.old, &self.new)?;
+
+        let mut compatibility = Compatibility::Safe;
+        for classified in &classified_changes {
+            println!("[{}] {}", classified.compatibility, classified.change);
+            compatibility = compatibility.combine(&classified.compatibility);
+        }
+
+        if compatibility.is_breaking() {
+            return Err(GenError::BreakingChanges {
+                old: self.old.clone(),
+                new: self.new.clone(),
+            });
+        }
+
+        println!(
+            "Changes from {} to {} are {}",
+            self.old, self.new, compatibility
+        );
+
+        Ok(())
+    }
+}
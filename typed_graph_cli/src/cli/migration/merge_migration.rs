@@ -0,0 +1,51 @@
+use crate::GenResult;
+use crate::Project;
+use clap::Parser;
+
+use crate::cli::*;
+
+/// Merge two heads into a new schema with changesets from both of them
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct MergeMigration {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    /// Name of the head to merge into
+    #[clap()]
+    pub head_a: String,
+
+    /// Name of the head to merge from
+    #[clap()]
+    pub head_b: String,
+
+    /// Name of the merged schema
+    #[clap(short, long)]
+    pub name: Option<String>,
+}
+
+impl Process<ProjectSettings> for MergeMigration {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+
+        let mut project = Project::open_project(p)?;
+        for schema in [&self.head_a, &self.head_b] {
+            if !project.has_schema(schema) {
+                println!("Failed to find schema {}", schema);
+                println!("Possible schemas are:");
+                for schema in project.iter_schema() {
+                    println!(" - {}", schema);
+                }
+                return Ok(());
+            }
+        }
+
+        let new_name = project.merge_heads(&self.head_a, &self.head_b, self.name.clone())?;
+        println!(
+            "Merged {} and {} into {}",
+            self.head_a, self.head_b, new_name
+        );
+
+        Ok(())
+    }
+}
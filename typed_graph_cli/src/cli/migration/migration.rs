@@ -15,7 +15,12 @@ pub struct Migration {
 #[derive(Subcommand, Debug)]
 pub enum MigrationType {
     Add(AddMigration),
+    Check(CheckMigration),
     Link(LinkMigration),
+    Merge(MergeMigration),
+    Rehash(RehashMigrations),
+    Remove(RemoveMigration),
+    Squash(SquashMigration),
     Update(UpdateMigrations),
 }
 
@@ -23,7 +28,12 @@ impl Process<ProjectSettings> for Migration {
     fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
         match &self.cmd {
             MigrationType::Add(a) => a.process(&self.settings.chain(settings)),
+            MigrationType::Check(a) => a.process(&self.settings.chain(settings)),
             MigrationType::Link(a) => a.process(&self.settings.chain(settings)),
+            MigrationType::Merge(a) => a.process(&self.settings.chain(settings)),
+            MigrationType::Rehash(a) => a.process(&self.settings.chain(settings)),
+            MigrationType::Remove(a) => a.process(&self.settings.chain(settings)),
+            MigrationType::Squash(a) => a.process(&self.settings.chain(settings)),
             MigrationType::Update(a) => a.process(&self.settings.chain(settings)),
         }
     }
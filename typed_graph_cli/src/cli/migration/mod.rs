@@ -1,9 +1,19 @@
 mod add_migration;
+mod check_migration;
 mod link_migration;
+mod merge_migration;
 mod migration;
+mod rehash_migrations;
+mod remove_migration;
+mod squash_migration;
 mod update_migrations;
 
 pub use add_migration::*;
+pub use check_migration::*;
 pub use link_migration::*;
+pub use merge_migration::*;
 pub use migration::*;
+pub use rehash_migrations::*;
+pub use remove_migration::*;
+pub use squash_migration::*;
 pub use update_migrations::*;
@@ -0,0 +1,34 @@
+use crate::GenResult;
+use crate::Project;
+use clap::Parser;
+
+use crate::cli::*;
+
+/// Update changesets made with an older hash version
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct RehashMigrations {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+}
+
+impl Process<ProjectSettings> for RehashMigrations {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+
+        let mut project = Project::open_project_raw(p)?;
+
+        let changeset_ids: Vec<_> = project.iter_changesets().copied().collect();
+        for changeset_id in changeset_ids {
+            if let Some(new_id) = project.rehash_changeset(&changeset_id)? {
+                let changeset = project.get_changeset(&new_id)?;
+                println!(
+                    "Rehashed changeset from {} to {}",
+                    changeset.old_version, changeset.new_version
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use crate::GenResult;
+use crate::Project;
+use clap::Parser;
+
+use crate::cli::*;
+
+/// Delete the changeset between two schemas
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct RemoveMigration {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    /// Name of the old schema
+    #[clap()]
+    pub old: String,
+
+    /// Name of the new schema
+    #[clap()]
+    pub new: String,
+
+    /// Delete the changeset even if schemas are left without a path to the rest of the project
+    #[clap(short, long)]
+    pub force: bool,
+}
+
+impl Process<ProjectSettings> for RemoveMigration {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+
+        let mut project = Project::open_project(p)?;
+        for schema in [&self.old, &self.new] {
+            if !project.has_schema(schema) {
+                println!("Failed to find schema {}", schema);
+                println!("Possible schemas are:");
+                for schema in project.iter_schema() {
+                    println!(" - {}", schema);
+                }
+                return Ok(());
+            }
+        }
+
+        project.delete_changeset_between(&self.old, &self.new, self.force)?;
+        println!("Removed changeset from {} to {}", self.old, self.new);
+
+        Ok(())
+    }
+}
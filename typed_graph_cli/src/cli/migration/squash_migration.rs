@@ -0,0 +1,60 @@
+use crate::GenResult;
+use crate::Project;
+use clap::Parser;
+
+use crate::cli::*;
+
+/// Merge all changesets between two schemas into a single changeset
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct SquashMigration {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    /// Name of the schema to squash from
+    #[clap()]
+    pub source: String,
+
+    /// Name of the schema to squash to
+    #[clap()]
+    pub target: String,
+
+    /// Delete the schemas in between source and target  
+    /// Required since they would be left without changesets
+    #[clap(short, long)]
+    pub remove_schemas: bool,
+}
+
+impl Process<ProjectSettings> for SquashMigration {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+
+        let mut project = Project::open_project(p)?;
+        for schema in [&self.source, &self.target] {
+            if !project.has_schema(schema) {
+                println!("Failed to find schema {}", schema);
+                println!("Possible schemas are:");
+                for schema in project.iter_schema() {
+                    println!(" - {}", schema);
+                }
+                return Ok(());
+            }
+        }
+
+        let squashed =
+            project.squash_changesets(&self.source, &self.target, self.remove_schemas)?;
+        let Some((id, p)) = squashed else {
+            println!(
+                "{} and {} are already linked by a single changeset",
+                self.source, self.target
+            );
+            return Ok(());
+        };
+
+        println!("Squashed changes from {} to {}:", self.source, self.target);
+        println!("{}", project.get_changeset(&id)?);
+        println!("Saved changeset for {} to {:?}", self.target, p);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,6 @@
 mod args;
+mod check;
+mod data;
 mod export;
 mod list;
 mod migration;
@@ -6,8 +8,11 @@ mod new_project;
 mod process;
 mod project_settings;
 mod schema;
+mod watch;
 
 pub use args::*;
+pub use check::*;
+pub use data::*;
 pub use export::*;
 pub use list::*;
 pub use migration::*;
@@ -15,3 +20,4 @@ pub use new_project::*;
 pub use process::*;
 pub use project_settings::*;
 pub use schema::*;
+pub use watch::*;
@@ -39,7 +39,7 @@ impl Process<ProjectSettings> for NewProject {
                 .as_ref()
                 .map(|s| s.as_str())
                 .unwrap_or_else(|| "V0.0");
-            let input = Mark::new(InputMarker::new_from_file(name.to_string(), p.join("schemas").join(name).join("schema.bs").to_string_lossy().to_string()));
+            let input = Mark::new(InputMarker::new_from_file(name.to_string(), prj.get_schema_folder().join(name).join("schema.bs").to_string_lossy().to_string()));
             let id = prj.add_schema(Schema::new(
                 Comments::default(),
                 Ident::new(name, input.clone()),
@@ -0,0 +1,53 @@
+use crate::GenResult;
+use crate::Project;
+use clap::Parser;
+
+use crate::cli::*;
+
+/// Delete a schema along with its changesets
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct DeleteSchema {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    /// Name of the schema to delete
+    #[clap()]
+    pub schema: String,
+
+    /// Link the parents of the schema directly to its children by combining the changesets around it
+    #[clap(short, long)]
+    pub reconnect: bool,
+
+    /// Delete the schema even if other schemas are left without a path to the rest of the project
+    #[clap(short, long)]
+    pub force: bool,
+}
+
+impl Process<ProjectSettings> for DeleteSchema {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+
+        let mut project = Project::open_project(p)?;
+        if !project.has_schema(&self.schema) {
+            println!("Failed to find schema {}", &self.schema);
+            println!("Possible schemas are:");
+            for schema in project.iter_schema() {
+                println!(" - {}", schema);
+            }
+            return Ok(());
+        }
+
+        let composed = project.delete_schema(&self.schema, self.reconnect, self.force)?;
+        for id in composed {
+            let changeset = project.get_changeset(&id)?;
+            println!(
+                "Reconnected {} to {}",
+                changeset.old_version, changeset.new_version
+            );
+        }
+        println!("Deleted schema {}", &self.schema);
+
+        Ok(())
+    }
+}
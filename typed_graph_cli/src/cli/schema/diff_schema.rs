@@ -0,0 +1,88 @@
+use crate::GenResult;
+use crate::Project;
+use crate::SchemaDiff;
+use build_script_shared::parsers::ParserSerialize;
+use clap::{Parser, ValueEnum};
+use std::io::IsTerminal;
+use std::path::Path;
+
+use crate::cli::*;
+
+/// Show the changes between two schemas without creating a changeset
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct DiffSchema {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    /// Name of the old schema or path to a schema file or folder
+    #[clap()]
+    pub old: String,
+
+    /// Name of the new schema or path to a schema file or folder
+    #[clap()]
+    pub new: String,
+
+    /// How the changes should be printed
+    #[clap(long, value_enum, default_value_t = DiffFormat::Summary)]
+    pub format: DiffFormat,
+
+    /// Do not color the summary
+    #[clap(long)]
+    pub no_color: bool,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffFormat {
+    /// One line per change along with its compatibility
+    Summary,
+    /// The changes as they would be written to a .bs.diff file
+    Changeset,
+}
+
+impl Process<ProjectSettings> for DiffSchema {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+
+        // Comparing two files does not require a project
+        let is_files = [&self.old, &self.new]
+            .iter()
+            .all(|schema| Path::new(schema).exists());
+        let project = if is_files {
+            None
+        } else {
+            Some(Project::open_project(p)?)
+        };
+
+        let mut schemas = Vec::new();
+        for schema in [&self.old, &self.new] {
+            match &project {
+                Some(project) if project.has_schema(schema) => {
+                    schemas.push(project.get_schema(schema)?.clone())
+                }
+                _ if Path::new(schema).exists() => schemas.push(Project::read_schema(schema)?),
+                _ => {
+                    println!("Failed to find schema {}", schema);
+                    if let Some(project) = &project {
+                        println!("Possible schemas are:");
+                        for schema in project.iter_schema() {
+                            println!(" - {}", schema);
+                        }
+                    }
+                    return Ok(());
+                }
+            }
+        }
+
+        let diff = SchemaDiff::new(&schemas[0], &schemas[1])?;
+        match self.format {
+            DiffFormat::Summary => {
+                let color = !self.no_color && std::io::stdout().is_terminal();
+                print!("{}", diff.summary(color));
+            }
+            DiffFormat::Changeset => print!("{}", diff.changeset.serialize_to_string()?),
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,11 @@
 mod clone_schema;
+mod delete_schema;
+mod diff_schema;
 mod rename_schema;
 mod schema;
 
 pub use clone_schema::*;
+pub use delete_schema::*;
+pub use diff_schema::*;
 pub use rename_schema::*;
 pub use schema::*;
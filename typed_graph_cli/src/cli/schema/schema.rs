@@ -15,14 +15,18 @@ pub struct Schema {
 #[derive(Subcommand, Debug)]
 pub enum SchemaType {
     Clone(CloneSchema),
+    Delete(DeleteSchema),
     Rename(RenameSchema),
+    Diff(DiffSchema),
 }
 
 impl Process<ProjectSettings> for Schema {
     fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
         match &self.cmd {
             SchemaType::Clone(a) => a.process(&self.settings.chain(settings)),
+            SchemaType::Delete(a) => a.process(&self.settings.chain(settings)),
             SchemaType::Rename(a) => a.process(&self.settings.chain(settings)),
+            SchemaType::Diff(a) => a.process(&self.settings.chain(settings)),
         }
     }
 }
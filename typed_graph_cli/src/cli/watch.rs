@@ -0,0 +1,121 @@
+use crate::cli::*;
+use crate::{targets, CodeGenerator, GenResult, Project, CONFIG_FILE};
+use clap::Parser;
+use notify_debouncer_mini::new_debouncer;
+use notify_debouncer_mini::notify::RecursiveMode;
+use std::fs::create_dir_all;
+use std::path::Path;
+use std::sync::mpsc::channel;
+use std::time::Duration;
+
+/// Regenerate the code every time a schema or changeset is changed
+///
+/// Only the rust and python targets declared in typed_graph.toml are regenerated
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+pub struct Watch {
+    #[clap(flatten)]
+    pub settings: ProjectSettings,
+
+    /// How many milliseconds to wait for more changes before regenerating
+    #[clap(long, default_value_t = 200)]
+    pub debounce: u64,
+}
+
+impl Process<ProjectSettings> for Watch {
+    fn process(&self, settings: &ProjectSettings) -> GenResult<()> {
+        let p = self.settings.chain(settings).get_project_path();
+
+        // The folders are only read once so changing them in the config requires a restart
+        let (_, schema_folder, changeset_folder) = Project::open_project_directory(&p)?;
+
+        let (tx, rx) = channel();
+        let mut debouncer = new_debouncer(Duration::from_millis(self.debounce), tx)?;
+        let watcher = debouncer.watcher();
+        watcher.watch(&schema_folder, RecursiveMode::Recursive)?;
+        watcher.watch(&changeset_folder, RecursiveMode::Recursive)?;
+
+        let config_path = p.join(CONFIG_FILE);
+        if config_path.exists() {
+            watcher.watch(&config_path, RecursiveMode::NonRecursive)?;
+        }
+
+        rebuild(&p);
+        println!("Watching {:?} for changes", p);
+
+        for res in rx {
+            match res {
+                Ok(events) => {
+                    for event in &events {
+                        println!("Changed {:?}", event.path);
+                    }
+                    rebuild(&p);
+                }
+                Err(e) => println!("{}", e),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Check the project and regenerate the code if no errors were found
+///
+/// Failing to regenerate is only reported so the watch can continue
+fn rebuild(p: &Path) {
+    let problems = match Project::check_project(p) {
+        Ok(problems) => problems,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+
+    for problem in &problems {
+        println!("{}", problem);
+    }
+
+    let error_count = problems.iter().filter(|problem| problem.is_error()).count();
+    if error_count != 0 {
+        println!("Found {} problems, waiting for changes", error_count);
+        return;
+    }
+
+    if let Err(e) = export_code(p) {
+        println!("{}", e);
+    }
+}
+
+/// Regenerate the rust and python targets
+/// Files are only written if their content changed
+fn export_code(p: &Path) -> GenResult<()> {
+    let project = Project::open_project(p)?;
+    let export = &project.get_config().export;
+
+    if export.rust.is_none() && export.python.is_none() {
+        println!("Project is valid, no rust or python targets are declared in {CONFIG_FILE}");
+        return Ok(());
+    }
+
+    if let Some(rust) = &export.rust {
+        let output = p.join(&rust.output);
+        create_dir_all(&output)?;
+        let removed = CodeGenerator::<targets::Rust>::write_to_file(&project, &output)?;
+        for stale_file in removed {
+            println!("Removed {:?}", stale_file);
+        }
+        println!("Done exporting to {:?}", output);
+    }
+
+    if let Some(python) = &export.python {
+        let output = p.join(&python.output);
+        create_dir_all(&output)?;
+        let removed = CodeGenerator::<targets::Python>::write_to_file(&project, &output)?;
+        for stale_file in removed {
+            println!("Removed {:?}", stale_file);
+        }
+        println!("Done exporting to {:?}", output);
+    }
+
+    Ok(())
+}
@@ -1,32 +1,54 @@
-use std::collections::HashMap;
-use std::fs::{write, File};
+use similar::TextDiff;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::Display;
+use std::fs::{create_dir_all, read_dir, read_to_string, remove_dir, remove_file, write, File};
 use std::io::Read;
 use std::ops::Deref;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 use crate::GenResult;
 
+/// Name of the file listing every file created by the last export
+pub const MANIFEST_FILE: &str = ".typed_graph_manifest";
+
 pub trait CodeGenerator<Target> {
     fn get_filename(&self) -> String;
     fn aggregate_content<P: AsRef<Path>>(&self, p: P) -> GenResult<GeneratedCode>;
 
-    fn write_to_file<P: AsRef<Path>>(&self, p: P) -> GenResult<()> {
-        self.aggregate_content(p)?.write_all()
+    /// Write the generated files and delete the ones left over from the previous export  
+    /// Returns the files which were deleted
+    fn write_to_file<P: AsRef<Path>>(&self, p: P) -> GenResult<Vec<PathBuf>> {
+        let p = p.as_ref();
+        let code = self.aggregate_content(p)?;
+        code.write_all()?;
+        code.update_manifest(p)
     }
 }
 
+/// What exporting the code will do to a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileStatus {
+    Created,
+    Changed,
+    Unchanged,
+    Removed,
+}
+
 /// A flat container of all the written content to different files
 ///
 /// This reduces the likelyhood of conflicting writes to files as the files are only written to once
 #[derive(Debug)]
 pub struct GeneratedCode {
     new_files: HashMap<PathBuf, Option<String>>,
+    /// Files which are only created once and then belong to the user
+    user_files: HashSet<PathBuf>,
 }
 
 impl GeneratedCode {
     pub fn new() -> GeneratedCode {
         GeneratedCode {
             new_files: Default::default(),
+            user_files: Default::default(),
         }
     }
 
@@ -40,12 +62,14 @@ impl GeneratedCode {
     }
 
     pub fn create_file(&mut self, path: PathBuf) {
+        self.user_files.insert(path.clone());
         if !self.new_files.contains_key(&path) {
             self.new_files.insert(path, None);
         }
     }
 
     pub fn create_file_with_default(&mut self, path: PathBuf, content: String) {
+        self.user_files.insert(path.clone());
         if !path.exists() {
             self.add_content(path, content);
         }
@@ -59,10 +83,135 @@ impl GeneratedCode {
                 self.create_file(p);
             }
         }
+        self.user_files.extend(other.user_files);
+    }
+
+    /// Files listed in the manifest of the previous export which are no longer generated
+    ///
+    /// Files belonging to the user are never included
+    pub fn find_stale_files<P: AsRef<Path>>(&self, root: P) -> GenResult<Vec<PathBuf>> {
+        let root = root.as_ref();
+        let manifest_path = root.join(MANIFEST_FILE);
+        if !manifest_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let manifest = read_to_string(manifest_path)?;
+        let stale_files = manifest
+            .lines()
+            .map(Path::new)
+            // Only trust paths pointing inside the output folder
+            .filter(|p| p.components().all(|c| matches!(c, Component::Normal(_))))
+            .map(|p| root.join(p))
+            .filter(|p| !self.new_files.contains_key(p) && !self.user_files.contains(p))
+            .filter(|p| p.is_file())
+            .collect();
+
+        Ok(stale_files)
+    }
+
+    /// Delete the stale files from the previous export and list the generated files in a new manifest  
+    /// Folders left empty by the deleted files are removed as well
+    pub fn update_manifest<P: AsRef<Path>>(&self, root: P) -> GenResult<Vec<PathBuf>> {
+        let root = root.as_ref();
+        let stale_files = self.find_stale_files(root)?;
+        for stale_file in &stale_files {
+            remove_file(stale_file)?;
+
+            let mut folder = stale_file.parent();
+            while let Some(f) = folder {
+                if f == root || read_dir(f)?.next().is_some() {
+                    break;
+                }
+                remove_dir(f)?;
+                folder = f.parent();
+            }
+        }
+
+        // Sorted so the manifest does not change between exports of the same project
+        let generated_files: BTreeSet<_> = self
+            .new_files
+            .keys()
+            .filter(|p| !self.user_files.contains(*p))
+            .filter_map(|p| p.strip_prefix(root).ok())
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .collect();
+
+        let mut manifest = String::new();
+        for p in generated_files {
+            manifest += &p;
+            manifest += "\n";
+        }
+        write(root.join(MANIFEST_FILE), manifest)?;
+
+        Ok(stale_files)
+    }
+
+    /// Compare the generated files with the ones currently on disk without writing anything  
+    /// The files are sorted by path
+    pub fn compare<P: AsRef<Path>>(&self, root: P) -> GenResult<Vec<(PathBuf, FileStatus)>> {
+        let mut files = Vec::new();
+        for (p, c) in &self.new_files {
+            let status = match c {
+                _ if !p.exists() => FileStatus::Created,
+                Some(content) if content != &read_to_string(p)? => FileStatus::Changed,
+                _ => FileStatus::Unchanged,
+            };
+            files.push((p.clone(), status));
+        }
+
+        for p in self.find_stale_files(root)? {
+            files.push((p, FileStatus::Removed));
+        }
+
+        files.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Ok(files)
+    }
+
+    /// Create a unified diff of every file which would be created, changed or removed
+    pub fn diff<P: AsRef<Path>>(&self, root: P) -> GenResult<String> {
+        let root = root.as_ref();
+        let mut s = String::new();
+        for (p, status) in self.compare(root)? {
+            let old_content = match status {
+                FileStatus::Unchanged => continue,
+                FileStatus::Created => String::new(),
+                FileStatus::Changed | FileStatus::Removed => read_to_string(&p)?,
+            };
+            let new_content = match status {
+                FileStatus::Removed => None,
+                _ => self.new_files.get(&p).cloned().flatten(),
+            };
+
+            let name = p.strip_prefix(root).unwrap_or(&p).to_string_lossy();
+            let old_name = match status {
+                FileStatus::Created => "/dev/null".to_string(),
+                _ => format!("a/{name}"),
+            };
+            let new_name = match status {
+                FileStatus::Removed => "/dev/null".to_string(),
+                _ => format!("b/{name}"),
+            };
+
+            let new_content = new_content.unwrap_or_default();
+            let diff = TextDiff::from_lines(&old_content, &new_content);
+            s += &diff
+                .unified_diff()
+                .header(&old_name, &new_name)
+                .to_string();
+        }
+
+        Ok(s)
     }
 
     pub fn write_all(&self) -> GenResult<()> {
         for (p, c) in &self.new_files {
+            if let Some(folder) = p.parent() {
+                if !folder.exists() {
+                    create_dir_all(folder)?;
+                }
+            }
+
             if let Some(content) = c {
                 if !p.exists() {
                     File::create(&p)?;
@@ -85,9 +234,72 @@ impl GeneratedCode {
     }
 }
 
+impl Display for FileStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileStatus::Created => write!(f, "created"),
+            FileStatus::Changed => write!(f, "changed"),
+            FileStatus::Unchanged => write!(f, "unchanged"),
+            FileStatus::Removed => write!(f, "removed"),
+        }
+    }
+}
+
 impl Deref for GeneratedCode {
     type Target = HashMap<PathBuf, Option<String>>;
     fn deref(&self) -> &Self::Target {
         &self.new_files
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn update_manifest_test() -> GenResult<()> {
+    use std::fs::create_dir_all;
+    use tempdir::TempDir;
+
+    let tmp = TempDir::new("typed_graph")?;
+    let root = tmp.path();
+    create_dir_all(root.join("V0_0/nodes"))?;
+
+    let mut code = GeneratedCode::new();
+    code.add_content(root.join("mod.rs"), "mod V0_0;".to_string());
+    code.add_content(root.join("V0_0/nodes/a.rs"), "struct A;".to_string());
+    code.add_content(root.join("V0_0/nodes/b.rs"), "struct B;".to_string());
+    code.create_file(root.join("imports.rs"));
+    code.create_file_with_default(root.join("V0_0/imports.py"), "NodeId = str".to_string());
+    code.write_all()?;
+    assert_eq!(code.update_manifest(root)?, Vec::<PathBuf>::new());
+    assert_eq!(
+        read_to_string(root.join(MANIFEST_FILE))?,
+        "V0_0/nodes/a.rs\nV0_0/nodes/b.rs\nmod.rs\n"
+    );
+
+    let mut code = GeneratedCode::new();
+    code.add_content(root.join("mod.rs"), "".to_string());
+    code.create_file(root.join("imports.rs"));
+    assert_eq!(
+        code.compare(root)?,
+        vec![
+            (root.join("V0_0/nodes/a.rs"), FileStatus::Removed),
+            (root.join("V0_0/nodes/b.rs"), FileStatus::Removed),
+            (root.join("imports.rs"), FileStatus::Unchanged),
+            (root.join("mod.rs"), FileStatus::Changed),
+        ]
+    );
+    assert!(code
+        .diff(root)?
+        .contains("--- a/mod.rs\n+++ b/mod.rs\n@@ -1 +0,0 @@\n-mod V0_0;"));
+    code.write_all()?;
+    assert_eq!(
+        code.update_manifest(root)?,
+        vec![root.join("V0_0/nodes/a.rs"), root.join("V0_0/nodes/b.rs")]
+    );
+
+    // Files belonging to the user are kept along with their folders
+    assert!(!root.join("V0_0/nodes").exists());
+    assert!(root.join("V0_0/imports.py").exists());
+    assert!(root.join("imports.rs").exists());
+    assert_eq!(read_to_string(root.join(MANIFEST_FILE))?, "mod.rs\n");
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use build_script_lang::schema::{LowerBound, Quantifier};
+use build_script_lang::schema::Quantifier;
 use std::fmt::Write;
 
 use crate::GenResult;
@@ -16,21 +16,14 @@ pub enum EdgeRepresentation {
 
 impl EdgeRepresentation {
     pub fn from_quantity<I>(quantity: &Quantifier<I>) -> EdgeRepresentation {
-        EdgeRepresentation::from_bounds(&quantity.bounds)
+        EdgeRepresentation::from_bounds(quantity.lower, quantity.upper)
     }
     
-    pub fn from_bounds(bounds: &Option<(LowerBound, u32)>) -> EdgeRepresentation {
-        match &bounds {
-            Some((lower, upper)) => {
-                if lower == &LowerBound::One && upper == &1 {
-                    EdgeRepresentation::Result
-                } else if upper == &1 {
-                    EdgeRepresentation::Option
-                } else {
-                    EdgeRepresentation::Iterator
-                }
-            }
-            None => EdgeRepresentation::Iterator,
+    pub fn from_bounds(lower: u32, upper: Option<u32>) -> EdgeRepresentation {
+        match upper {
+            Some(1) if lower == 1 => EdgeRepresentation::Result,
+            Some(0 | 1) => EdgeRepresentation::Option,
+            _ => EdgeRepresentation::Iterator,
         }
     }
 
@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+
+use build_script_lang::schema::{Schema, SchemaStm};
+
+use crate::Direction;
+
+/// Copy of the schema as seen when traversing the edges in the given direction
+///
+/// Groups at the start of an endpoint are expanded into their members, so every node gets its own getters,
+/// while groups at the end are kept so the getters can return any of the members
+pub fn directed_endpoints<I: Clone + Ord>(schema: &Schema<I>, dir: Direction) -> Schema<I> {
+    let mut directed = schema.clone();
+    for stm in directed.iter_mut() {
+        if let SchemaStm::Edge(e) = stm {
+            let mut endpoints = BTreeMap::new();
+            for endpoint in e.endpoints.values() {
+                let start = match dir {
+                    Direction::Forward => &endpoint.source,
+                    Direction::Backwards => &endpoint.target,
+                };
+
+                for member in schema.group_members(start) {
+                    let mut pair = endpoint.clone();
+                    match dir {
+                        Direction::Forward => pair.source = member.clone(),
+                        Direction::Backwards => pair.target = member.clone(),
+                    }
+                    endpoints.insert((pair.source.clone(), pair.target.clone()), pair);
+                }
+            }
+            e.endpoints = endpoints;
+        }
+    }
+
+    directed
+}
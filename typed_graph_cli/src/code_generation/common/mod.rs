@@ -1,5 +1,7 @@
 mod edge_representation;
 mod direction_constants;
+mod group_endpoints;
 
 pub use direction_constants::*;
-pub use edge_representation::*;
\ No newline at end of file
+pub use edge_representation::*;
+pub use group_endpoints::*;
@@ -0,0 +1,55 @@
+use build_script_lang::schema::Schema;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::{GenResult, GeneratedCode, ToPythonType};
+
+/// Write ./aliases.py
+///
+/// The aliases are expanded so they can be declared in any order
+pub(super) fn write_aliases_py<I: Ord + Clone>(
+    schema: &Schema<I>,
+    new_files: &mut GeneratedCode,
+    schema_folder: &Path,
+) -> GenResult<()> {
+    let aliases_path = schema_folder.join("aliases.py");
+    let schema = schema.expand_aliases();
+    let mut s = String::new();
+
+    writeln!(s, "from __future__ import annotations")?;
+    writeln!(
+        s,
+        "from typing import Optional, List, Set, Dict, TypeVar, TypeAlias"
+    )?;
+    writeln!(s, "from ..primitives import *")?;
+    writeln!(s, "from ..imports import *")?;
+    writeln!(s, "from .imports import *")?;
+    writeln!(s, "from .structs import *")?;
+    writeln!(s, "from .types import *")?;
+
+    let mut declared_generics = HashSet::new();
+    for alias in schema.aliases() {
+        writeln!(s)?;
+        for generic in &alias.generics.generics {
+            let letter = generic.letter.to_string();
+            if declared_generics.insert(letter.clone()) {
+                writeln!(s, "{letter} = TypeVar(\"{letter}\")")?;
+            }
+        }
+
+        for comment in alias.comments.iter_doc() {
+            writeln!(s, "# {comment}")?;
+        }
+        writeln!(
+            s,
+            "{}: TypeAlias = {}",
+            alias.name,
+            alias.ty.to_python_type_quoted(false)
+        )?;
+    }
+
+    new_files.add_content(aliases_path, s);
+
+    Ok(())
+}
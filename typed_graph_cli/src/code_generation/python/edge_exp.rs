@@ -4,7 +4,9 @@ use indexmap::IndexSet;
 use std::collections::{BTreeMap, HashSet};
 use std::fmt::{Debug, Write};
 use std::path::Path;
-use crate::common::{function_suffix, rename_attribute_name, search_dir, EdgeRepresentation};
+use crate::common::{
+    directed_endpoints, function_suffix, rename_attribute_name, search_dir, EdgeRepresentation,
+};
 use crate::{targets, CodeGenerator, Direction, GenError, GenResult, GeneratedCode, ToSnakeCase};
 
 use super::{write_comments, write_fields};
@@ -29,6 +31,8 @@ impl<I> CodeGenerator<targets::Python> for EdgeExp<I> {
         writeln!(s, "from ..edge_type import EdgeType")?;
         writeln!(s, "from ..structs import *")?;
         writeln!(s, "from ..types import *")?;
+        writeln!(s, "from ..aliases import *")?;
+        writeln!(s, "from ...primitives import *")?;
         writeln!(s, "from ...imports import *")?;
         writeln!(s, "from ..imports import *")?;
         writeln!(s, "from pydantic import Field, AliasChoices")?;
@@ -126,7 +130,7 @@ pub(super) fn write_edge_type_py<I: Ord>(
     Ok(())
 }
 
-pub(super) fn write_edge_endpoints_py<I: Debug + Ord>(
+pub(super) fn write_edge_endpoints_py<I: Debug + Ord + Clone>(
     schema: &Schema<I>,
     new_file: &mut GeneratedCode,
     nodes_path: &Path,
@@ -135,14 +139,23 @@ pub(super) fn write_edge_endpoints_py<I: Debug + Ord>(
 
     let mut edges: BTreeMap<_, BTreeMap<_, Vec<_>>> = BTreeMap::new();
 
-    for edge in schema.edges() {
-        for ((source, target), endpoint) in &edge.endpoints {
+    // Each node in a group gets the getters of the group
+    let forward_schema = directed_endpoints(schema, Direction::Forward);
+    let backwards_schema = directed_endpoints(schema, Direction::Backwards);
+
+    for edge in forward_schema.edges() {
+        for ((source, _), endpoint) in &edge.endpoints {
             edges
                 .entry(source)
                 .or_default()
                 .entry(Direction::Forward)
                 .or_default()
                 .push((endpoint, edge));
+        }
+    }
+
+    for edge in backwards_schema.edges() {
+        for ((_, target), endpoint) in &edge.endpoints {
             edges
                 .entry(target)
                 .or_default()
@@ -152,6 +165,16 @@ pub(super) fn write_edge_endpoints_py<I: Debug + Ord>(
         }
     }
 
+    let groups: BTreeMap<_, _> = schema
+        .groups()
+        .map(|g| {
+            (
+                g.name.to_string(),
+                g.members.iter().map(|m| m.to_string()).collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+
     let mut nodes = BTreeMap::new();
 
     for node in schema.nodes() {
@@ -182,18 +205,27 @@ pub(super) fn write_edge_endpoints_py<I: Debug + Ord>(
                 grouped_by_end.entry(end).or_default().push(edge);
                 grouped_by_start.entry(start).or_default().push(&edge.name);
                 grouped_by_edge
-                    .entry(edge.name.clone())
+                    .entry(edge.name.to_string())
                     .or_default()
                     .insert(end);
             }
 
-            write_getter_with_node(&mut s, node, dir, &schema_name, &nodes, &grouped_by_end)?;
+            write_getter_with_node(
+                &mut s,
+                node,
+                dir,
+                &schema_name,
+                &nodes,
+                &groups,
+                &grouped_by_end,
+            )?;
 
             write_getter_with_edge(
                 &mut s,
                 dir,
                 &schema_name,
                 &mut edge_impl,
+                &groups,
                 &endpoints,
                 &grouped_by_edge,
             )?;
@@ -203,6 +235,7 @@ pub(super) fn write_edge_endpoints_py<I: Debug + Ord>(
                 dir,
                 &schema_name,
                 &mut endpoint_impl,
+                &groups,
                 &endpoints,
             )?;
         }
@@ -214,6 +247,29 @@ pub(super) fn write_edge_endpoints_py<I: Debug + Ord>(
     Ok(())
 }
 
+/// The annotation used for a node or any of the nodes in a group
+fn node_annotation<I>(node: &Ident<I>, groups: &BTreeMap<String, Vec<String>>) -> String {
+    if groups.contains_key(node.as_str()) {
+        format!("{node}")
+    } else {
+        format!("Node.{node}")
+    }
+}
+
+/// Filter keeping the pairs of (edge, node) where the node is of the given type or in the given group
+fn node_type_filter<I>(node: &Ident<I>, groups: &BTreeMap<String, Vec<String>>) -> String {
+    if let Some(members) = groups.get(node.as_str()) {
+        let node_types = members
+            .iter()
+            .map(|m| format!("NodeType.{m}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("filter(lambda ne: ne[1].get_type() in [{node_types}], nodes)")
+    } else {
+        format!("filter(lambda ne: ne[1].get_type() == NodeType.{node}, nodes)")
+    }
+}
+
 /// Create getter functions with a fixed node type
 fn write_getter_with_node<I: Debug + Ord>(
     s: &mut String,
@@ -221,6 +277,7 @@ fn write_getter_with_node<I: Debug + Ord>(
     dir: Direction,
     schema_name: &str,
     nodes: &BTreeMap<&Ident<I>, &NodeExp<I>>,
+    groups: &BTreeMap<String, Vec<String>>,
     grouped_by_end: &BTreeMap<&Ident<I>, Vec<&&EdgeExp<I>>>,
 ) -> GenResult<()> {
     for (end, edges) in grouped_by_end {
@@ -295,7 +352,7 @@ fn write_getter_with_node<I: Debug + Ord>(
             edge_repr = EdgeRepresentation::Option;
         }
 
-        let return_type = edge_repr.get_return_type_python(target_type, format!("Node.{end}"));
+        let return_type = edge_repr.get_return_type_python(target_type, node_annotation(end, groups));
 
         let rename_attribute = nodes.get(node).and_then(|n| {
             n.attributes
@@ -323,10 +380,7 @@ fn write_getter_with_node<I: Debug + Ord>(
             s,
             "        nodes = map(lambda e: (e.weight, g.get_node(e.get_outer())), edges)"
         )?;
-        writeln!(
-            s,
-            "        nodes = filter(lambda ne: ne[1].get_type() == NodeType.{end}, nodes)"
-        )?;
+        writeln!(s, "        nodes = {}", node_type_filter(end, groups))?;
         edge_repr.collect_results_python(edge_name_list, s)?;
         writeln!(s, "        return nodes")?;
     }
@@ -340,6 +394,7 @@ fn write_getter_with_edge<I: Debug + Ord>(
     dir: Direction,
     schema_name: &str,
     edge_impl: &mut HashSet<(String, Direction)>,
+    groups: &BTreeMap<String, Vec<String>>,
     endpoints: &Vec<(&EndPoint<I>, &EdgeExp<I>)>,
     grouped_by_edge: &BTreeMap<String, IndexSet<&Ident<I>>>,
 ) -> GenResult<()> {
@@ -363,13 +418,13 @@ fn write_getter_with_edge<I: Debug + Ord>(
             ))),
 
             // Use specific type
-            [n] => Ok((format!("Node.{n}"), true)),
+            [n] => Ok((node_annotation(n, groups), true)),
 
             // Use any of the specific types
             nodes if nodes.len() < 10 => {
                 let generics = nodes
                     .iter()
-                    .map(|n| format!("Node.{n}"))
+                    .map(|n| node_annotation(n, groups))
                     .collect::<Vec<_>>()
                     .join(" | ");
                 Ok((generics, true))
@@ -426,6 +481,7 @@ fn write_getter_with_node_and_edge<I: Debug + Ord>(
     dir: Direction,
     schema_name: &str,
     endpoint_impl: &mut HashSet<(String, String, Direction)>,
+    groups: &BTreeMap<String, Vec<String>>,
     endpoints: &Vec<(&EndPoint<I>, &EdgeExp<I>)>,
 ) -> GenResult<()> {
     for (endpoint, edge) in endpoints {
@@ -469,7 +525,7 @@ fn write_getter_with_node_and_edge<I: Debug + Ord>(
 
         let edge_repr = EdgeRepresentation::from_quantity(quantity);
 
-        let return_type = edge_repr.get_return_type_python(format!("Edge.{edge_type}"), format!("Node.{target_type}"));
+        let return_type = edge_repr.get_return_type_python(format!("Edge.{edge_type}"), node_annotation(target_type, groups));
 
         // Write get by edge type method
         writeln!(s, "")?;
@@ -482,10 +538,7 @@ fn write_getter_with_node_and_edge<I: Debug + Ord>(
             s,
             "        nodes = map(lambda e: (e.weight, g.get_node(e.get_outer())), edges)"
         )?;
-        writeln!(
-            s,
-            "        nodes = filter(lambda ne: ne[1].get_type() == NodeType.{target_type}, nodes)"
-        )?;
+        writeln!(s, "        nodes = {}", node_type_filter(target_type, groups))?;
         edge_repr.collect_results_python(edge_type, s)?;
         writeln!(s, "        return nodes")?;
     }
@@ -2,7 +2,8 @@ use build_script_lang::schema::{EnumExp, EnumVarient};
 use std::fmt::Write;
 
 use crate::{
-    targets, CodeGenerator, GeneratedCode, ToDefaultPythonValue, ToPythonType, ToSnakeCase,
+    targets, CodeGenerator, GeneratedCode, ToDefaultPythonValue, ToPythonType, ToPythonValue,
+    ToSnakeCase,
 };
 
 use super::write_comments;
@@ -31,12 +32,14 @@ impl<I> CodeGenerator<targets::Python> for EnumExp<I> {
             "from typing import Optional, List, Set, Dict, TypeVar, Generic, ClassVar, Annotated, Literal, TYPE_CHECKING"
         )?;
         writeln!(s, "from pydantic import Field, AliasChoices")?;
+        writeln!(s, "from ...primitives import *")?;
         writeln!(s, "")?;
         writeln!(s, "if TYPE_CHECKING:")?;
         writeln!(s, "    from ..imports import *")?;
         writeln!(s, "    from ...imports import *")?;
         writeln!(s, "    from ..structs import *")?;
         writeln!(s, "    from ..types import *")?;
+        writeln!(s, "    from ..aliases import *")?;
         writeln!(s)?;
 
         for generic in &self.generics.generics {
@@ -91,7 +94,12 @@ impl<I> CodeGenerator<targets::Python> for EnumExp<I> {
                         // Handle skipped
                         if field_value.attributes.is_skipped() {
                             field_attributes.push("exclude=True".to_string());
+                        }
 
+                        if let Some(default) = &field_value.default {
+                            let default = default.to_python_value(&field_value.field_type);
+                            field_attributes.push(format!("default_factory=lambda:{default}"));
+                        } else if field_value.attributes.is_skipped() {
                             let default = field_value.field_type.to_default_python_value();
                             field_attributes.push(format!("default_factory=lambda:{default}"));
                         }
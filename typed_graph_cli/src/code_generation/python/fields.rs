@@ -2,7 +2,7 @@ use std::fmt::Write;
 
 use build_script_lang::schema::Fields;
 
-use crate::{GenResult, ToDefaultPythonValue, ToPythonType};
+use crate::{GenResult, ToDefaultPythonValue, ToPythonType, ToPythonValue};
 
 use super::write_comments;
 
@@ -18,7 +18,10 @@ pub fn write_fields<I>(s: &mut impl Write, fields: &Fields<I>, quote_fields: boo
             field_attributes.push("exclude=True".to_string());
         }
 
-        if field_value.attributes.is_skipped() || field_value.attributes.is_default() {
+        if let Some(default) = &field_value.default {
+            let default = default.to_python_value(&field_value.field_type);
+            field_attributes.push(format!("default_factory=lambda:{default}"));
+        } else if field_value.attributes.is_skipped() || field_value.attributes.is_default() {
             let default = field_value.field_type.to_default_python_value();
             field_attributes.push(format!("default_factory=lambda:{default}"));
         }
@@ -0,0 +1,38 @@
+use build_script_lang::schema::Schema;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::{GenResult, GeneratedCode};
+
+/// Write ./groups.py
+///
+/// Each group is a union of the nodes in it
+pub(super) fn write_groups_py<I: Ord>(
+    schema: &Schema<I>,
+    new_files: &mut GeneratedCode,
+    schema_folder: &Path,
+) -> GenResult<()> {
+    let groups_path = schema_folder.join("groups.py");
+    let mut s = String::new();
+
+    writeln!(s, "from typing import Union, TypeAlias")?;
+    writeln!(s, "from .nodes import *")?;
+
+    for group in schema.groups() {
+        writeln!(s)?;
+        for comment in group.comments.iter_doc() {
+            writeln!(s, "# {comment}")?;
+        }
+        let members = group
+            .members
+            .iter()
+            .map(|m| m.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(s, "{}: TypeAlias = Union[{members}]", group.name)?;
+    }
+
+    new_files.add_content(groups_path, s);
+
+    Ok(())
+}
@@ -1,15 +1,19 @@
+mod alias_exp;
 mod comments;
 mod edge_exp;
 mod enum_exp;
 mod fields;
+mod group_exp;
 mod node_exp;
 mod project;
 mod schema;
 mod struct_exp;
 mod to_python_type;
 
+use alias_exp::*;
 use comments::*;
 use edge_exp::*;
 use fields::*;
+use group_exp::*;
 use node_exp::*;
 pub use to_python_type::*;
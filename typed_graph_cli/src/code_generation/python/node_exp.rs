@@ -27,6 +27,8 @@ impl<I> CodeGenerator<targets::Python> for NodeExp<I> {
         writeln!(s, "from ..edge_type import EdgeType")?;
         writeln!(s, "from ..structs import *")?;
         writeln!(s, "from ..types import *")?;
+        writeln!(s, "from ..aliases import *")?;
+        writeln!(s, "from ...primitives import *")?;
         writeln!(s, "from ...imports import *")?;
         writeln!(s, "from ..imports import *")?;
         writeln!(s, "from typed_graph import NodeExt, RecievedNoneValue")?;
@@ -35,25 +37,43 @@ impl<I> CodeGenerator<targets::Python> for NodeExp<I> {
             "from typing import Optional, List, Set, Dict, Iterator, Tuple, ClassVar, TYPE_CHECKING"
         )?;
         writeln!(s, "from pydantic import Field, AliasChoices")?;
+        if let Some(parent) = &self.parent {
+            writeln!(s, "from .{} import {parent}", parent.to_string().to_snake_case())?;
+        }
         writeln!(s, "")?;
         writeln!(s, "if TYPE_CHECKING:")?;
         writeln!(s, "    from .. import *")?;
         writeln!(s, "    from ..edges import *")?;
         writeln!(s, "    from ..nodes import *")?;
+        writeln!(s, "    from ..groups import *")?;
         writeln!(s, "")?;
-        writeln!(s, "class {node_name}(NodeExt[NodeId, NodeType]):")?;
-        write_comments(&mut s, &self.comments)?;
-        writeln!(s, "    id: NodeId")?;
-        write_fields(&mut s, &self.fields, false)?;
-        writeln!(s, "")?;
-        writeln!(s, "    def get_id(self) -> NodeId:")?;
-        writeln!(s, "        return self.id")?;
-        writeln!(s)?;
-        writeln!(s, "    def set_id(self, id: NodeId) -> None:")?;
-        writeln!(s, "        self.id = id")?;
-        writeln!(s)?;
-        writeln!(s, "    def get_type(self) -> NodeType:")?;
-        writeln!(s, "        return NodeType.{node_name}")?;
+        // Children only declare their own fields and inherit the rest from the base class
+        if let Some(parent) = &self.parent {
+            writeln!(s, "class {node_name}({parent}):")?;
+            write_comments(&mut s, &self.comments)?;
+            write_fields(&mut s, &self.fields, false)?;
+            if self.is_abstract && self.fields.is_empty() {
+                writeln!(s, "    pass")?;
+            }
+        } else {
+            writeln!(s, "class {node_name}(NodeExt[NodeId, NodeType]):")?;
+            write_comments(&mut s, &self.comments)?;
+            writeln!(s, "    id: NodeId")?;
+            write_fields(&mut s, &self.fields, false)?;
+            writeln!(s)?;
+            writeln!(s, "    def get_id(self) -> NodeId:")?;
+            writeln!(s, "        return self.id")?;
+            writeln!(s)?;
+            writeln!(s, "    def set_id(self, id: NodeId) -> None:")?;
+            writeln!(s, "        self.id = id")?;
+        }
+
+        // Abstract nodes never exist in the graph so they have no type
+        if !self.is_abstract {
+            writeln!(s)?;
+            writeln!(s, "    def get_type(self) -> NodeType:")?;
+            writeln!(s, "        return NodeType.{node_name}")?;
+        }
 
         let mut new_files = GeneratedCode::new();
         new_files.add_content(node_path, s);
@@ -69,7 +89,10 @@ pub(super) fn write_nodes_py<I: Ord>(
 ) -> GenResult<()> {
     let node_path = schema_folder.join("node.py");
 
-    let nodes: Vec<_> = schema.nodes().map(|n| n.name.to_string()).collect();
+    let nodes: Vec<_> = schema
+        .concrete_nodes()
+        .map(|n| n.name.to_string())
+        .collect();
 
     let mut node = String::new();
     writeln!(node, "from .node_type import NodeType")?;
@@ -108,7 +131,7 @@ pub(super) fn write_node_type_py<I: Ord>(
     schema_folder: &Path,
 ) -> GenResult<()> {
     let node_path = schema_folder.join("node_type.py");
-    let nodes: Vec<_> = schema.nodes().collect();
+    let nodes: Vec<_> = schema.concrete_nodes().collect();
     let mut node_type = String::new();
 
     writeln!(node_type, "from typed_graph import StrEnum")?;
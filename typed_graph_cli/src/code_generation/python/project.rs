@@ -1,3 +1,4 @@
+use std::fmt::Write;
 use std::path::Path;
 
 use crate::{targets, CodeGenerator, GenError, GenResult, GeneratedCode, Project};
@@ -12,7 +13,7 @@ impl CodeGenerator<targets::Python> for Project {
         p: P,
     ) -> crate::GenResult<GeneratedCode> {
         let output_folder = p.as_ref();
-        if !output_folder.exists() || !output_folder.is_dir() {
+        if output_folder.exists() && !output_folder.is_dir() {
             return Err(GenError::MissingFolder {
                 folder: output_folder
                     .to_str()
@@ -31,6 +32,7 @@ impl CodeGenerator<targets::Python> for Project {
         }
 
         write_init(&mut new_files, output_folder)?;
+        write_primitives(&mut new_files, output_folder)?;
 
         Ok(new_files)
     }
@@ -45,3 +47,40 @@ fn write_init(new_files: &mut GeneratedCode, project_folder: &Path) -> GenResult
 
     Ok(())
 }
+
+/// Write ./primitives.py
+///
+/// Duration and Bytes are read and written the same way serde does in the rust export
+fn write_primitives(new_files: &mut GeneratedCode, project_folder: &Path) -> GenResult<()> {
+    let primitives_path = project_folder.join("primitives.py");
+
+    let mut s = String::new();
+    writeln!(s, "from datetime import datetime, date, timedelta, timezone")?;
+    writeln!(s, "from uuid import UUID")?;
+    writeln!(s, "from typing import Annotated, List")?;
+    writeln!(s, "from pydantic import AwareDatetime, BeforeValidator, PlainSerializer")?;
+    writeln!(s)?;
+    writeln!(s, "def _parse_duration(value):")?;
+    writeln!(s, "    if isinstance(value, dict):")?;
+    writeln!(s, "        return timedelta(seconds=value['secs'], microseconds=value['nanos'] // 1000)")?;
+    writeln!(s, "    return value")?;
+    writeln!(s)?;
+    writeln!(s, "def _serialize_duration(value: timedelta) -> dict:")?;
+    writeln!(s, "    microseconds = value // timedelta(microseconds=1)")?;
+    writeln!(s, "    return {{'secs': microseconds // 1000000, 'nanos': microseconds % 1000000 * 1000}}")?;
+    writeln!(s)?;
+    writeln!(s, "def _parse_bytes(value):")?;
+    writeln!(s, "    if isinstance(value, list):")?;
+    writeln!(s, "        return bytes(value)")?;
+    writeln!(s, "    return value")?;
+    writeln!(s)?;
+    writeln!(s, "DateTime = AwareDatetime")?;
+    writeln!(s, "Date = date")?;
+    writeln!(s, "Duration = Annotated[timedelta, BeforeValidator(_parse_duration), PlainSerializer(_serialize_duration, return_type=dict, when_used='json')]")?;
+    writeln!(s, "Uuid = UUID")?;
+    writeln!(s, "Bytes = Annotated[bytes, BeforeValidator(_parse_bytes), PlainSerializer(list, return_type=List[int], when_used='json')]")?;
+
+    new_files.add_content(primitives_path, s);
+
+    Ok(())
+}
@@ -4,13 +4,13 @@ use std::fmt::{Debug, Write};
 use std::path::Path;
 
 use super::{
-    write_edge_endpoints_py, write_edge_type_py, write_edges_py, write_node_type_py, write_nodes_py,
+    write_aliases_py, write_edge_endpoints_py, write_edge_type_py, write_edges_py,
+    write_groups_py, write_node_type_py, write_nodes_py,
 };
-use std::fs::create_dir;
 
 impl<I> CodeGenerator<targets::Python> for Schema<I>
 where
-    I: Ord + Debug,
+    I: Ord + Debug + Clone,
 {
     fn get_filename(&self) -> String {
         self.version.to_string().replace(".", "_").to_snake_case()
@@ -23,28 +23,11 @@ where
         let schema_folder = p
             .as_ref()
             .join(CodeGenerator::<targets::Python>::get_filename(self));
-        if !schema_folder.exists() {
-            create_dir(&schema_folder)?;
-        }
-
         let nodes_folder = schema_folder.join("nodes");
         let structs_folder = schema_folder.join("structs");
         let edges_folder = schema_folder.join("edges");
         let types_folder = schema_folder.join("types");
 
-        if !nodes_folder.exists() {
-            create_dir(&nodes_folder)?;
-        }
-        if !structs_folder.exists() {
-            create_dir(&structs_folder)?;
-        }
-        if !edges_folder.exists() {
-            create_dir(&edges_folder)?;
-        }
-        if !types_folder.exists() {
-            create_dir(&types_folder)?;
-        }
-
         let mut new_files = GeneratedCode::new();
 
         write_content(
@@ -70,6 +53,8 @@ where
         )?;
         write_schema_impl_py(self, &mut new_files, &schema_folder)?;
         write_edge_endpoints_py(self, &mut new_files, &nodes_folder)?;
+        write_aliases_py(self, &mut new_files, &schema_folder)?;
+        write_groups_py(self, &mut new_files, &schema_folder)?;
 
         Ok(new_files)
     }
@@ -101,7 +86,9 @@ where
             SchemaStm::Enum(n) => {
                 CodeGenerator::<targets::Python>::aggregate_content(n, &types_folder)
             }
-            SchemaStm::Import(_) => Ok(GeneratedCode::new()),
+            SchemaStm::Import(_) | SchemaStm::Alias(_) | SchemaStm::Group(_) => {
+                Ok(GeneratedCode::new())
+            }
         }?;
 
         new_files.append(added_files);
@@ -155,7 +142,7 @@ where
                 &n.name,
                 &mut types_init,
             ),
-            SchemaStm::Import(_) => continue,
+            SchemaStm::Import(_) | SchemaStm::Alias(_) | SchemaStm::Group(_) => continue,
         };
 
         writeln!(f, "from .{filename} import {type_name}")?;
@@ -172,7 +159,7 @@ where
             SchemaStm::Struct(n) => (&n.name, &mut structs_init),
             SchemaStm::Edge(n) => (&n.name, &mut edges_init),
             SchemaStm::Enum(n) => (&n.name, &mut types_init),
-            SchemaStm::Import(_) => continue,
+            SchemaStm::Import(_) | SchemaStm::Alias(_) | SchemaStm::Group(_) => continue,
         };
 
         writeln!(f, "    '{type_name}',")?;
@@ -201,6 +188,7 @@ where
     writeln!(s, "from ..imports import *")?;
     writeln!(s, "from .structs import *")?;
     writeln!(s, "from .types import *")?;
+    writeln!(s, "from .aliases import *")?;
     writeln!(s, "")?;
     for stm in schema.iter() {
         match stm {
@@ -213,6 +201,7 @@ where
     writeln!(s, "")?;
     writeln!(s, "from .edge import Edge")?;
     writeln!(s, "from .node import Node")?;
+    writeln!(s, "from .groups import *")?;
     writeln!(s, "from .schema import {schema_name}")?;
     writeln!(s, "")?;
     writeln!(s, "from typed_graph import TypedGraph, PartialTypedGraph")?;
@@ -257,7 +246,7 @@ where
     Ok(())
 }
 
-fn write_schema_impl_py<I: Ord>(
+fn write_schema_impl_py<I: Ord + Clone>(
     schema: &Schema<I>,
     new_files: &mut GeneratedCode,
     schema_folder: &Path,
@@ -292,16 +281,18 @@ fn write_schema_impl_py<I: Ord>(
         schema_py,
         "    endpoint_meta: ClassVar[Dict[Tuple[EdgeType, NodeType, NodeType], int]] = {{"
     )?;
-    for e in schema.edges() {
+    // The graph only ever sees the nodes in a group
+    let expanded = schema.expand_groups();
+    for e in expanded.edges() {
         let edge_type = &e.name;
         for ((source, target), endpoint) in &e.endpoints {
-            let outgoing_quantity = endpoint.outgoing_quantity.bounds;
-            let incoming_quantity = endpoint.incoming_quantity.bounds;
+            let outgoing_quantity = endpoint.outgoing_quantity.upper;
+            let incoming_quantity = endpoint.incoming_quantity.upper;
 
             let outgoing_quantity_text =
-                outgoing_quantity.map_or_else(|| "None".to_string(), |(_, q)| q.to_string());
+                outgoing_quantity.map_or_else(|| "None".to_string(), |q| q.to_string());
             let incoming_quantity_text =
-                incoming_quantity.map_or_else(|| "None".to_string(), |(_, q)| q.to_string());
+                incoming_quantity.map_or_else(|| "None".to_string(), |q| q.to_string());
 
             writeln!(schema_py, "        (EdgeType.{edge_type}, NodeType.{source}, NodeType.{target}): ({outgoing_quantity_text}, {incoming_quantity_text}),")?;
         }
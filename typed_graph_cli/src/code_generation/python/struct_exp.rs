@@ -28,12 +28,14 @@ impl<I> CodeGenerator<targets::Python> for StructExp<I> {
             "from typing import Optional, List, Set, Dict, TypeVar, Generic, ClassVar, TYPE_CHECKING"
         )?;
         writeln!(s, "from pydantic import Field, AliasChoices")?;
+        writeln!(s, "from ...primitives import *")?;
         writeln!(s, "")?;
         writeln!(s, "if TYPE_CHECKING:")?;
         writeln!(s, "    from ..imports import *")?;
         writeln!(s, "    from ...imports import *")?;
         writeln!(s, "    from ..structs import *")?;
         writeln!(s, "    from ..types import *")?;
+        writeln!(s, "    from ..aliases import *")?;
         writeln!(s)?;
         for generic in &self.generics.generics {
             let letter = &generic.letter;
@@ -1,4 +1,4 @@
-use build_script_shared::parsers::Types;
+use build_script_shared::parsers::{Literal, Types};
 
 pub trait ToPythonType {
     fn to_python_type(&self) -> String {
@@ -24,6 +24,15 @@ impl<I> ToPythonType for Types<I> {
             Types::I32(_) => "int".to_string(),
             Types::I16(_) => "int".to_string(),
             Types::I8(_) => "int".to_string(),
+            Types::U128(_) => "int".to_string(),
+            Types::I128(_) => "int".to_string(),
+            Types::Char(_) => "str".to_string(),
+            // The remaining primitives are declared in primitives.py
+            Types::DateTime(_) => "DateTime".to_string(),
+            Types::Date(_) => "Date".to_string(),
+            Types::Duration(_) => "Duration".to_string(),
+            Types::Uuid(_) => "Uuid".to_string(),
+            Types::Bytes(_) => "Bytes".to_string(),
             Types::Option { inner, .. } => {
                 format!("Optional[{}]", inner.to_python_type_quoted(requires_quotes))
             }
@@ -86,6 +95,14 @@ impl<I> ToDefaultPythonValue for Types<I> {
             Types::I32(_) => "0".to_string(),
             Types::I16(_) => "0".to_string(),
             Types::I8(_) => "0".to_string(),
+            Types::U128(_) => "0".to_string(),
+            Types::I128(_) => "0".to_string(),
+            Types::Char(_) => "'\\0'".to_string(),
+            Types::DateTime(_) => "datetime.fromtimestamp(0, timezone.utc)".to_string(),
+            Types::Date(_) => "date(1970, 1, 1)".to_string(),
+            Types::Duration(_) => "timedelta()".to_string(),
+            Types::Uuid(_) => "UUID(int=0)".to_string(),
+            Types::Bytes(_) => "b''".to_string(),
             Types::Option { .. } => "None".to_string(),
             Types::List { .. }
             | Types::Set { .. } => "[]".to_string(),
@@ -110,3 +127,46 @@ impl<I> ToDefaultPythonValue for Types<I> {
         }
     }
 }
+
+pub trait ToPythonValue<I> {
+    fn to_python_value(&self, ty: &Types<I>) -> String;
+}
+
+impl<I> ToPythonValue<I> for Literal<I> {
+    fn to_python_value(&self, ty: &Types<I>) -> String {
+        match (self, ty) {
+            (Literal::None { .. }, _) => "None".to_string(),
+            (literal, Types::Option { inner, .. }) => literal.to_python_value(inner),
+            (Literal::Number { value, .. }, _) => value.clone(),
+            (Literal::String { value, .. }, _) => python_str_literal(value),
+            (Literal::Bool { value: true, .. }, _) => "True".to_string(),
+            (Literal::Bool { value: false, .. }, _) => "False".to_string(),
+            (Literal::List { .. }, Types::Set { .. }) => "set()".to_string(),
+            (Literal::List { .. }, _) => "[]".to_string(),
+            (Literal::Map { .. }, _) => "{}".to_string(),
+            (Literal::Varient { name }, Types::Reference { inner, .. }) => {
+                format!("{inner}.{name}")
+            }
+            (Literal::Varient { name }, _) => name.to_string(),
+        }
+    }
+}
+
+/// Write a string as a python string literal
+fn python_str_literal(value: &str) -> String {
+    let mut s = String::from('"');
+    for c in value.chars() {
+        match c {
+            '\\' => s.push_str("\\\\"),
+            '"' => s.push_str("\\\""),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            // All control characters are below 0x100 so they fit in a \x escape
+            c if c.is_control() => s.push_str(&format!("\\x{:02x}", c as u32)),
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+    s
+}
@@ -0,0 +1,60 @@
+use build_script_lang::schema::Schema;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::{GenResult, GeneratedCode, ToRustType};
+
+use super::{write_comments, FieldFormatter};
+
+/// All aliases of a schema are written to aliases.rs as type aliases
+pub(super) fn write_aliases_rs<I: Ord>(
+    schema: &Schema<I>,
+    new_files: &mut GeneratedCode,
+    schema_folder: &Path,
+) -> GenResult<()> {
+    let aliases_path = schema_folder.join("aliases.rs");
+    let mut s = String::new();
+
+    writeln!(s, "#[allow(unused_imports)]")?;
+    writeln!(s, "use super::super::imports::*;")?;
+    writeln!(s, "#[allow(unused_imports)]")?;
+    writeln!(s, "use super::*;")?;
+    writeln!(s, "#[allow(unused_imports)]")?;
+    writeln!(s, "use indexmap::IndexMap;")?;
+    writeln!(s, "#[allow(unused_imports)]")?;
+    writeln!(s, "use std::collections::HashSet;")?;
+
+    for alias in schema.aliases() {
+        let generics = alias
+            .generics
+            .generics
+            .iter()
+            .map(|generic| generic.letter.to_string())
+            .collect::<Vec<_>>();
+        let generics = if generics.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", generics.join(", "))
+        };
+
+        writeln!(s)?;
+        write_comments(
+            &mut s,
+            &alias.comments,
+            FieldFormatter {
+                indents: 0,
+                include_visibility: true,
+            },
+        )?;
+        writeln!(
+            s,
+            "pub type {}{generics} = {};",
+            alias.name,
+            alias.ty.to_rust_type()
+        )?;
+    }
+
+    new_files.add_content(aliases_path, s);
+
+    Ok(())
+}
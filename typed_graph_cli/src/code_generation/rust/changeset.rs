@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 use std::fmt::Debug;
+use std::hash::Hash;
 use std::path::Path;
 
 use super::*;
@@ -10,16 +11,22 @@ use crate::{targets, CodeGenerator, GenResult, GeneratedCode, Project, ToSnakeCa
 
 impl<I> CodeGenerator<targets::Rust> for (&ChangeSet<I>, &Schema<I>, &Schema<I>, &Project)
 where
-    I: Ord + Debug + Clone + PartialEq + Default,
+    I: Ord + Debug + Clone + PartialEq + Default + Hash,
 {
     fn get_filename(&self) -> String {
         CodeGenerator::<targets::Rust>::get_filename(&(self.3, self.1))
     }
 
     fn aggregate_content<P: AsRef<Path>>(&self, p: P) -> GenResult<GeneratedCode> {
-        let changeset = self.0;
-        let old_schema = self.1;
-        let new_schema = self.2;
+        // The migrations work on the nodes with their inherited fields,
+        // the concrete endpoints of each group and on the types the aliases stand for
+        let changeset = &self.0.resolve_inheritance(self.1, self.2)?;
+        let old_resolved = &self.1.resolve_inheritance();
+        let new_resolved = &self.2.resolve_inheritance();
+        let changeset = &changeset.expand_groups(old_resolved, new_resolved)?;
+        let old_schema = &old_resolved.expand_groups().expand_aliases();
+        let new_schema = &new_resolved.expand_groups().expand_aliases();
+        let changeset = &changeset.expand_aliases(old_schema, new_schema);
         let new_schema_folder = p
             .as_ref()
             .join(CodeGenerator::<targets::Rust>::get_filename(&(self.3, self.2)));
@@ -49,11 +56,14 @@ where
 
         for stm in new_schema.iter() {
             // Check if the type is new
-            if !old_types.contains(stm.get_type()) {
+            let old_type = changeset.get_old_type_name(stm.get_type());
+            if !old_types.contains(old_type) {
                 continue;
             }
 
             let (folder, filename) = match stm {
+                // Abstract nodes are not generated
+                SchemaStm::Node(n) if n.is_abstract => continue,
                 SchemaStm::Node(n) => (
                     &nodes_folder,
                     CodeGenerator::<targets::Rust>::get_filename(n),
@@ -70,17 +80,20 @@ where
                     &types_folder,
                     CodeGenerator::<targets::Rust>::get_filename(t),
                 ),
-                SchemaStm::Import(_) => continue,
+                SchemaStm::Import(_) | SchemaStm::Alias(_) | SchemaStm::Group(_) => continue,
             };
 
-            let parent_ty = format!("super::super::super::{}::{}", old_mod, stm.get_type());
+            let parent_ty = format!("super::super::super::{}::{}", old_mod, old_type);
+            let old_fields = old_schema
+                .get_type(None, old_type)
+                .and_then(SchemaStm::get_fields);
 
             let from = match stm {
-                SchemaStm::Node(n) => write_node_from(n, changeset, &parent_ty),
-                SchemaStm::Struct(n) => write_struct_from(n, changeset, &parent_ty),
-                SchemaStm::Edge(e) => write_edge_from(e, changeset, &parent_ty),
+                SchemaStm::Node(n) => write_node_from(n, changeset, old_fields, &parent_ty),
+                SchemaStm::Struct(n) => write_struct_from(n, changeset, old_fields, &parent_ty),
+                SchemaStm::Edge(e) => write_edge_from(e, changeset, old_fields, &parent_ty),
                 SchemaStm::Enum(t) => write_type_from(t, changeset, &parent_ty),
-                SchemaStm::Import(_) => unimplemented!(),
+                SchemaStm::Import(_) | SchemaStm::Alias(_) | SchemaStm::Group(_) => unimplemented!(),
             }?;
 
             let path = folder.join(format!("{}.rs", filename));
@@ -88,7 +101,8 @@ where
         }
 
         let new_path = format!("super::super::{}", new_mod);
-        let s = write_migrate_schema(old_schema, new_schema, &changeset.handler, &new_path)?;
+        let lossy_changes = changeset.get_lossy_changes(old_schema)?;
+        let s = write_migrate_schema(old_schema, new_schema, changeset, &lossy_changes, &new_path)?;
         let path = old_schema_folder.join("schema.rs");
         new_files.add_content(path, s);
 
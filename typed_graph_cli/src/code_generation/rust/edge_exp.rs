@@ -1,17 +1,23 @@
 use build_changeset_lang::{ChangeSet, FieldPath, SingleChange};
-use build_script_lang::schema::{EdgeExp, EndPoint, NodeExp, Schema};
+use build_script_lang::schema::{EdgeExp, EndPoint, Fields, NodeExp, Schema};
 use build_script_shared::parsers::Ident;
 use indexmap::IndexSet;
 use std::collections::{BTreeMap, HashSet};
 use std::fmt::{Debug, Write};
+use std::hash::Hash;
 use std::path::Path;
 
-use crate::common::{function_suffix, rename_attribute_name, search_dir, EdgeRepresentation};
+use crate::common::{
+    directed_endpoints, function_suffix, rename_attribute_name, search_dir, EdgeRepresentation,
+};
 use crate::{
     targets, CodeGenerator, Direction, GenError, GenResult, GeneratedCode, ToRustType, ToSnakeCase,
 };
 
-use super::{write_comments, write_fields, FieldFormatter};
+use super::{
+    added_field_value, edited_field_value, write_comments, write_field_defaults, write_fields,
+    write_fill_bindings, FieldFormatter,
+};
 
 impl<I> CodeGenerator<targets::Rust> for EdgeExp<I> {
     fn get_filename(&self) -> String {
@@ -61,6 +67,7 @@ impl<I> CodeGenerator<targets::Rust> for EdgeExp<I> {
         writeln!(s, "    pub(crate) id: EK,")?;
         write_fields(
             &mut s,
+            &edge_name.to_string(),
             &self.fields,
             FieldFormatter {
                 indents: 1,
@@ -68,6 +75,12 @@ impl<I> CodeGenerator<targets::Rust> for EdgeExp<I> {
             },
         )?;
         writeln!(s, "}}")?;
+        write_field_defaults(
+            &mut s,
+            &edge_name.to_string(),
+            &self.fields,
+            &[],
+        )?;
 
         writeln!(s, "")?;
         writeln!(s, "#[allow(unused)]")?;
@@ -526,6 +539,7 @@ pub(super) fn write_edge_type_rs<I: Ord>(
 pub(super) fn write_edge_from<I>(
     e: &EdgeExp<I>,
     changeset: &ChangeSet<I>,
+    old_fields: Option<&Fields<I>>,
     parent_ty: &String,
 ) -> GenResult<String>
 where
@@ -548,19 +562,31 @@ where
         s,
         "    fn try_from(other: {parent_ty}<EK>) -> Result<Self, Self::Error> {{"
     )?;
+    write_fill_bindings(&mut s, changeset, &e.name)?;
     writeln!(s, "        Ok({edge_type} {{")?;
     writeln!(s, "            id: other.id.into(),")?;
     for field_value in e.fields.iter() {
         let field_name = &field_value.name;
         let field_path = FieldPath::new_path(e.name.clone(), vec![field_name.clone()]);
         let changes = changeset.get_changes(field_path.clone());
-        let is_news = changes
-            .iter()
-            .any(|c| matches!(c, SingleChange::AddedField(_)));
-        if is_news {
-            writeln!(s, "           {field_name}: Default::default(),")?;
+        let old_field_name = changeset
+            .get_old_field_name(&field_path)
+            .unwrap_or(field_name);
+        let added_field = changes.iter().find_map(|c| {
+            if let SingleChange::AddedField(v) = c {
+                Some(v)
+            } else {
+                None
+            }
+        });
+        if let Some(added_field) = added_field {
+            if let Some(value) = added_field_value(added_field, old_fields) {
+                writeln!(s, "           {field_name}: {value},")?;
+            } else {
+                omit_convertion = true;
+                writeln!(s, "           {field_name}: /* Insert convertion */,")?;
+            }
         } else {
-            let mut need_manual_implementation = false;
             let type_change = changes
                 .iter()
                 .filter_map(|c| {
@@ -572,26 +598,15 @@ where
                 })
                 .next();
             if let Some(type_change) = type_change {
-                if !type_change
-                    .old_type()
-                    .is_gen_compatible(type_change.new_type())
-                {
+                if let Some(value) = edited_field_value(type_change, old_field_name, old_fields) {
+                    writeln!(s, "           {field_name}: {value},")?;
+                } else {
                     // We cannot trust the auto generated conversion so a manual one should be made instead
                     omit_convertion = true;
-                    need_manual_implementation = true;
-                }
-
-                if need_manual_implementation {
                     writeln!(s, "           {field_name}: /* Insert convertion */,")?;
-                } else {
-                    writeln!(
-                        s,
-                        "           {field_name}: {},",
-                        type_change.old_type().gen_convertion(format!("other.{field_name}"), true, type_change.new_type())
-                    )?;
                 }
             } else {
-                writeln!(s, "           {field_name}: {},", field_value.field_type.gen_convertion(format!("other.{field_name}"), true, &field_value.field_type))?;
+                writeln!(s, "           {field_name}: {},", field_value.field_type.gen_convertion(format!("other.{old_field_name}"), true, &field_value.field_type))?;
             }
         }
     }
@@ -606,7 +621,7 @@ where
     }
 }
 
-pub(super) fn write_edge_endpoints<I: Debug + Ord>(
+pub(super) fn write_edge_endpoints<I: Debug + Ord + Clone + Hash>(
     schema: &Schema<I>,
     new_file: &mut GeneratedCode,
     nodes_path: &Path,
@@ -615,14 +630,23 @@ pub(super) fn write_edge_endpoints<I: Debug + Ord>(
 
     let mut edges: BTreeMap<_, BTreeMap<_, Vec<_>>> = BTreeMap::new();
 
-    for edge in schema.edges() {
-        for ((source, target), endpoint) in &edge.endpoints {
+    // Each node in a group gets the getters of the group
+    let forward_schema = directed_endpoints(schema, Direction::Forward);
+    let backwards_schema = directed_endpoints(schema, Direction::Backwards);
+
+    for edge in forward_schema.edges() {
+        for ((source, _), endpoint) in &edge.endpoints {
             edges
                 .entry(source)
                 .or_default()
                 .entry(Direction::Forward)
                 .or_default()
                 .push((endpoint, edge));
+        }
+    }
+
+    for edge in backwards_schema.edges() {
+        for ((_, target), endpoint) in &edge.endpoints {
             edges
                 .entry(target)
                 .or_default()
@@ -632,6 +656,8 @@ pub(super) fn write_edge_endpoints<I: Debug + Ord>(
         }
     }
 
+    let groups: HashSet<_> = schema.groups().map(|g| &g.name).collect();
+
     let mut nodes = BTreeMap::new();
 
     for node in schema.nodes() {
@@ -666,18 +692,27 @@ pub(super) fn write_edge_endpoints<I: Debug + Ord>(
                 grouped_by_end.entry(end).or_default().push(edge);
                 grouped_by_start.entry(start).or_default().push(&edge.name);
                 grouped_by_edge
-                    .entry(edge.name.clone())
+                    .entry(edge.name.to_string())
                     .or_default()
                     .insert(end);
             }
 
-            write_getter_with_node(&mut s, node, dir, &schema_name, &nodes, &grouped_by_end)?;
+            write_getter_with_node(
+                &mut s,
+                node,
+                dir,
+                &schema_name,
+                &nodes,
+                &groups,
+                &grouped_by_end,
+            )?;
 
             write_getter_with_edge(
                 &mut s,
                 dir,
                 &schema_name,
                 &mut edge_impl,
+                &groups,
                 &endpoints,
                 &grouped_by_edge,
             )?;
@@ -687,6 +722,7 @@ pub(super) fn write_edge_endpoints<I: Debug + Ord>(
                 dir,
                 &schema_name,
                 &mut endpoint_impl,
+                &groups,
                 &endpoints,
             )?;
         }
@@ -700,13 +736,23 @@ pub(super) fn write_edge_endpoints<I: Debug + Ord>(
     Ok(())
 }
 
+/// The type used to reference a node or any of the nodes in a group
+fn node_ref_type<I: Hash + Eq>(node: &Ident<I>, groups: &HashSet<&Ident<I>>) -> String {
+    if groups.contains(node) {
+        format!("{node}<'a, NK>")
+    } else {
+        format!("&'a {node}<NK>")
+    }
+}
+
 /// Create getter functions with a fixed node type
-fn write_getter_with_node<I: Debug + Ord>(
+fn write_getter_with_node<I: Debug + Ord + Hash>(
     s: &mut String,
     node: &Ident<I>,
     dir: Direction,
     schema_name: &str,
     nodes: &BTreeMap<&Ident<I>, &NodeExp<I>>,
+    groups: &HashSet<&Ident<I>>,
     grouped_by_end: &BTreeMap<&Ident<I>, Vec<&&EdgeExp<I>>>,
 ) -> GenResult<()> {
     for (end, edges) in grouped_by_end {
@@ -781,7 +827,7 @@ fn write_getter_with_node<I: Debug + Ord>(
             edge_repr = EdgeRepresentation::Option;
         }
 
-        let return_type = edge_repr.get_return_type_rust(&source_type, format!("&'a {end}<NK>"), schema_name);
+        let return_type = edge_repr.get_return_type_rust(&source_type, node_ref_type(end, groups), schema_name);
 
         let rename_attribute = nodes.get(node).and_then(|n| {
             n.attributes
@@ -821,11 +867,12 @@ fn write_getter_with_node<I: Debug + Ord>(
 }
 
 /// Create getter functions with a fixed edge type
-fn write_getter_with_edge<I: Debug + Ord>(
+fn write_getter_with_edge<I: Debug + Ord + Hash>(
     s: &mut String,
     dir: Direction,
     schema_name: &str,
     edge_impl: &mut HashSet<(String, Direction)>,
+    groups: &HashSet<&Ident<I>>,
     endpoints: &Vec<(&EndPoint<I>, &EdgeExp<I>)>,
     grouped_by_edge: &BTreeMap<String, IndexSet<&Ident<I>>>,
 ) -> GenResult<()> {
@@ -849,14 +896,14 @@ fn write_getter_with_edge<I: Debug + Ord>(
             ))),
 
             // Use specific type
-            [n] => Ok((format!("&'a {n}<NK>"), true)),
+            [n] => Ok((node_ref_type(n, groups), true)),
 
             // Use any of the specific types
             nodes if nodes.len() < 10 => {
                 let len = nodes.len();
                 let generics = nodes
                     .iter()
-                    .map(|n| format!("&'a {n}<NK>"))
+                    .map(|n| node_ref_type(n, groups))
                     .collect::<Vec<_>>()
                     .join(", ");
                 Ok((format!("Either{len}<{generics}>"), true))
@@ -911,11 +958,12 @@ fn write_getter_with_edge<I: Debug + Ord>(
 }
 
 /// Create getter functions with a fixed node and edge type
-fn write_getter_with_node_and_edge<I: Ord + Debug>(
+fn write_getter_with_node_and_edge<I: Ord + Debug + Hash>(
     s: &mut String,
     dir: Direction,
     schema_name: &str,
     endpoint_impl: &mut HashSet<(String, String, Direction)>,
+    groups: &HashSet<&Ident<I>>,
     endpoints: &Vec<(&EndPoint<I>, &EdgeExp<I>)>,
 ) -> GenResult<()> {
     for (endpoint, edge) in endpoints {
@@ -957,7 +1005,7 @@ fn write_getter_with_node_and_edge<I: Ord + Debug>(
             Direction::Backwards => &endpoint.incoming_quantity,
         };
         let edge_repr = EdgeRepresentation::from_quantity(quantity);
-        let return_type = edge_repr.get_return_type_rust(format!("&'a {edge_type}<EK>"), format!("&'a {target_type}<NK>"), schema_name);
+        let return_type = edge_repr.get_return_type_rust(format!("&'a {edge_type}<EK>"), node_ref_type(target_type, groups), schema_name);
 
         // Write get by edge type method
         writeln!(s, "")?;
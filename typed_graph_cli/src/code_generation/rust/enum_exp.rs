@@ -5,7 +5,10 @@ use std::fmt::Write;
 
 use crate::{targets, CodeGenerator, GenResult, GeneratedCode, ToRustType, ToSnakeCase};
 
-use super::{create_generics, write_comments, write_fields, FieldFormatter};
+use super::{
+    added_field_value, create_generics, fill_value, write_comments, write_field_defaults,
+    write_fields, FieldFormatter,
+};
 
 impl<I> CodeGenerator<targets::Rust> for EnumExp<I> {
     fn get_filename(&self) -> String {
@@ -143,6 +146,7 @@ impl<I> CodeGenerator<targets::Rust> for EnumExp<I> {
                     writeln!(s, "    {name} {{")?;
                     write_fields(
                         &mut s,
+                        &format!("{enum_name}{name}"),
                         fields,
                         FieldFormatter {
                             indents: 2,
@@ -226,6 +230,16 @@ impl<I> CodeGenerator<targets::Rust> for EnumExp<I> {
             }
         }
         writeln!(s, "}}")?;
+        for varient in &self.varients {
+            if let EnumVarient::Struct { name, fields, .. } = varient {
+                write_field_defaults(
+                    &mut s,
+                    &format!("{enum_name}{name}"),
+                    fields,
+                    &self.generics.generics,
+                )?;
+            }
+        }
         writeln!(s, "")?;
         writeln!(
             s,
@@ -321,6 +335,7 @@ pub(super) fn write_type_from<I: Clone + PartialEq + Ord + Default>(
     for varient in &t.varients {
         let field_path = FieldPath::new_path(t.name.clone(), vec![varient.name().clone()]);
         let changes = changeset.get_changes(field_path);
+        let old_name = changeset.get_old_varient_name(&t.name, varient.name());
 
         let is_new = changes
             .iter()
@@ -351,11 +366,22 @@ pub(super) fn write_type_from<I: Clone + PartialEq + Ord + Default>(
                         .collect();
 
                     // We then build up pattern in the match statement statement with all of the patterns
-                    writeln!(s, "           {parent_ty}::{name} {{")?;
+                    writeln!(s, "           {parent_ty}::{old_name} {{")?;
                     for field_value in fields.iter() {
                         let field_name = &field_value.name;
                         if persistent_fields.contains(field_name) {
-                            writeln!(s, "                {field_name},")?;
+                            let field_path = FieldPath::new_path(
+                                enum_name.clone(),
+                                vec![name.clone(), (*field_name).clone()],
+                            );
+                            let old_field_name = changeset
+                                .get_old_field_name(&field_path)
+                                .unwrap_or(field_name);
+                            if old_field_name != field_name {
+                                writeln!(s, "                {old_field_name}: {field_name},")?;
+                            } else {
+                                writeln!(s, "                {field_name},")?;
+                            }
                         }
                     }
                     writeln!(s, "                ..")?;
@@ -382,7 +408,23 @@ pub(super) fn write_type_from<I: Clone + PartialEq + Ord + Default>(
                                 })
                                 .next();
                             let mut need_manual_implementation = false;
-                            if let Some(type_change) = type_change {
+                            // Fields of the old varient are bound by name so only literals can be used as fill
+                            let fill = type_change.and_then(|type_change| {
+                                type_change
+                                    .fill()
+                                    .map(|fill| fill_value(fill, type_change.new_type(), None))
+                            });
+                            if let Some(fill) = fill {
+                                if let Some(value) = fill {
+                                    writeln!(s, "                {field_name}: {value},")?;
+                                } else {
+                                    omit_convertion = true;
+                                    writeln!(
+                                        s,
+                                        "                {field_name}: /* Insert convertion */,"
+                                    )?;
+                                }
+                            } else if let Some(type_change) = type_change {
                                 if !type_change
                                     .old_type()
                                     .is_gen_compatible(type_change.new_type())
@@ -409,7 +451,26 @@ pub(super) fn write_type_from<I: Clone + PartialEq + Ord + Default>(
                                 writeln!(s, "           {field_name}: {},", field_value.field_type.gen_convertion(format!("{field_name}"), true, &field_value.field_type))?;
                             }
                         } else {
-                            writeln!(s, "                {field_name}: Default::default(),")?;
+                            let field_path = FieldPath::new_path(
+                                enum_name.clone(),
+                                vec![name.clone(), (*field_name).clone()],
+                            );
+                            let added_field = changeset.get_changes(field_path).into_iter().find_map(|c| {
+                                if let SingleChange::AddedField(v) = c {
+                                    Some(v)
+                                } else {
+                                    None
+                                }
+                            });
+                            let value = added_field
+                                .map(|added_field| added_field_value(added_field, None))
+                                .unwrap_or_else(|| Some("Default::default()".to_string()));
+                            if let Some(value) = value {
+                                writeln!(s, "                {field_name}: {value},")?;
+                            } else {
+                                omit_convertion = true;
+                                writeln!(s, "                {field_name}: /* Insert convertion */,")?;
+                            }
                         }
                     }
                     writeln!(s, "           }}),")?;
@@ -436,13 +497,13 @@ pub(super) fn write_type_from<I: Clone + PartialEq + Ord + Default>(
                     if let Some(type_change) = type_change {
                         writeln!(
                             s,
-                            "            {parent_ty}::{name}(ty) => Ok({enum_name}::{name}({})),",
+                            "            {parent_ty}::{old_name}(ty) => Ok({enum_name}::{name}({})),",
                             type_change.old_type().gen_convertion("ty".to_string(), true, type_change.new_type())
                         )?;
                     } else {
                         writeln!(
                             s,
-                            "            {parent_ty}::{name}(ty) => Ok({enum_name}::{name}({})),",
+                            "            {parent_ty}::{old_name}(ty) => Ok({enum_name}::{name}({})),",
                             ty.gen_convertion("ty".to_string(), true, ty)
                         )?;
                     }
@@ -450,7 +511,7 @@ pub(super) fn write_type_from<I: Clone + PartialEq + Ord + Default>(
                 EnumVarient::Unit { name, .. } => {
                     writeln!(
                         s,
-                        "            {parent_ty}::{name} => Ok({enum_name}::{name}),"
+                        "            {parent_ty}::{old_name} => Ok({enum_name}::{name}),"
                     )?;
                 }
             }
@@ -1,8 +1,12 @@
 use std::fmt::Write;
 
+use build_changeset_lang::{
+    AddedField, ChangeSet, EditedField, FieldPath, FillExpression, SingleChange,
+};
 use build_script_lang::schema::{Fields, Visibility};
+use build_script_shared::parsers::{Generic, Ident, Literal, Types};
 
-use crate::{GenResult, ToRustType};
+use crate::{GenResult, ToRustType, ToRustValue, ToSnakeCase};
 
 use super::write_comments;
 
@@ -20,6 +24,7 @@ impl FieldFormatter {
 
 pub fn write_fields<I>(
     s: &mut impl Write,
+    owner: &str,
     fields: &Fields<I>,
     fmt: FieldFormatter,
 ) -> GenResult<()> {
@@ -33,8 +38,18 @@ pub fn write_fields<I>(
             writeln!(s, "{space}#[serde(skip)]")?;
         }
 
-        if field_value.attributes.is_default() {
-            writeln!(s, "{space}#[serde(default)]")?;
+        match &field_value.default {
+            Some(Literal::None { .. } | Literal::List { .. } | Literal::Map { .. }) => {
+                writeln!(s, "{space}#[serde(default)]")?;
+            }
+            Some(_) => {
+                let default_fn = default_fn_name(owner, field_name);
+                writeln!(s, "{space}#[serde(default = \"{default_fn}\")]")?;
+            }
+            None if field_value.attributes.is_default() => {
+                writeln!(s, "{space}#[serde(default)]")?;
+            }
+            None => (),
         }
 
         let alias_attributes = field_value.attributes.get_alias();
@@ -59,3 +74,154 @@ pub fn write_fields<I>(
 
     Ok(())
 }
+
+/// Write the functions used by serde to fill in fields with a default value
+///
+/// Empty values uses Default::default() so they do not need one
+pub fn write_field_defaults<I>(
+    s: &mut impl Write,
+    owner: &str,
+    fields: &Fields<I>,
+    generics: &[Generic<I>],
+) -> GenResult<()> {
+    for field_value in fields.iter() {
+        let default = match &field_value.default {
+            Some(Literal::None { .. } | Literal::List { .. } | Literal::Map { .. }) | None => {
+                continue
+            }
+            Some(default) => default,
+        };
+
+        // Only the generics used by the field can be inferred when serde calls the function
+        let used_generics: Vec<_> = generics
+            .iter()
+            .filter(|generic| uses_generic(&field_value.field_type, &generic.letter))
+            .map(|generic| generic.letter.to_string())
+            .collect();
+        let fn_generics = if used_generics.is_empty() {
+            "".to_string()
+        } else {
+            format!("<{}>", used_generics.join(", "))
+        };
+
+        let default_fn = default_fn_name(owner, &field_value.name);
+        let field_type = field_value.field_type.to_rust_type();
+        let value = default.to_rust_value(&field_value.field_type);
+
+        writeln!(s, "")?;
+        writeln!(s, "fn {default_fn}{fn_generics}() -> {field_type} {{")?;
+        writeln!(s, "    {value}")?;
+        writeln!(s, "}}")?;
+    }
+
+    Ok(())
+}
+
+/// The value a newly added field should be given when migrating
+///
+/// None is returned if the value has to be written manually
+pub fn added_field_value<I>(
+    added_field: &AddedField<I>,
+    old_fields: Option<&Fields<I>>,
+) -> Option<String> {
+    if let Some(fill) = added_field.fill() {
+        return fill_value(fill, added_field.field_type(), old_fields);
+    }
+
+    Some(
+        added_field
+            .default()
+            .map(|default| default.to_rust_value(added_field.field_type()))
+            .unwrap_or_else(|| "Default::default()".to_string()),
+    )
+}
+
+/// The value an edited field should be given when migrating
+///
+/// A fill expression takes the place of converting the old value
+pub fn edited_field_value<I>(
+    edited_field: &EditedField<I>,
+    old_field_name: &Ident<I>,
+    old_fields: Option<&Fields<I>>,
+) -> Option<String> {
+    if let Some(fill) = edited_field.fill() {
+        return fill_value(fill, edited_field.new_type(), old_fields);
+    }
+
+    if edited_field
+        .old_type()
+        .is_gen_compatible(edited_field.new_type())
+    {
+        Some(edited_field.old_type().gen_convertion(
+            format!("other.{old_field_name}"),
+            true,
+            edited_field.new_type(),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Create the expression for a fill
+///
+/// Old fields are only available if the fields of the previous version are known
+/// and they have been bound using write_fill_bindings
+pub fn fill_value<I>(
+    fill: &FillExpression<I>,
+    ty: &Types<I>,
+    old_fields: Option<&Fields<I>>,
+) -> Option<String> {
+    match fill {
+        FillExpression::Literal(literal) => Some(literal.to_rust_value(ty)),
+        FillExpression::OldField(name) => {
+            let old_type = &old_fields?.get_field(name.as_str())?.field_type;
+            if old_type.is_gen_compatible(ty) {
+                Some(old_type.gen_convertion(format!("fill_{name}"), true, ty))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Copy the old fields used by fills before any of them are moved into the new type
+pub fn write_fill_bindings<I: Clone + PartialEq>(
+    s: &mut impl Write,
+    changeset: &ChangeSet<I>,
+    type_name: &Ident<I>,
+) -> GenResult<()> {
+    let mut bound: Vec<&Ident<I>> = Vec::new();
+    for change in changeset.get_changes(FieldPath::new(type_name.clone())) {
+        let fill = match change {
+            SingleChange::AddedField(f) => f.fill(),
+            SingleChange::EditedFieldType(f) => f.fill(),
+            _ => None,
+        };
+
+        if let Some(FillExpression::OldField(name)) = fill {
+            if !bound.contains(&name) {
+                writeln!(s, "       let fill_{name} = other.{name}.clone();")?;
+                bound.push(name);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn uses_generic<I>(ty: &Types<I>, letter: &Ident<I>) -> bool {
+    match ty {
+        Types::Reference {
+            inner, generics, ..
+        } => inner == letter || generics.iter().any(|g| uses_generic(g, letter)),
+        Types::Option { inner, .. } | Types::List { inner, .. } | Types::Set { inner, .. } => {
+            uses_generic(inner, letter)
+        }
+        Types::Map { key, value, .. } => uses_generic(key, letter) || uses_generic(value, letter),
+        _ => false,
+    }
+}
+
+fn default_fn_name(owner: &str, field_name: impl std::fmt::Display) -> String {
+    format!("default_{}_{}", owner.to_snake_case(), field_name)
+}
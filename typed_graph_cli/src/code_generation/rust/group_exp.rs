@@ -0,0 +1,75 @@
+use build_script_lang::schema::Schema;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::{GenResult, GeneratedCode};
+
+use super::{write_comments, FieldFormatter};
+
+/// All groups of a schema are written to groups.rs as an enum holding a reference to one of the members
+///
+/// The enum is what is returned when traversing an edge ending in the group
+pub(super) fn write_groups_rs<I: Ord>(
+    schema: &Schema<I>,
+    new_files: &mut GeneratedCode,
+    schema_folder: &Path,
+) -> GenResult<()> {
+    let groups_path = schema_folder.join("groups.rs");
+    let mut s = String::new();
+
+    writeln!(s, "#[allow(unused_imports)]")?;
+    writeln!(s, "use super::*;")?;
+    writeln!(s, "#[allow(unused_imports)]")?;
+    writeln!(s, "use typed_graph::*;")?;
+
+    for group in schema.groups() {
+        let group_name = &group.name;
+
+        writeln!(s)?;
+        write_comments(
+            &mut s,
+            &group.comments,
+            FieldFormatter {
+                indents: 0,
+                include_visibility: true,
+            },
+        )?;
+        writeln!(s, "#[derive(Debug, Clone, Copy)]")?;
+        writeln!(s, "pub enum {group_name}<'a, NK> {{")?;
+        for member in &group.members {
+            writeln!(s, "    {member}(&'a {member}<NK>),")?;
+        }
+        writeln!(s, "}}")?;
+
+        writeln!(s)?;
+        writeln!(
+            s,
+            "impl<'b, NK, EK, S> Downcast<'b, NK, EK, {group_name}<'b, NK>, S> for Node<NK>"
+        )?;
+        writeln!(s, "where")?;
+        writeln!(s, "    NK: Key,")?;
+        writeln!(s, "    EK: Key,")?;
+        writeln!(s, "    S: SchemaExt<NK, EK, N = Node<NK>>")?;
+        writeln!(s, "{{")?;
+        writeln!(
+            s,
+            "    fn downcast<'a: 'b>(&'a self) -> SchemaResult<{group_name}<'a, NK>, NK, EK, S> {{"
+        )?;
+        writeln!(s, "        match self {{")?;
+        for member in &group.members {
+            writeln!(
+                s,
+                "            Node::{member}(n) => Ok({group_name}::{member}(n)),"
+            )?;
+        }
+        writeln!(s, "            #[allow(unreachable_patterns)]")?;
+        writeln!(s, "            n => Err(TypedError::DownCastFailed(\"{group_name}\".to_string(), n.get_type().to_string()))")?;
+        writeln!(s, "        }}")?;
+        writeln!(s, "    }}")?;
+        writeln!(s, "}}")?;
+    }
+
+    new_files.add_content(groups_path, s);
+
+    Ok(())
+}
@@ -1,20 +1,26 @@
+mod alias_exp;
 mod changeset;
 mod comments;
 mod edge_exp;
 mod enum_exp;
 mod fields;
 mod generics_util;
+mod group_exp;
+mod node_bases;
 mod node_exp;
 mod project;
 mod schema;
 mod struct_exp;
 mod to_rust_type;
 
+use alias_exp::*;
 use comments::*;
 use edge_exp::*;
 use enum_exp::*;
 use fields::*;
 use generics_util::*;
+use group_exp::*;
+use node_bases::*;
 use node_exp::*;
 use schema::*;
 use struct_exp::*;
@@ -0,0 +1,95 @@
+use build_script_lang::schema::Schema;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::{GenResult, GeneratedCode, ToRustType};
+
+use super::{write_comments, FieldFormatter};
+
+/// Every node which is inherited from is written to bases.rs as a trait
+/// with accessors for the fields it declares
+///
+/// The trait is implemented by the node itself and all of its concrete descendants
+pub(super) fn write_bases_rs<I: Ord + Clone>(
+    schema: &Schema<I>,
+    new_files: &mut GeneratedCode,
+    schema_folder: &Path,
+) -> GenResult<()> {
+    let bases_path = schema_folder.join("bases.rs");
+    let mut s = String::new();
+
+    writeln!(s, "#[allow(unused_imports)]")?;
+    writeln!(s, "use super::super::imports::*;")?;
+    writeln!(s, "#[allow(unused_imports)]")?;
+    writeln!(s, "use super::*;")?;
+    writeln!(s, "#[allow(unused_imports)]")?;
+    writeln!(s, "use indexmap::IndexMap;")?;
+    writeln!(s, "#[allow(unused_imports)]")?;
+    writeln!(s, "use std::collections::HashSet;")?;
+
+    for base in schema.nodes() {
+        let descendants: Vec<_> = schema.descendants(base).collect();
+        if !base.is_abstract && descendants.is_empty() {
+            continue;
+        }
+
+        let base_name = &base.name;
+        let supertrait = base
+            .parent
+            .as_ref()
+            .map_or_else(String::new, |parent| format!(": {parent}Fields"));
+
+        writeln!(s)?;
+        write_comments(
+            &mut s,
+            &base.comments,
+            FieldFormatter {
+                indents: 0,
+                include_visibility: true,
+            },
+        )?;
+        writeln!(s, "pub trait {base_name}Fields{supertrait} {{")?;
+        for field_value in base.fields.iter() {
+            let field_name = &field_value.name;
+            let field_type = field_value.field_type.to_rust_type();
+            writeln!(s, "    fn {field_name}(&self) -> &{field_type};")?;
+            writeln!(
+                s,
+                "    fn {field_name}_mut(&mut self) -> &mut {field_type};"
+            )?;
+        }
+        writeln!(s, "}}")?;
+
+        let implementors = Some(base)
+            .into_iter()
+            .chain(descendants)
+            .filter(|n| !n.is_abstract);
+        for node in implementors {
+            let node_name = &node.name;
+            writeln!(s)?;
+            writeln!(s, "impl<NK> {base_name}Fields for {node_name}<NK> {{")?;
+            for (i, field_value) in base.fields.iter().enumerate() {
+                let field_name = &field_value.name;
+                let field_type = field_value.field_type.to_rust_type();
+                if i != 0 {
+                    writeln!(s)?;
+                }
+                writeln!(s, "    fn {field_name}(&self) -> &{field_type} {{")?;
+                writeln!(s, "        &self.{field_name}")?;
+                writeln!(s, "    }}")?;
+                writeln!(s)?;
+                writeln!(
+                    s,
+                    "    fn {field_name}_mut(&mut self) -> &mut {field_type} {{"
+                )?;
+                writeln!(s, "        &mut self.{field_name}")?;
+                writeln!(s, "    }}")?;
+            }
+            writeln!(s, "}}")?;
+        }
+    }
+
+    new_files.add_content(bases_path, s);
+
+    Ok(())
+}
@@ -1,12 +1,15 @@
 use build_changeset_lang::{ChangeSet, FieldPath, SingleChange};
-use build_script_lang::schema::{NodeExp, Schema};
+use build_script_lang::schema::{Fields, NodeExp, Schema};
 use std::collections::HashSet;
 use std::fmt::Write;
 use std::path::Path;
 
 use crate::{targets, CodeGenerator, GenResult, GeneratedCode, ToRustType, ToSnakeCase};
 
-use super::{write_comments, write_fields, FieldFormatter};
+use super::{
+    added_field_value, edited_field_value, write_comments, write_field_defaults, write_fields,
+    write_fill_bindings, FieldFormatter,
+};
 
 impl<I> CodeGenerator<targets::Rust> for NodeExp<I> {
     fn get_filename(&self) -> String {
@@ -71,6 +74,7 @@ impl<I> CodeGenerator<targets::Rust> for NodeExp<I> {
         writeln!(s, "    pub(crate) id: NK,")?;
         write_fields(
             &mut s,
+            &node_type.to_string(),
             &self.fields,
             FieldFormatter {
                 indents: 1,
@@ -79,6 +83,12 @@ impl<I> CodeGenerator<targets::Rust> for NodeExp<I> {
         )?;
         writeln!(s, "")?;
         writeln!(s, "}}")?;
+        write_field_defaults(
+            &mut s,
+            &node_type.to_string(),
+            &self.fields,
+            &[],
+        )?;
 
         writeln!(s, "")?;
         writeln!(s, "#[allow(unused)]")?;
@@ -544,6 +554,7 @@ pub(super) fn write_node_type_rs<I: Ord>(
 pub(super) fn write_node_from<I: Clone + PartialEq>(
     n: &NodeExp<I>,
     changeset: &ChangeSet<I>,
+    old_fields: Option<&Fields<I>>,
     parent_ty: &String,
 ) -> GenResult<String> {
     let mut omit_convertion = false;
@@ -562,19 +573,31 @@ pub(super) fn write_node_from<I: Clone + PartialEq>(
         s,
         "    fn try_from(other: {parent_ty}<NK>) -> Result<Self, Self::Error> {{"
     )?;
+    write_fill_bindings(&mut s, changeset, &n.name)?;
     writeln!(s, "       Ok({node_type} {{")?;
     writeln!(s, "           id: other.id.into(),")?;
     for field_value in n.fields.iter() {
         let field_name = &field_value.name;
         let field_path = FieldPath::new_path(n.name.clone(), vec![field_name.clone()]);
         let changes = changeset.get_changes(field_path.clone());
-        let is_news = changes
-            .iter()
-            .any(|c| matches!(c, SingleChange::AddedField(_)));
-        if is_news {
-            writeln!(s, "           {field_name}: Default::default(),")?;
+        let old_field_name = changeset
+            .get_old_field_name(&field_path)
+            .unwrap_or(field_name);
+        let added_field = changes.iter().find_map(|c| {
+            if let SingleChange::AddedField(v) = c {
+                Some(v)
+            } else {
+                None
+            }
+        });
+        if let Some(added_field) = added_field {
+            if let Some(value) = added_field_value(added_field, old_fields) {
+                writeln!(s, "           {field_name}: {value},")?;
+            } else {
+                omit_convertion = true;
+                writeln!(s, "           {field_name}: /* Insert convertion */,")?;
+            }
         } else {
-            let mut need_manual_implementation = false;
             let type_change = changes
                 .iter()
                 .filter_map(|c| {
@@ -586,27 +609,15 @@ pub(super) fn write_node_from<I: Clone + PartialEq>(
                 })
                 .next();
             if let Some(type_change) = type_change {
-                if !type_change
-                    .old_type()
-                    .is_gen_compatible(type_change.new_type())
-                {
+                if let Some(value) = edited_field_value(type_change, old_field_name, old_fields) {
+                    writeln!(s, "           {field_name}: {value},")?;
+                } else {
                     // We cannot trust the auto generated conversion so a manual one should be made instead
                     omit_convertion = true;
-                    need_manual_implementation = true;
-                }
-                if need_manual_implementation {
                     writeln!(s, "           {field_name}: /* Insert convertion */,")?;
-                } else {
-                    writeln!(
-                        s,
-                        "           {field_name}: {},",
-                        type_change
-                            .old_type()
-                            .gen_convertion(format!("other.{field_name}"), true, type_change.new_type())
-                    )?;
                 }
             } else {
-                writeln!(s, "           {field_name}: {},", field_value.field_type.gen_convertion(format!("other.{field_name}"), true, &field_value.field_type))?;
+                writeln!(s, "           {field_name}: {},", field_value.field_type.gen_convertion(format!("other.{old_field_name}"), true, &field_value.field_type))?;
             }
         }
     }
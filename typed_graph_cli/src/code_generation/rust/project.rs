@@ -16,7 +16,7 @@ impl CodeGenerator<targets::Rust> for Project {
         p: P,
     ) -> crate::GenResult<GeneratedCode> {
         let output_folder = p.as_ref();
-        if !output_folder.exists() || !output_folder.is_dir() {
+        if output_folder.exists() && !output_folder.is_dir() {
             return Err(GenError::MissingFolder {
                 folder: output_folder
                     .to_str()
@@ -43,14 +43,26 @@ impl CodeGenerator<targets::Rust> for Project {
                 output_folder,
             )?;
             new_files.append(added_files);
+
+            // Also allow migrating back to the old version
+            let (inverted, _) = changeset.invert(old_schema)?;
+            let added_files = CodeGenerator::<targets::Rust>::aggregate_content(
+                &(&inverted, new_schema, old_schema, self),
+                output_folder,
+            )?;
+            new_files.append(added_files);
         }
 
-        let added_files = write_transitive_migrations(self, output_folder)?;
+        let added_files =
+            write_transitive_migrations(self, output_folder, Direction::Backwards)?;
+        new_files.append(added_files);
+        let added_files = write_transitive_migrations(self, output_folder, Direction::Forward)?;
         new_files.append(added_files);
 
         write_any_schema(&self, &mut new_files, output_folder)?;
         write_any_graph(&self, &mut new_files, output_folder)?;
         write_mod(self, &mut new_files, output_folder)?;
+        write_primitives(&mut new_files, output_folder)?;
 
         Ok(new_files)
     }
@@ -67,6 +79,7 @@ fn write_mod(
 
     writeln!(project_mod, "#[allow(unused)]")?;
     writeln!(project_mod, "mod imports;")?;
+    writeln!(project_mod, "mod primitives;")?;
     writeln!(project_mod, "")?;
 
     for schema in project.iter_schema() {
@@ -99,6 +112,37 @@ fn write_mod(
     Ok(())
 }
 
+/// Write ./primitives.rs
+///
+/// DateTime, Date and Uuid are only backed by chrono and uuid when the chrono and uuid features are enabled  
+/// Otherwise they are kept as strings with the same json representation
+fn write_primitives(new_files: &mut GeneratedCode, project_folder: &Path) -> GenResult<()> {
+    let primitives_path = project_folder.join("primitives.rs");
+
+    let mut s = String::new();
+    writeln!(s, "#[cfg(feature = \"chrono\")]")?;
+    writeln!(s, "pub type DateTime = chrono::DateTime<chrono::Utc>;")?;
+    writeln!(s, "#[cfg(not(feature = \"chrono\"))]")?;
+    writeln!(s, "pub type DateTime = String;")?;
+    writeln!(s, "")?;
+    writeln!(s, "#[cfg(feature = \"chrono\")]")?;
+    writeln!(s, "pub use chrono::NaiveDate as Date;")?;
+    writeln!(s, "#[cfg(not(feature = \"chrono\"))]")?;
+    writeln!(s, "pub type Date = String;")?;
+    writeln!(s, "")?;
+    writeln!(s, "#[cfg(feature = \"uuid\")]")?;
+    writeln!(s, "pub use uuid::Uuid;")?;
+    writeln!(s, "#[cfg(not(feature = \"uuid\"))]")?;
+    writeln!(s, "pub type Uuid = String;")?;
+    writeln!(s, "")?;
+    writeln!(s, "pub use std::time::Duration;")?;
+    writeln!(s, "pub type Bytes = Vec<u8>;")?;
+
+    new_files.add_content(primitives_path, s);
+
+    Ok(())
+}
+
 fn write_any_schema(
     project: &Project,
     new_files: &mut GeneratedCode,
@@ -323,14 +367,18 @@ fn write_any_graph(
     Ok(())
 }
 
+/// Chain the direct migrations together
+///
+/// Going backwards in the version tree creates the upgrades and going forward creates the downgrades
 fn write_transitive_migrations(
     project: &Project,
     project_folder: &Path,
+    dir: Direction,
 ) -> GenResult<GeneratedCode> {
     let mut targets = HashSet::new();
     let mut sources = HashSet::new();
     let mut versions: HashMap<&String, HashSet<&String>> = HashMap::new();
-    let version_iter = project.iter_version(Some(Direction::Backwards));
+    let version_iter = project.iter_version(Some(dir));
 
     for (source, target, _) in version_iter {
         targets.insert(target);
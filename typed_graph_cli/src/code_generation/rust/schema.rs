@@ -1,17 +1,18 @@
 use super::{
-    write_edge_endpoints, write_edge_type_rs, write_edges_rs, write_node_type_rs, write_nodes_rs,
+    write_aliases_rs, write_bases_rs, write_edge_endpoints, write_edge_type_rs, write_edges_rs,
+    write_groups_rs, write_node_type_rs, write_nodes_rs,
 };
 use crate::*;
+use build_changeset_lang::{ChangeSet, LossyChange};
 use build_script_lang::schema::{Schema, SchemaStm};
-use build_script_shared::parsers::Ident;
 use std::collections::HashSet;
 use std::fmt::{Debug, Write};
-use std::fs::create_dir;
+use std::hash::Hash;
 use std::path::Path;
 
 impl<I> CodeGenerator<targets::Rust> for (&Project, &Schema<I>)
 where
-    I: Ord + Debug,
+    I: Ord + Debug + Clone + Hash,
 {
     fn get_filename(&self) -> String {
         self.1.version.to_string().replace(".", "_").to_snake_case()
@@ -24,44 +25,30 @@ where
         let schema_folder = p
             .as_ref()
             .join(CodeGenerator::<targets::Rust>::get_filename(self));
-        if !schema_folder.exists() {
-            create_dir(&schema_folder)?;
-        }
-
         let nodes_folder = schema_folder.join("nodes");
         let structs_folder = schema_folder.join("structs");
         let edges_folder = schema_folder.join("edges");
         let types_folder = schema_folder.join("types");
 
-        if !nodes_folder.exists() {
-            create_dir(&nodes_folder)?;
-        }
-        if !structs_folder.exists() {
-            create_dir(&structs_folder)?;
-        }
-        if !edges_folder.exists() {
-            create_dir(&edges_folder)?;
-        }
-        if !types_folder.exists() {
-            create_dir(&types_folder)?;
-        }
+        // The nodes are generated with all of their inherited fields
+        let schema = &self.1.concrete_schema();
 
         let mut new_files = GeneratedCode::new();
 
         write_content(
-            &self.1,
+            schema,
             &mut new_files,
             &nodes_folder,
             &structs_folder,
             &edges_folder,
             &types_folder,
         )?;
-        write_nodes_rs(&self.1, &mut new_files, &schema_folder)?;
-        write_node_type_rs(&self.1, &mut new_files, &schema_folder)?;
-        write_edges_rs(&self.1, &mut new_files, &schema_folder)?;
-        write_edge_type_rs(&self.1, &mut new_files, &schema_folder)?;
+        write_nodes_rs(schema, &mut new_files, &schema_folder)?;
+        write_node_type_rs(schema, &mut new_files, &schema_folder)?;
+        write_edges_rs(schema, &mut new_files, &schema_folder)?;
+        write_edge_type_rs(schema, &mut new_files, &schema_folder)?;
         write_mod(
-            &self.1,
+            schema,
             &mut new_files,
             &schema_folder,
             &nodes_folder,
@@ -69,8 +56,11 @@ where
             &edges_folder,
             &types_folder,
         )?;
-        write_schema_impl_rs(&self.1, self.0, &mut new_files, &schema_folder)?;
-        write_edge_endpoints(&self.1, &mut new_files, &nodes_folder)?;
+        write_schema_impl_rs(schema, self.0, &mut new_files, &schema_folder)?;
+        write_edge_endpoints(schema, &mut new_files, &nodes_folder)?;
+        write_aliases_rs(self.1, &mut new_files, &schema_folder)?;
+        write_bases_rs(self.1, &mut new_files, &schema_folder)?;
+        write_groups_rs(schema, &mut new_files, &schema_folder)?;
 
         Ok(new_files)
     }
@@ -102,7 +92,9 @@ where
             SchemaStm::Enum(n) => {
                 CodeGenerator::<targets::Rust>::aggregate_content(n, &types_folder)
             }
-            SchemaStm::Import(_) => Ok(GeneratedCode::new()),
+            SchemaStm::Import(_) | SchemaStm::Alias(_) | SchemaStm::Group(_) => {
+                Ok(GeneratedCode::new())
+            }
         }?;
 
         new_files.append(added_files);
@@ -152,7 +144,7 @@ where
                 CodeGenerator::<targets::Rust>::get_filename(n),
                 &mut types_mod,
             ),
-            SchemaStm::Import(_) => continue,
+            SchemaStm::Import(_) | SchemaStm::Alias(_) | SchemaStm::Group(_) => continue,
         };
 
         writeln!(f, "mod {};", filename)?;
@@ -178,6 +170,9 @@ where
     writeln!(schema_mod, "mod node_type;")?;
     writeln!(schema_mod, "mod schema;")?;
     writeln!(schema_mod, "mod imports;")?;
+    writeln!(schema_mod, "mod aliases;")?;
+    writeln!(schema_mod, "mod bases;")?;
+    writeln!(schema_mod, "mod groups;")?;
     writeln!(schema_mod, "")?;
     writeln!(schema_mod, "#[allow(unused)]")?;
     writeln!(schema_mod, "pub use schema::*;")?;
@@ -200,7 +195,15 @@ where
     writeln!(schema_mod, "#[allow(unused)]")?;
     writeln!(schema_mod, "pub use imports::*;")?;
     writeln!(schema_mod, "#[allow(unused)]")?;
+    writeln!(schema_mod, "pub use aliases::*;")?;
+    writeln!(schema_mod, "#[allow(unused)]")?;
+    writeln!(schema_mod, "pub use bases::*;")?;
+    writeln!(schema_mod, "#[allow(unused)]")?;
+    writeln!(schema_mod, "pub use groups::*;")?;
+    writeln!(schema_mod, "#[allow(unused)]")?;
     writeln!(schema_mod, "pub use super::imports::*;")?;
+    writeln!(schema_mod, "#[allow(unused)]")?;
+    writeln!(schema_mod, "pub use super::primitives::*;")?;
 
     let imports_path = schema_folder.join("imports.rs");
     new_files.create_file(imports_path);
@@ -210,7 +213,7 @@ where
     Ok(())
 }
 
-fn write_schema_impl_rs<I: Ord>(
+fn write_schema_impl_rs<I: Ord + Clone>(
     schema: &Schema<I>,
     project: &Project,
     new_files: &mut GeneratedCode,
@@ -297,14 +300,16 @@ fn write_schema_impl_rs<I: Ord>(
         schema_rs,
         "        match (edge_ty, source_ty, target_ty) {{"
     )?;
-    for e in schema.edges() {
+    // The graph only ever sees the nodes in a group
+    let expanded = schema.expand_groups();
+    for e in expanded.edges() {
         let edge_type = &e.name;
         for ((source, target), endpoint) in &e.endpoints {
-            if let Some((_, upper)) = endpoint.incoming_quantity.bounds {
+            if let Some(upper) = endpoint.incoming_quantity.upper {
                 writeln!(schema_rs, "            (EdgeType::{edge_type}, NodeType::{source}, NodeType::{target}) if incoming_edge_count > {upper} => Err(DisAllowedEdge::ToManyIncoming),")?;
             }
 
-            if let Some((_, upper)) = endpoint.outgoing_quantity.bounds {
+            if let Some(upper) = endpoint.outgoing_quantity.upper {
                 writeln!(schema_rs, "            (EdgeType::{edge_type}, NodeType::{source}, NodeType::{target}) if outgoing_edge_count > {upper} => Err(DisAllowedEdge::ToManyOutgoing),")?;
             }
 
@@ -394,9 +399,11 @@ fn write_schema_impl_rs<I: Ord>(
 pub(super) fn write_migrate_schema<I: Ord>(
     old_schema: &Schema<I>,
     new_schema: &Schema<I>,
-    handler: &Option<Ident<I>>,
+    changeset: &ChangeSet<I>,
+    lossy_changes: &[LossyChange<I>],
     new_path: &String,
 ) -> GenResult<String> {
+    let handler = &changeset.handler;
     let old_schema_name = old_schema.version.replace(".", "_");
     let new_schema_name = new_schema.version.replace(".", "_");
     let new_schema_full_path = format!("{}::{}", new_path, new_schema_name);
@@ -405,9 +412,20 @@ pub(super) fn write_migrate_schema<I: Ord>(
         |handler| handler.to_string(),
     );
 
-    let new_types: HashSet<_> = new_schema.iter().map(SchemaStm::get_type).collect();
+    // Abstract nodes never hold any data so nothing migrates to them
+    let new_types: HashSet<_> = new_schema
+        .iter()
+        .filter(|stm| !matches!(stm, SchemaStm::Node(n) if n.is_abstract))
+        .map(SchemaStm::get_type)
+        .collect();
 
     let mut s = String::new();
+    if !lossy_changes.is_empty() {
+        writeln!(s, "/// Migrating to {new_schema_name} does not carry over all data:")?;
+        for lossy in lossy_changes {
+            writeln!(s, "/// - {}", lossy.reason)?;
+        }
+    }
     writeln!(s, "impl<NK, EK> MigrateSchema<NK, EK, {new_schema_full_path}<NK, EK>> for {old_schema_name}<NK, EK>")?;
     writeln!(s, "where")?;
     writeln!(s, "    NK: Key,")?;
@@ -415,11 +433,12 @@ pub(super) fn write_migrate_schema<I: Ord>(
     writeln!(s, "{{")?;
     writeln!(s, "    fn update_node(&self, _new_schema: &{new_schema_full_path}<NK, EK>, node: Self::N) -> SchemaResult<Option<<{new_schema_full_path}<NK, EK> as SchemaExt<NK, EK>>::N>, NK, EK, {new_schema_full_path}<NK, EK>> {{")?;
     writeln!(s, "        match node {{")?;
-    for n in old_schema.nodes() {
+    for n in old_schema.concrete_nodes() {
         let node_type = &n.name;
+        let new_node_type = changeset.get_new_type_name(&n.name);
 
-        if new_types.contains(&n.name) {
-            writeln!(s, "            Node::{node_type}(e) => Ok(Some({new_path}::Node::{node_type}(e.try_into().map_err(|e: UpgradeError| SchemaError::<NK, EK, {new_schema_full_path}<NK, EK>>::UpgradeError(e))?))),")?;
+        if new_types.contains(new_node_type) {
+            writeln!(s, "            Node::{node_type}(e) => Ok(Some({new_path}::Node::{new_node_type}(e.try_into().map_err(|e: UpgradeError| SchemaError::<NK, EK, {new_schema_full_path}<NK, EK>>::UpgradeError(e))?))),")?;
         } else {
             writeln!(s, "            Node::{node_type}(_) => Ok(None),")?;
         }
@@ -433,9 +452,10 @@ pub(super) fn write_migrate_schema<I: Ord>(
     writeln!(s, "        match edge {{")?;
     for e in old_schema.edges() {
         let edge_type = &e.name;
+        let new_edge_type = changeset.get_new_type_name(&e.name);
 
-        if new_types.contains(&e.name) {
-            writeln!(s, "            Edge::{edge_type}(e) => Ok(Some({new_path}::Edge::{edge_type}(e.try_into().map_err(|e: UpgradeError| SchemaError::<NK, EK, {new_schema_full_path}<NK, EK>>::UpgradeError(e))?))),")?;
+        if new_types.contains(new_edge_type) {
+            writeln!(s, "            Edge::{edge_type}(e) => Ok(Some({new_path}::Edge::{new_edge_type}(e.try_into().map_err(|e: UpgradeError| SchemaError::<NK, EK, {new_schema_full_path}<NK, EK>>::UpgradeError(e))?))),")?;
         } else {
             writeln!(s, "            Edge::{edge_type}(_) => Ok(None),")?;
         }
@@ -449,11 +469,12 @@ pub(super) fn write_migrate_schema<I: Ord>(
     writeln!(s, "        match edge_type {{")?;
     for n in old_schema.edges() {
         let node_type = &n.name;
+        let new_node_type = changeset.get_new_type_name(&n.name);
 
-        if new_types.contains(&n.name) {
+        if new_types.contains(new_node_type) {
             writeln!(
                 s,
-                "            EdgeType::{node_type} => Some({new_path}::EdgeType::{node_type}),"
+                "            EdgeType::{node_type} => Some({new_path}::EdgeType::{new_node_type}),"
             )?;
         } else {
             writeln!(s, "            EdgeType::{node_type} => None,")?;
@@ -466,13 +487,14 @@ pub(super) fn write_migrate_schema<I: Ord>(
     writeln!(s, "")?;
     writeln!(s, "     fn update_node_type(&self, _new_schema: &{new_schema_full_path}<NK, EK>, node_type: <Self::N as Typed>::Type) -> Option<<<{new_schema_full_path}<NK, EK> as SchemaExt<NK, EK>>::N as Typed>::Type> {{")?;
     writeln!(s, "        match node_type {{")?;
-    for n in old_schema.nodes() {
+    for n in old_schema.concrete_nodes() {
         let node_type = &n.name;
+        let new_node_type = changeset.get_new_type_name(&n.name);
 
-        if new_types.contains(&n.name) {
+        if new_types.contains(new_node_type) {
             writeln!(
                 s,
-                "            NodeType::{node_type} => Some({new_path}::NodeType::{node_type}),"
+                "            NodeType::{node_type} => Some({new_path}::NodeType::{new_node_type}),"
             )?;
         } else {
             writeln!(s, "            NodeType::{node_type} => None,")?;
@@ -1,10 +1,13 @@
 use build_changeset_lang::{ChangeSet, FieldPath, SingleChange};
-use build_script_lang::schema::StructExp;
+use build_script_lang::schema::{Fields, StructExp};
 use std::fmt::Write;
 
 use crate::{targets, CodeGenerator, GenResult, GeneratedCode, ToRustType, ToSnakeCase};
 
-use super::{create_generics, write_comments, write_fields, FieldFormatter};
+use super::{
+    added_field_value, create_generics, edited_field_value, write_comments, write_field_defaults,
+    write_fields, write_fill_bindings, FieldFormatter,
+};
 
 impl<I> CodeGenerator<targets::Rust> for StructExp<I> {
     fn get_filename(&self) -> String {
@@ -80,6 +83,7 @@ impl<I> CodeGenerator<targets::Rust> for StructExp<I> {
         writeln!(s, "pub struct {}{generics} {{", self.name)?;
         write_fields(
             &mut s,
+            &self.name.to_string(),
             &self.fields,
             FieldFormatter {
                 indents: 1,
@@ -87,6 +91,12 @@ impl<I> CodeGenerator<targets::Rust> for StructExp<I> {
             },
         )?;
         writeln!(s, "}}")?;
+        write_field_defaults(
+            &mut s,
+            &self.name.to_string(),
+            &self.fields,
+            &self.generics.generics,
+        )?;
 
         writeln!(s, "")?;
         writeln!(s, "#[allow(unused)]")?;
@@ -118,6 +128,7 @@ impl<I> CodeGenerator<targets::Rust> for StructExp<I> {
 pub(super) fn write_struct_from<I: Clone + PartialEq + Ord + Default>(
     n: &StructExp<I>,
     changeset: &ChangeSet<I>,
+    old_fields: Option<&Fields<I>>,
     parent_ty: &String,
 ) -> GenResult<String> {
     
@@ -144,81 +155,56 @@ pub(super) fn write_struct_from<I: Clone + PartialEq + Ord + Default>(
         s,
         "    fn try_from(other: {parent_ty}{old_type_generics}) -> Result<Self, Self::Error> {{"
     )?;
+    write_fill_bindings(&mut s, changeset, &n.name)?;
     writeln!(s, "       Ok({struct_type} {{")?;
     for field_value in n.fields.iter() {
         let field_name = &field_value.name;
         let field_path = FieldPath::new_path(n.name.clone(), vec![field_name.clone()]);
         let changes = changeset.get_changes(field_path.clone());
+        let old_field_name = changeset
+            .get_old_field_name(&field_path)
+            .unwrap_or(field_name);
 
-        let is_news = changes
-            .iter()
-            .any(|c| matches!(c, SingleChange::AddedField(_)));
-        if is_news {
-            writeln!(s, "           {field_name}: Default::default(),")?;
+        let added_field = changes.iter().find_map(|c| {
+            if let SingleChange::AddedField(v) = c {
+                Some(v)
+            } else {
+                None
+            }
+        });
+        if let Some(added_field) = added_field {
+            if let Some(value) = added_field_value(added_field, old_fields) {
+                writeln!(s, "           {field_name}: {value},")?;
+            } else {
+                omit_convertion = true;
+                writeln!(s, "           {field_name}: /* Insert convertion */,")?;
+            }
         } else {
-            let changed_type = changes
+            let type_change = changes
                 .iter()
-                .find(|c| matches!(c, SingleChange::EditedFieldType(_)));
-            if let Some(SingleChange::EditedFieldType(changed_type)) = changed_type {
-                let mut need_manual_implementation = false;
-                if !changed_type
-                    .old_type()
-                    .is_gen_compatible(changed_type.new_type())
-                {
+                .filter_map(|c| {
+                    if let SingleChange::EditedFieldType(v) = c {
+                        Some(v)
+                    } else {
+                        None
+                    }
+                })
+                .next();
+            if let Some(type_change) = type_change {
+                if let Some(value) = edited_field_value(type_change, old_field_name, old_fields) {
+                    writeln!(s, "           {field_name}: {value},")?;
+                } else {
                     // We cannot trust the auto generated conversion so a manual one should be made instead
                     omit_convertion = true;
-                    need_manual_implementation = true;
-                }
-                if need_manual_implementation {
                     writeln!(s, "           {field_name}: /* Insert convertion */,")?;
-                } else {
-                    writeln!(
-                        s,
-                        "           {field_name}: {},",
-                        changed_type.old_type()
-                            .gen_convertion(format!("other.{field_name}"), true, changed_type.new_type())
-                    )?;
                 }
             } else {
-                let mut need_manual_implementation = false;
-                let type_change = changes
-                    .iter()
-                    .filter_map(|c| {
-                        if let SingleChange::EditedFieldType(v) = c {
-                            Some(v)
-                        } else {
-                            None
-                        }
-                    })
-                    .next();
-                if let Some(type_change) = type_change {
-                    if !type_change
-                        .old_type()
-                        .is_gen_compatible(type_change.new_type())
-                    {
-                        // We cannot trust the auto generated conversion so a manual one should be made instead
-                        omit_convertion = true;
-                        need_manual_implementation = true;
-                    }
-                    if need_manual_implementation {
-                        writeln!(s, "           {field_name}: /* Insert convertion */,")?;
-                    } else {
-                        writeln!(
-                            s,
-                            "           {field_name}: {},",
-                            type_change
-                                .old_type()
-                                .gen_convertion(format!("other.{field_name}"), true, type_change.new_type())
-                        )?;
-                    } 
-                } else {
-                    writeln!(
-                        s,
-                        "           {field_name}: {},",
-                        field_value.field_type
-                            .gen_convertion(format!("other.{field_name}"), true, &field_value.field_type)
-                    )?;
-                }
+                writeln!(
+                    s,
+                    "           {field_name}: {},",
+                    field_value.field_type
+                        .gen_convertion(format!("other.{old_field_name}"), true, &field_value.field_type)
+                )?;
             }
         }
     }
@@ -1,4 +1,4 @@
-use build_script_shared::parsers::Types;
+use build_script_shared::parsers::{Literal, Types};
 
 pub trait ToRustType<I> {
     fn to_rust_type(&self) -> String;
@@ -23,6 +23,14 @@ impl<I> ToRustType<I> for Types<I> {
             Types::I32(_) => self.to_string(),
             Types::I16(_) => self.to_string(),
             Types::I8(_) => self.to_string(),
+            Types::U128(_) => self.to_string(),
+            Types::I128(_) => self.to_string(),
+            Types::Char(_) => self.to_string(),
+            Types::DateTime(_) => self.to_string(),
+            Types::Date(_) => self.to_string(),
+            Types::Duration(_) => self.to_string(),
+            Types::Uuid(_) => self.to_string(),
+            Types::Bytes(_) => self.to_string(),
             Types::Reference {
                 inner, generics, ..
             } => {
@@ -64,7 +72,15 @@ impl<I> ToRustType<I> for Types<I> {
             | (Types::I64(_), Types::I64(_))
             | (Types::I32(_), Types::I32(_))
             | (Types::I16(_), Types::I16(_))
-            | (Types::I8(_), Types::I8(_)) => {
+            | (Types::I8(_), Types::I8(_))
+            | (Types::U128(_), Types::U128(_))
+            | (Types::I128(_), Types::I128(_))
+            | (Types::Char(_), Types::Char(_))
+            | (Types::DateTime(_), Types::DateTime(_))
+            | (Types::Date(_), Types::Date(_))
+            | (Types::Duration(_), Types::Duration(_))
+            | (Types::Uuid(_), Types::Uuid(_))
+            | (Types::Bytes(_), Types::Bytes(_)) => {
                 if root {
                     self_var
                 } else {
@@ -181,7 +197,27 @@ impl<I> ToRustType<I> for Types<I> {
             | (Types::U16(_), Types::I16(_))
             | (Types::U8(_), Types::I16(_))
             | (Types::I8(_), Types::I8(_))
-            | (Types::U8(_), Types::I8(_)) => true,
+            | (Types::U8(_), Types::I8(_))
+            | (Types::U64(_), Types::U128(_))
+            | (Types::U32(_), Types::U128(_))
+            | (Types::U16(_), Types::U128(_))
+            | (Types::U8(_), Types::U128(_))
+            | (Types::U128(_), Types::U128(_))
+            | (Types::I64(_), Types::I128(_))
+            | (Types::I32(_), Types::I128(_))
+            | (Types::I16(_), Types::I128(_))
+            | (Types::I8(_), Types::I128(_))
+            | (Types::I128(_), Types::I128(_))
+            | (Types::U64(_), Types::I128(_))
+            | (Types::U32(_), Types::I128(_))
+            | (Types::U16(_), Types::I128(_))
+            | (Types::U8(_), Types::I128(_))
+            | (Types::Char(_), Types::Char(_))
+            | (Types::DateTime(_), Types::DateTime(_))
+            | (Types::Date(_), Types::Date(_))
+            | (Types::Duration(_), Types::Duration(_))
+            | (Types::Uuid(_), Types::Uuid(_))
+            | (Types::Bytes(_), Types::Bytes(_)) => true,
             (Types::Reference { inner: inner1, .. }, Types::Reference { inner: inner2, .. }) if inner1 == inner2 => true,
             (Types::Option { inner: inner1, .. }, Types::Option { inner: inner2, .. }) if inner1.is_gen_compatible(&inner2) => true,
             (t, Types::Option { inner, .. }) if t.is_gen_compatible(inner) => true,
@@ -217,6 +253,14 @@ impl<I> ToRustType<I> for Types<I> {
             | (Types::I32(_), _)
             | (Types::I16(_), _)
             | (Types::I8(_), _)
+            | (Types::U128(_), _)
+            | (Types::I128(_), _)
+            | (Types::Char(_), _)
+            | (Types::DateTime(_), _)
+            | (Types::Date(_), _)
+            | (Types::Duration(_), _)
+            | (Types::Uuid(_), _)
+            | (Types::Bytes(_), _)
             | (Types::Reference { .. }, _)
             | (Types::Option { .. }, _)
             | (Types::List { .. }, _)
@@ -225,3 +269,72 @@ impl<I> ToRustType<I> for Types<I> {
         }
     }
 }
+
+pub trait ToRustValue<I> {
+    fn to_rust_value(&self, ty: &Types<I>) -> String;
+}
+
+impl<I> ToRustValue<I> for Literal<I> {
+    fn to_rust_value(&self, ty: &Types<I>) -> String {
+        match (self, ty) {
+            (Literal::None { .. } | Literal::List { .. } | Literal::Map { .. }, _) => {
+                "Default::default()".to_string()
+            }
+            (literal, Types::Option { inner, .. }) => {
+                format!("Some({})", literal.to_rust_value(inner))
+            }
+            (Literal::String { value, .. }, Types::Char(_)) => {
+                rust_char_literal(value.chars().next().unwrap_or_default())
+            }
+            (Literal::String { value, .. }, _) => {
+                format!("{}.to_string()", rust_str_literal(value))
+            }
+            // Floats has to be written with a decimal point
+            (Literal::Number { value, .. }, Types::F64(_) | Types::F32(_))
+                if !value.contains('.') =>
+            {
+                format!("{value}.0")
+            }
+            (Literal::Number { value, .. }, _) => value.clone(),
+            (Literal::Bool { value, .. }, _) => value.to_string(),
+            (Literal::Varient { name }, Types::Reference { inner, .. }) => {
+                format!("{inner}::{name}")
+            }
+            (Literal::Varient { name }, _) => name.to_string(),
+        }
+    }
+}
+
+/// Write a string as a rust string literal
+fn rust_str_literal(value: &str) -> String {
+    let mut s = String::from('"');
+    for c in value.chars() {
+        push_rust_escaped(&mut s, c, '"');
+    }
+    s.push('"');
+    s
+}
+
+/// Write a char as a rust char literal
+fn rust_char_literal(c: char) -> String {
+    let mut s = String::from('\'');
+    push_rust_escaped(&mut s, c, '\'');
+    s.push('\'');
+    s
+}
+
+fn push_rust_escaped(s: &mut String, c: char, quote: char) {
+    match c {
+        '\\' => s.push_str("\\\\"),
+        '\n' => s.push_str("\\n"),
+        '\r' => s.push_str("\\r"),
+        '\t' => s.push_str("\\t"),
+        '\0' => s.push_str("\\0"),
+        c if c == quote => {
+            s.push('\\');
+            s.push(c);
+        }
+        c if c.is_control() => s.push_str(&format!("\\u{{{:x}}}", c as u32)),
+        c => s.push(c),
+    }
+}
@@ -0,0 +1,374 @@
+use build_script_lang::schema::{EnumVarient, Fields, Quantifier, Schema, SchemaStm};
+use build_script_shared::parsers::Types;
+use fake::faker::lorem::en::Word;
+use fake::Fake;
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::Rng;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+use crate::data::*;
+use crate::{GenError, GenResult};
+
+/// Number of edges to create from each node when the endpoint has no upper bound
+const DEFAULT_MAX_EDGES: u32 = 3;
+/// Max number of elements in generated lists, sets and maps
+const MAX_COLLECTION_SIZE: usize = 3;
+
+/// Create a random graph following the schema
+///
+/// Node types are picked at random and edges are added such that the quantifiers of every endpoint are upheld where possible
+pub fn generate_graph<I: Clone + Ord, R: Rng + ?Sized>(
+    schema: &Schema<I>,
+    node_count: usize,
+    rng: &mut R,
+) -> GenResult<Value> {
+    // The data never refers to the aliases or groups themselves
+    // and only holds concrete nodes with all of their inherited fields
+    let schema = &schema.concrete_schema().expand_aliases().expand_groups();
+    let node_types: Vec<_> = schema.nodes().collect();
+    if node_types.is_empty() && node_count != 0 {
+        return Err(GenError::DataGenerationFailed(format!(
+            "{} does not have any node types",
+            schema.version
+        )));
+    }
+
+    let mut generator = Generator { schema, rng };
+
+    let mut nodes = Vec::new();
+    let mut node_ids: HashMap<String, Vec<usize>> = HashMap::new();
+    for id in 0..node_count {
+        let n = node_types[generator.rng.gen_range(0..node_types.len())];
+        let mut body = generator.generate_fields(&n.fields, &HashMap::new())?;
+        body.insert("id".to_string(), json!(id));
+
+        nodes.push(json!({ n.name.to_string(): body }));
+        node_ids.entry(n.name.to_string()).or_default().push(id);
+    }
+
+    let mut edges = Vec::new();
+    for e in schema.edges() {
+        for endpoint in e.endpoints.values() {
+            let empty = Vec::new();
+            let sources = node_ids.get(endpoint.source.as_str()).unwrap_or(&empty);
+            let targets = node_ids.get(endpoint.target.as_str()).unwrap_or(&empty);
+
+            let connections = generator.connect(
+                sources,
+                targets,
+                &endpoint.outgoing_quantity,
+                &endpoint.incoming_quantity,
+            );
+
+            for (source, target) in connections {
+                let mut body = generator.generate_fields(&e.fields, &HashMap::new())?;
+                body.insert("id".to_string(), json!(edges.len()));
+
+                edges.push(json!({
+                    "weight": { e.name.to_string(): body },
+                    "source": source,
+                    "target": target
+                }));
+            }
+        }
+    }
+
+    Ok(json!({
+        "schema": schema.version.to_string(),
+        "nodes": nodes,
+        "edges": edges
+    }))
+}
+
+struct Generator<'a, I, R: ?Sized> {
+    schema: &'a Schema<I>,
+    rng: &'a mut R,
+}
+
+impl<'a, I: Clone + Ord, R: Rng + ?Sized> Generator<'a, I, R> {
+    /// Pick which sources should be connected to which targets
+    fn connect(
+        &mut self,
+        sources: &[usize],
+        targets: &[usize],
+        outgoing: &Quantifier<I>,
+        incoming: &Quantifier<I>,
+    ) -> Vec<(usize, usize)> {
+        let outgoing_max = outgoing.upper.unwrap_or(u32::MAX) as usize;
+        let incoming_max = incoming.upper.unwrap_or(u32::MAX) as usize;
+
+        let mut outgoing_count = vec![0; sources.len()];
+        let mut incoming_count = vec![0; targets.len()];
+        let mut connections = Vec::new();
+
+        for (s, source) in sources.iter().enumerate() {
+            let lower = outgoing.lower;
+            let upper = outgoing
+                .upper
+                .unwrap_or_else(|| DEFAULT_MAX_EDGES.max(lower));
+            let count = self.rng.gen_range(lower.min(upper)..=upper) as usize;
+
+            let available = (0..targets.len()).filter(|t| incoming_count[*t] < incoming_max);
+            for t in available.choose_multiple(self.rng, count) {
+                outgoing_count[s] += 1;
+                incoming_count[t] += 1;
+                connections.push((*source, targets[t]));
+            }
+        }
+
+        // Targets which still need incoming edges takes them from any source with room for more
+        let incoming_min = incoming.lower as usize;
+        for (t, target) in targets.iter().enumerate() {
+            while incoming_count[t] < incoming_min {
+                let available: Vec<_> = (0..sources.len())
+                    .filter(|s| outgoing_count[*s] < outgoing_max)
+                    .collect();
+                let Some(s) = available.choose(self.rng) else {
+                    break;
+                };
+
+                outgoing_count[*s] += 1;
+                incoming_count[t] += 1;
+                connections.push((sources[*s], *target));
+            }
+        }
+
+        connections
+    }
+
+    fn generate_fields(
+        &mut self,
+        fields: &Fields<I>,
+        generics: &HashMap<String, Types<I>>,
+    ) -> GenResult<Map<String, Value>> {
+        let mut obj = Map::new();
+        for field in fields.iter() {
+            if field.attributes.is_skipped() {
+                continue;
+            }
+
+            let value = self.generate_value(&field.field_type, generics)?;
+            obj.insert(field.name.to_string(), value);
+        }
+
+        Ok(obj)
+    }
+
+    /// Days are kept below 29 so every month is valid
+    fn generate_date(&mut self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}",
+            self.rng.gen_range(1970..2100),
+            self.rng.gen_range(1..=12),
+            self.rng.gen_range(1..=28)
+        )
+    }
+
+    fn generate_value(
+        &mut self,
+        ty: &Types<I>,
+        generics: &HashMap<String, Types<I>>,
+    ) -> GenResult<Value> {
+        let value = match ty {
+            Types::String(_) => json!(Word().fake_with_rng::<String, R>(self.rng)),
+            Types::Bool(_) => json!(self.rng.gen::<bool>()),
+            Types::F64(_) => json!(self.rng.gen_range(-1000.0..1000.0f64)),
+            Types::F32(_) => json!(self.rng.gen_range(-1000.0..1000.0f32)),
+            Types::Usize(_) | Types::U64(_) => json!(self.rng.gen::<u64>()),
+            Types::U32(_) => json!(self.rng.gen::<u32>()),
+            Types::U16(_) => json!(self.rng.gen::<u16>()),
+            Types::U8(_) => json!(self.rng.gen::<u8>()),
+            Types::Isize(_) | Types::I64(_) => json!(self.rng.gen::<i64>()),
+            Types::I32(_) => json!(self.rng.gen::<i32>()),
+            Types::I16(_) => json!(self.rng.gen::<i16>()),
+            Types::I8(_) => json!(self.rng.gen::<i8>()),
+            // Json numbers outside 64 bits are not portable so 128 bit numbers stay within 64 bits
+            Types::U128(_) => json!(self.rng.gen::<u64>()),
+            Types::I128(_) => json!(self.rng.gen::<i64>()),
+            Types::Char(_) => json!(self.rng.gen_range('a'..='z').to_string()),
+            Types::DateTime(_) => json!(format!(
+                "{}T{:02}:{:02}:{:02}Z",
+                self.generate_date(),
+                self.rng.gen_range(0..24),
+                self.rng.gen_range(0..60),
+                self.rng.gen_range(0..60)
+            )),
+            Types::Date(_) => json!(self.generate_date()),
+            Types::Duration(_) => json!({
+                "secs": self.rng.gen_range(0..1_000_000u64),
+                "nanos": self.rng.gen_range(0..1_000_000_000u32)
+            }),
+            Types::Uuid(_) => {
+                // Random (version 4) uuid
+                let n = self.rng.gen::<u128>() & !(0xf << 76) & !(0x3 << 62) | (0x4 << 76) | (0x2 << 62);
+                let hex = format!("{n:032x}");
+                json!(format!(
+                    "{}-{}-{}-{}-{}",
+                    &hex[0..8],
+                    &hex[8..12],
+                    &hex[12..16],
+                    &hex[16..20],
+                    &hex[20..32]
+                ))
+            }
+            Types::Bytes(_) => {
+                let len = self.rng.gen_range(0..=MAX_COLLECTION_SIZE);
+                json!((0..len).map(|_| self.rng.gen::<u8>()).collect::<Vec<_>>())
+            }
+            Types::Option { inner, .. } => {
+                if self.rng.gen::<bool>() {
+                    self.generate_value(inner, generics)?
+                } else {
+                    Value::Null
+                }
+            }
+            Types::List { inner, .. } | Types::Set { inner, .. } => {
+                let len = self.rng.gen_range(0..=MAX_COLLECTION_SIZE);
+                let mut items: Vec<Value> = Vec::new();
+                for _ in 0..len {
+                    let item = self.generate_value(inner, generics)?;
+
+                    // Sets can not contain the same value twice
+                    if matches!(ty, Types::List { .. }) || !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+                Value::Array(items)
+            }
+            Types::Map { key, value, .. } => {
+                let len = self.rng.gen_range(0..=MAX_COLLECTION_SIZE);
+                let mut entries = Map::new();
+                for _ in 0..len {
+                    // Json only allows string keys so other keys are stored as strings
+                    let key = match self.generate_value(key, generics)? {
+                        Value::String(key) => key,
+                        key => key.to_string(),
+                    };
+                    let value = self.generate_value(value, generics)?;
+                    entries.insert(key, value);
+                }
+                Value::Object(entries)
+            }
+            Types::Reference {
+                inner,
+                generics: type_generics,
+                ..
+            } => {
+                // Generics have already been replaced by the types they were given
+                if let Some(ty) = generics.get(inner.as_str()) {
+                    return self.generate_value(ty, &HashMap::new());
+                }
+
+                let schema = self.schema;
+                match schema.get_type(None, inner) {
+                    Some(SchemaStm::Struct(s)) => {
+                        let scope = create_scope(
+                            s.generics.generics.iter().map(|g| g.letter.to_string()),
+                            type_generics,
+                            generics,
+                        );
+                        Value::Object(self.generate_fields(&s.fields, &scope)?)
+                    }
+                    Some(SchemaStm::Enum(e)) => {
+                        let scope = create_scope(
+                            e.generics.generics.iter().map(|g| g.letter.to_string()),
+                            type_generics,
+                            generics,
+                        );
+
+                        let varient = e
+                            .varients
+                            .iter()
+                            .filter(|varient| !varient.attributes().is_skipped())
+                            .choose(self.rng)
+                            .ok_or_else(|| {
+                                GenError::DataGenerationFailed(format!(
+                                    "{} does not have any varients which can be stored",
+                                    e.name
+                                ))
+                            })?;
+
+                        let is_untagged =
+                            e.attributes.is_untagged() || varient.attributes().is_untagged();
+                        let name = varient.name().to_string();
+                        let body = match varient {
+                            EnumVarient::Unit { .. } if is_untagged => Value::Null,
+                            EnumVarient::Unit { .. } => return Ok(json!(name)),
+                            EnumVarient::Struct { fields, .. } => {
+                                Value::Object(self.generate_fields(fields, &scope)?)
+                            }
+                            EnumVarient::Opaque { ty, .. } => self.generate_value(ty, &scope)?,
+                        };
+
+                        if is_untagged {
+                            body
+                        } else {
+                            json!({ name: body })
+                        }
+                    }
+                    // Imported types are defined outside the schema so we do not know what they look like
+                    _ => Value::Null,
+                }
+            }
+        };
+
+        Ok(value)
+    }
+}
+
+#[test]
+fn generate_graph_test() -> GenResult<()> {
+    use build_script_shared::parsers::ParserDeserialize;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let schema = "
+    <V0>
+    node Student {
+        @json(skip)
+        secret: String,
+        score: u8,
+        grades: Map<u8, List<Grade>>,
+        state: Option<State>,
+        pair: Pair<i16>
+    };
+    node Teacher {
+        name: String
+    };
+    edge Teaches(Teacher => Student[1..2]) {
+        room: Option<u32>
+    };
+    edge Knows(Student[1..3] => Student, Teacher[0..1] => Teacher[0..1]) {};
+    struct Pair<T> {
+        left: T,
+        right: Option<T>
+    };
+    enum Grade {
+        A,
+        B
+    };
+    enum State {
+        Idle,
+        Away(u8),
+        Busy {
+            reason: String
+        }
+    };";
+
+    let (_, schema) = Schema::parse(schema).unwrap();
+
+    for seed in 0..20 {
+        let graph = generate_graph(&schema, 10, &mut StdRng::seed_from_u64(seed))?;
+        assert_eq!(
+            validate_graph(&graph, &schema),
+            vec![],
+            "seed {seed}: {graph:#}"
+        );
+
+        let same_graph = generate_graph(&schema, 10, &mut StdRng::seed_from_u64(seed))?;
+        assert_eq!(graph, same_graph);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,428 @@
+use build_script_lang::schema::{EnumVarient, Fields, Schema, SchemaStm};
+use build_script_shared::parsers::Types;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+use crate::{DroppedData, GenError, GenResult};
+
+/// What should happen to a value once it has been visited
+pub(crate) enum Visit {
+    Keep,
+    /// Take the value out of the graph for the given reason
+    Remove(String),
+}
+
+/// Walks a serialized graph and visits every value belonging to a type in the schema
+///
+/// Nodes and edges are visited as the object holding their fields.
+/// Removed values are taken out of the closest option, list or map.
+/// If there is none the whole node or edge is removed along with all edges connected to it
+pub(crate) struct GraphWalker<'a, I> {
+    schema: &'a Schema<I>,
+    version: String,
+    pub dropped: Vec<DroppedData>,
+}
+
+impl<'a, I> GraphWalker<'a, I>
+where
+    I: Clone,
+{
+    pub fn new(schema: &'a Schema<I>, version: String) -> Self {
+        GraphWalker {
+            schema,
+            version,
+            dropped: Vec::new(),
+        }
+    }
+
+    pub fn walk<F>(&mut self, graph: &mut Value, f: &mut F) -> GenResult<()>
+    where
+        F: FnMut(&SchemaStm<I>, &mut Value, &str) -> GenResult<Visit>,
+    {
+        let mut removed_nodes = HashSet::new();
+        let nodes = get_list(graph, "nodes")?;
+        for (i, mut node) in std::mem::take(nodes).into_iter().enumerate() {
+            let pointer = format!("/nodes/{i}");
+            match self.walk_node(&mut node, &pointer, f)? {
+                Some(reason) => {
+                    if let Some(id) = get_node_id(&node) {
+                        removed_nodes.insert(id.to_string());
+                    }
+                    self.drop_value(pointer, reason, node);
+                }
+                None => nodes.push(node),
+            }
+        }
+
+        let edges = get_list(graph, "edges")?;
+        for (i, mut edge) in std::mem::take(edges).into_iter().enumerate() {
+            let pointer = format!("/edges/{i}");
+            let removed_endpoint = ["source", "target"].into_iter().find(|endpoint| {
+                edge.get(endpoint)
+                    .is_some_and(|id| removed_nodes.contains(&id.to_string()))
+            });
+
+            if let Some(endpoint) = removed_endpoint {
+                self.drop_value(pointer, format!("its {endpoint} node was removed"), edge);
+                continue;
+            }
+
+            match self.walk_edge(&mut edge, &pointer, f)? {
+                Some(reason) => self.drop_value(pointer, reason, edge),
+                None => edges.push(edge),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn walk_node<F>(
+        &mut self,
+        node: &mut Value,
+        pointer: &str,
+        f: &mut F,
+    ) -> GenResult<Option<String>>
+    where
+        F: FnMut(&SchemaStm<I>, &mut Value, &str) -> GenResult<Visit>,
+    {
+        let (name, body) = get_tagged(node, pointer)?;
+        let pointer = format!("{pointer}/{}", escape_pointer(&name));
+        let schema = self.schema;
+        let stm = match schema.get_type(None, &name) {
+            Some(stm @ SchemaStm::Node(n)) => {
+                if let Some(reason) =
+                    self.walk_fields(&n.fields, body, &pointer, &HashMap::new(), f)?
+                {
+                    return Ok(Some(reason));
+                }
+                stm
+            }
+            _ => {
+                return Err(GenError::DataMigrationFailed(format!(
+                    "{pointer} is not a node type"
+                )))
+            }
+        };
+
+        self.visit(stm, body, &pointer, f)
+    }
+
+    fn walk_edge<F>(
+        &mut self,
+        edge: &mut Value,
+        pointer: &str,
+        f: &mut F,
+    ) -> GenResult<Option<String>>
+    where
+        F: FnMut(&SchemaStm<I>, &mut Value, &str) -> GenResult<Visit>,
+    {
+        let weight_pointer = format!("{pointer}/weight");
+        let weight = edge
+            .get_mut("weight")
+            .ok_or_else(|| GenError::DataMigrationFailed(format!("{pointer} has no weight")))?;
+
+        let (name, body) = get_tagged(weight, &weight_pointer)?;
+        let pointer = format!("{weight_pointer}/{}", escape_pointer(&name));
+        let schema = self.schema;
+        let stm = match schema.get_type(None, &name) {
+            Some(stm @ SchemaStm::Edge(e)) => {
+                if let Some(reason) =
+                    self.walk_fields(&e.fields, body, &pointer, &HashMap::new(), f)?
+                {
+                    return Ok(Some(reason));
+                }
+                stm
+            }
+            _ => {
+                return Err(GenError::DataMigrationFailed(format!(
+                    "{pointer} is not an edge type"
+                )))
+            }
+        };
+
+        self.visit(stm, body, &pointer, f)
+    }
+
+    fn walk_fields<F>(
+        &mut self,
+        fields: &Fields<I>,
+        value: &mut Value,
+        pointer: &str,
+        generics: &HashMap<String, Types<I>>,
+        f: &mut F,
+    ) -> GenResult<Option<String>>
+    where
+        F: FnMut(&SchemaStm<I>, &mut Value, &str) -> GenResult<Visit>,
+    {
+        for field in fields.iter() {
+            let field_pointer = format!("{pointer}/{}", field.name);
+            if let Some(field_value) = value.get_mut(field.name.as_str()) {
+                let reason =
+                    self.walk_value(&field.field_type, field_value, &field_pointer, generics, f)?;
+                if reason.is_some() {
+                    return Ok(reason);
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn walk_value<F>(
+        &mut self,
+        ty: &Types<I>,
+        value: &mut Value,
+        pointer: &str,
+        generics: &HashMap<String, Types<I>>,
+        f: &mut F,
+    ) -> GenResult<Option<String>>
+    where
+        F: FnMut(&SchemaStm<I>, &mut Value, &str) -> GenResult<Visit>,
+    {
+        match ty {
+            Types::Option { inner, .. } => {
+                if value.is_null() {
+                    return Ok(None);
+                }
+
+                if let Some(reason) = self.walk_value(inner, value, pointer, generics, f)? {
+                    let old_value = std::mem::take(value);
+                    self.drop_value(pointer.to_string(), reason, old_value);
+                }
+            }
+            Types::List { inner, .. } | Types::Set { inner, .. } => {
+                let Some(items) = value.as_array_mut() else {
+                    return Ok(None);
+                };
+
+                for (i, mut item) in std::mem::take(items).into_iter().enumerate() {
+                    let item_pointer = format!("{pointer}/{i}");
+                    match self.walk_value(inner, &mut item, &item_pointer, generics, f)? {
+                        Some(reason) => self.drop_value(item_pointer, reason, item),
+                        None => items.push(item),
+                    }
+                }
+            }
+            Types::Map { value: inner, .. } => {
+                let Some(entries) = value.as_object_mut() else {
+                    return Ok(None);
+                };
+
+                let keys: Vec<String> = entries.keys().cloned().collect();
+                for key in keys {
+                    let entry_pointer = format!("{pointer}/{}", escape_pointer(&key));
+                    let entry = entries.get_mut(&key).unwrap();
+                    if let Some(reason) =
+                        self.walk_value(inner, entry, &entry_pointer, generics, f)?
+                    {
+                        let old_entry = entries.remove(&key).unwrap();
+                        self.drop_value(entry_pointer, reason, old_entry);
+                    }
+                }
+            }
+            Types::Reference {
+                inner,
+                generics: type_generics,
+                ..
+            } => {
+                // Generics have already been replaced by the types they were given
+                if let Some(ty) = generics.get(inner.as_str()) {
+                    return self.walk_value(ty, value, pointer, &HashMap::new(), f);
+                }
+
+                let schema = self.schema;
+                let Some(stm) = schema.get_type(None, inner) else {
+                    return Ok(None);
+                };
+
+                let reason = match stm {
+                    SchemaStm::Struct(s) => {
+                        let scope = create_scope(
+                            s.generics.generics.iter().map(|g| g.letter.to_string()),
+                            type_generics,
+                            generics,
+                        );
+                        self.walk_fields(&s.fields, value, pointer, &scope, f)?
+                    }
+                    SchemaStm::Enum(e) => {
+                        let scope = create_scope(
+                            e.generics.generics.iter().map(|g| g.letter.to_string()),
+                            type_generics,
+                            generics,
+                        );
+
+                        let varient = get_varient_name(value).and_then(|name| {
+                            e.varients.iter().find(|varient| varient.name() == &name)
+                        });
+
+                        match varient {
+                            Some(varient) if !e.attributes.is_untagged() => {
+                                self.walk_varient(varient, value, pointer, &scope, f)?
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => return Ok(None),
+                };
+
+                if reason.is_some() {
+                    return Ok(reason);
+                }
+
+                return self.visit(stm, value, pointer, f);
+            }
+            _ => (),
+        }
+
+        Ok(None)
+    }
+
+    fn walk_varient<F>(
+        &mut self,
+        varient: &EnumVarient<I>,
+        value: &mut Value,
+        pointer: &str,
+        generics: &HashMap<String, Types<I>>,
+        f: &mut F,
+    ) -> GenResult<Option<String>>
+    where
+        F: FnMut(&SchemaStm<I>, &mut Value, &str) -> GenResult<Visit>,
+    {
+        let name = varient.name().as_str();
+        let pointer = format!("{pointer}/{name}");
+        let Some(inner) = value.get_mut(name) else {
+            return Ok(None);
+        };
+
+        match varient {
+            EnumVarient::Struct { fields, .. } => {
+                self.walk_fields(fields, inner, &pointer, generics, f)
+            }
+            EnumVarient::Opaque { ty, .. } => self.walk_value(ty, inner, &pointer, generics, f),
+            EnumVarient::Unit { .. } => Ok(None),
+        }
+    }
+
+    fn visit<F>(
+        &mut self,
+        stm: &SchemaStm<I>,
+        value: &mut Value,
+        pointer: &str,
+        f: &mut F,
+    ) -> GenResult<Option<String>>
+    where
+        F: FnMut(&SchemaStm<I>, &mut Value, &str) -> GenResult<Visit>,
+    {
+        match f(stm, value, pointer)? {
+            Visit::Keep => Ok(None),
+            Visit::Remove(reason) => Ok(Some(reason)),
+        }
+    }
+
+    fn drop_value(&mut self, pointer: String, reason: String, value: Value) {
+        self.dropped.push(DroppedData {
+            version: self.version.clone(),
+            pointer,
+            reason,
+            value,
+        });
+    }
+}
+
+/// Retrieve a list of the graph
+pub(crate) fn get_list<'v>(graph: &'v mut Value, name: &str) -> GenResult<&'v mut Vec<Value>> {
+    graph
+        .get_mut(name)
+        .and_then(Value::as_array_mut)
+        .ok_or_else(|| {
+            GenError::DataMigrationFailed(format!("expected a list of {name} in the graph"))
+        })
+}
+
+/// Retrieve the id of a node on the form {"NodeType": {"id": ...}}
+pub(crate) fn get_node_id(node: &Value) -> Option<&Value> {
+    node.as_object()?.values().next()?.get("id")
+}
+
+/// Split a value on the form {"Type": body} into its name and body
+pub(crate) fn get_tagged<'v>(
+    value: &'v mut Value,
+    pointer: &str,
+) -> GenResult<(String, &'v mut Value)> {
+    match value.as_object_mut() {
+        Some(obj) if obj.len() == 1 => {
+            let (name, body) = obj.iter_mut().next().unwrap();
+            Ok((name.clone(), body))
+        }
+        _ => Err(GenError::DataMigrationFailed(format!(
+            "expected {pointer} to be an object with a single type"
+        ))),
+    }
+}
+
+/// Retrieve the name of the varient used by an enum value
+///
+/// Unit varients are stored as "Varient" and all others as {"Varient": ...}
+pub(crate) fn get_varient_name(value: &Value) -> Option<String> {
+    match value {
+        Value::String(name) => Some(name.clone()),
+        Value::Object(obj) if obj.len() == 1 => obj.keys().next().cloned(),
+        _ => None,
+    }
+}
+
+/// Escape a key so it can be used in a json pointer
+pub(crate) fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Pair the generics of a type with the types they are given
+pub(crate) fn create_scope<I: Clone>(
+    letters: impl Iterator<Item = String>,
+    types: &[Box<Types<I>>],
+    generics: &HashMap<String, Types<I>>,
+) -> HashMap<String, Types<I>> {
+    letters
+        .zip(types)
+        .map(|(letter, ty)| (letter, resolve_generics(ty, generics)))
+        .collect()
+}
+
+/// Replace all generics in the type with the types they were given
+fn resolve_generics<I: Clone>(ty: &Types<I>, generics: &HashMap<String, Types<I>>) -> Types<I> {
+    match ty {
+        Types::Reference {
+            inner,
+            generics: type_generics,
+            marker,
+        } => match generics.get(inner.as_str()) {
+            Some(generic) if type_generics.is_empty() => generic.clone(),
+            _ => Types::Reference {
+                inner: inner.clone(),
+                generics: type_generics
+                    .iter()
+                    .map(|g| Box::new(resolve_generics(g, generics)))
+                    .collect(),
+                marker: marker.clone(),
+            },
+        },
+        Types::Option { inner, marker } => Types::Option {
+            inner: Box::new(resolve_generics(inner, generics)),
+            marker: marker.clone(),
+        },
+        Types::List { inner, marker } => Types::List {
+            inner: Box::new(resolve_generics(inner, generics)),
+            marker: marker.clone(),
+        },
+        Types::Set { inner, marker } => Types::Set {
+            inner: Box::new(resolve_generics(inner, generics)),
+            marker: marker.clone(),
+        },
+        Types::Map { key, value, marker } => Types::Map {
+            key: Box::new(resolve_generics(key, generics)),
+            value: Box::new(resolve_generics(value, generics)),
+            marker: marker.clone(),
+        },
+        ty => ty.clone(),
+    }
+}
@@ -0,0 +1,617 @@
+use build_changeset_lang::{ChangeSet, FieldPath, FillExpression, SingleChange};
+use build_script_lang::schema::{Schema, SchemaStm, SchemaStmType};
+use build_script_shared::parsers::{Ident, Literal, Types};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+use crate::data::*;
+use crate::{GenError, GenResult};
+
+/// Prefix of the fields copied for fills, matching how fills refer to them
+const OLD_FIELD_PREFIX: &str = "old.";
+
+/// Data which could not be carried over while migrating a graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct DroppedData {
+    /// Version the graph was being migrated to
+    pub version: String,
+    /// Json pointer to where the data was before it was dropped
+    pub pointer: String,
+    pub reason: String,
+    pub value: Value,
+}
+
+impl Display for DroppedData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: dropped {} as {}",
+            self.version, self.pointer, self.reason
+        )
+    }
+}
+
+/// Migrate a serialized graph across a single changeset
+///
+/// The graph is expected to be on the form {"nodes": [...], "edges": [...]}
+/// Data which could not be migrated is removed from the graph and returned
+pub fn migrate_graph<I>(
+    graph: &mut Value,
+    changeset: &ChangeSet<I>,
+    old_schema: &Schema<I>,
+) -> GenResult<Vec<DroppedData>>
+where
+    I: Hash + Clone + Default + PartialEq + Debug + Ord,
+{
+    let version = changeset.new_version.to_string();
+    let mut dropped = Vec::new();
+
+    // The data never refers to the aliases or groups themselves and holds the inherited fields on every node
+    // so the changes are made to the types they stand for and propagated to the children
+    let mut new_schema = old_schema.clone();
+    for change in &changeset.changes {
+        change.apply(&mut new_schema)?;
+    }
+    let changeset = &changeset.resolve_inheritance(old_schema, &new_schema)?;
+    let new_resolved = &new_schema.resolve_inheritance();
+    let old_resolved = &old_schema.resolve_inheritance();
+    let changeset = &changeset.expand_groups(old_resolved, new_resolved)?;
+    let new_schema = &new_resolved.expand_groups().expand_aliases();
+    let old_schema = &old_resolved.expand_groups().expand_aliases();
+    let changeset = &changeset.expand_aliases(old_schema, new_schema);
+
+    // Fills refer to the fields of the previous version
+    // so they have to be copied before any of the changes are applied
+    bind_fills(graph, changeset, old_schema)?;
+
+    let mut schema = old_schema.clone();
+    for change in &changeset.changes {
+        dropped.extend(migrate_change(graph, change, &schema, &version)?);
+        change.apply(&mut schema)?;
+    }
+
+    let mut walker = GraphWalker::new(&schema, version.clone());
+    walker.walk(graph, &mut |stm, value, _| {
+        let fields = match stm {
+            SchemaStm::Enum(_) => value
+                .as_object_mut()
+                .and_then(|obj| obj.values_mut().next())
+                .and_then(Value::as_object_mut),
+            _ => value.as_object_mut(),
+        };
+
+        if let Some(fields) = fields {
+            fields.retain(|name, _| !name.starts_with(OLD_FIELD_PREFIX));
+        }
+
+        Ok(Visit::Keep)
+    })?;
+
+    if let Some(schema_name @ Value::String(_)) = graph.get_mut("schema") {
+        *schema_name = Value::String(version);
+    }
+
+    Ok(dropped)
+}
+
+/// Apply a single change to the graph
+///
+/// The schema is the one from before the change is applied
+fn migrate_change<I>(
+    graph: &mut Value,
+    change: &SingleChange<I>,
+    schema: &Schema<I>,
+    version: &str,
+) -> GenResult<Vec<DroppedData>>
+where
+    I: Clone + PartialEq,
+{
+    let mut dropped = Vec::new();
+    let mut walker = GraphWalker::new(schema, version.to_string());
+
+    match change {
+        SingleChange::RemovedType(t)
+            if matches!(t.type_type, SchemaStmType::Node | SchemaStmType::Edge) =>
+        {
+            let reason = format!("{} was removed", t.type_name);
+            walker.walk(graph, &mut |stm, _, _| {
+                if stm.get_type() == &t.type_name {
+                    Ok(Visit::Remove(reason.clone()))
+                } else {
+                    Ok(Visit::Keep)
+                }
+            })?;
+        }
+        SingleChange::RenamedType(t) => match t.type_type {
+            SchemaStmType::Node => {
+                for node in get_list(graph, "nodes")? {
+                    rename_key(node, &t.old_name, &t.new_name);
+                }
+            }
+            SchemaStmType::Edge => {
+                for edge in get_list(graph, "edges")? {
+                    if let Some(weight) = edge.get_mut("weight") {
+                        rename_key(weight, &t.old_name, &t.new_name);
+                    }
+                }
+            }
+            _ => (),
+        },
+        SingleChange::RemovedEndpoint(e) => {
+            let node_types: HashMap<String, String> = get_list(graph, "nodes")?
+                .iter()
+                .filter_map(|node| {
+                    let id = get_node_id(node)?.to_string();
+                    let node_type = node.as_object()?.keys().next()?.clone();
+                    Some((id, node_type))
+                })
+                .collect();
+
+            let endpoint = e.endpoint();
+            let node_type = |edge: &Value, side: &str| {
+                edge.get(side)
+                    .and_then(|id| node_types.get(&id.to_string()))
+                    .cloned()
+            };
+
+            let edges = get_list(graph, "edges")?;
+            for (i, edge) in std::mem::take(edges).into_iter().enumerate() {
+                let edge_type = edge.get("weight").and_then(get_varient_name);
+                let is_removed = edge_type.is_some_and(|name| e.type_name() == &name)
+                    && node_type(&edge, "source").is_some_and(|name| endpoint.source == name)
+                    && node_type(&edge, "target").is_some_and(|name| endpoint.target == name);
+
+                if is_removed {
+                    dropped.push(DroppedData {
+                        version: version.to_string(),
+                        pointer: format!("/edges/{i}"),
+                        reason: format!(
+                            "{} going from {} to {} was removed",
+                            e.type_name(),
+                            endpoint.source,
+                            endpoint.target
+                        ),
+                        value: edge,
+                    });
+                } else {
+                    edges.push(edge);
+                }
+            }
+        }
+        SingleChange::AddedField(f) => {
+            let path = get_field_path(change)?;
+            let (field, varient) = split_field_path(&path)?;
+            walker.walk(graph, &mut |stm, value, pointer| {
+                if stm.get_type() != &path.root {
+                    return Ok(Visit::Keep);
+                }
+
+                let Some(fields) = get_fields(value, varient) else {
+                    return Ok(Visit::Keep);
+                };
+
+                let new_value = f
+                    .fill()
+                    .and_then(|fill| fill_value(fill, fields))
+                    .or_else(|| f.default().map(literal_value))
+                    .or_else(|| default_value(f.field_type(), schema))
+                    .ok_or_else(|| {
+                        GenError::DataMigrationFailed(format!(
+                            "no value could be found for {path} in {pointer}"
+                        ))
+                    })?;
+
+                fields.insert(field.to_string(), new_value);
+                Ok(Visit::Keep)
+            })?;
+        }
+        SingleChange::EditedFieldType(f) => {
+            let path = get_field_path(change)?;
+            let (field, varient) = split_field_path(&path)?;
+
+            let Some(fill) = f.fill() else {
+                // Allowed convertions keep the json representation of the old value
+                if f.old_type().check_convertion(f.new_type()) {
+                    return Ok(dropped);
+                }
+
+                walker.walk(graph, &mut |stm, value, pointer| {
+                    let has_value = stm.get_type() == &path.root
+                        && get_fields(value, varient)
+                            .is_some_and(|fields| fields.contains_key(field));
+
+                    if has_value {
+                        return Err(GenError::DataMigrationFailed(format!(
+                            "{} can not be converted from {} to {}, add a fill to {path}",
+                            field_pointer(pointer, varient, field),
+                            f.old_type(),
+                            f.new_type()
+                        )));
+                    }
+
+                    Ok(Visit::Keep)
+                })?;
+
+                return Ok(dropped);
+            };
+            walker.walk(graph, &mut |stm, value, pointer| {
+                if stm.get_type() != &path.root {
+                    return Ok(Visit::Keep);
+                }
+
+                let Some(fields) = get_fields(value, varient) else {
+                    return Ok(Visit::Keep);
+                };
+
+                let Some(new_value) = fill_value(fill, fields) else {
+                    return Ok(Visit::Keep);
+                };
+
+                if let Some(old_value) = fields.insert(field.to_string(), new_value.clone()) {
+                    if old_value != new_value {
+                        dropped.push(DroppedData {
+                            version: version.to_string(),
+                            pointer: field_pointer(pointer, varient, field),
+                            reason: format!("{path} was replaced by its fill"),
+                            value: old_value,
+                        });
+                    }
+                }
+
+                Ok(Visit::Keep)
+            })?;
+        }
+        SingleChange::RemovedField(_) => {
+            let path = get_field_path(change)?;
+            let (field, varient) = split_field_path(&path)?;
+            walker.walk(graph, &mut |stm, value, pointer| {
+                if stm.get_type() != &path.root {
+                    return Ok(Visit::Keep);
+                }
+
+                let old_value = get_fields(value, varient).and_then(|fields| fields.remove(field));
+                if let Some(old_value) = old_value {
+                    dropped.push(DroppedData {
+                        version: version.to_string(),
+                        pointer: field_pointer(pointer, varient, field),
+                        reason: format!("{path} was removed"),
+                        value: old_value,
+                    });
+                }
+
+                Ok(Visit::Keep)
+            })?;
+        }
+        SingleChange::RenamedField(f) => {
+            let path = get_field_path(change)?;
+            let (field, varient) = split_field_path(&path)?;
+            walker.walk(graph, &mut |stm, value, _| {
+                if stm.get_type() == &path.root {
+                    if let Some(fields) = get_fields(value, varient) {
+                        if let Some(field_value) = fields.remove(field) {
+                            fields.insert(f.new_name().to_string(), field_value);
+                        }
+                    }
+                }
+
+                Ok(Visit::Keep)
+            })?;
+        }
+        SingleChange::RemovedVarient(v) => {
+            let reason = format!("{}.{} was removed", v.type_name, v.varient_name);
+            walker.walk(graph, &mut |stm, value, _| {
+                let is_removed = stm.get_type() == &v.type_name
+                    && get_varient_name(value).is_some_and(|name| v.varient_name == name);
+
+                if is_removed {
+                    Ok(Visit::Remove(reason.clone()))
+                } else {
+                    Ok(Visit::Keep)
+                }
+            })?;
+        }
+        SingleChange::RenamedVarient(v) => {
+            walker.walk(graph, &mut |stm, value, _| {
+                if stm.get_type() != &v.type_name {
+                    return Ok(Visit::Keep);
+                }
+
+                match value {
+                    Value::String(name) if v.old_name == *name => {
+                        *name = v.new_name.to_string();
+                    }
+                    value => rename_key(value, &v.old_name, &v.new_name),
+                }
+
+                Ok(Visit::Keep)
+            })?;
+        }
+        _ => (),
+    }
+
+    dropped.extend(walker.dropped);
+    Ok(dropped)
+}
+
+/// Copy the old fields used by fills so they are available after the changes are applied
+fn bind_fills<I>(
+    graph: &mut Value,
+    changeset: &ChangeSet<I>,
+    old_schema: &Schema<I>,
+) -> GenResult<()>
+where
+    I: Clone + PartialEq,
+{
+    let mut bindings = Vec::new();
+    for change in &changeset.changes {
+        let fill = match change {
+            SingleChange::AddedField(f) => f.fill(),
+            SingleChange::EditedFieldType(f) => f.fill(),
+            _ => None,
+        };
+
+        let (Some(FillExpression::OldField(name)), Some(path)) = (fill, change.get_target()) else {
+            continue;
+        };
+
+        // The changes may rename the type and varient before the field is filled
+        let type_name = changeset.get_old_type_name(&path.root).to_string();
+        let varient = match path.path.as_slice() {
+            [varient, _] => Some(
+                changeset
+                    .get_old_varient_name(&path.root, varient)
+                    .to_string(),
+            ),
+            _ => None,
+        };
+
+        bindings.push((type_name, varient, name.to_string()));
+    }
+
+    if bindings.is_empty() {
+        return Ok(());
+    }
+
+    let mut walker = GraphWalker::new(old_schema, changeset.old_version.to_string());
+    walker.walk(graph, &mut |stm, value, _| {
+        for (type_name, varient, name) in &bindings {
+            if stm.get_type() != type_name {
+                continue;
+            }
+
+            if let Some(fields) = get_fields(value, varient.as_deref()) {
+                if let Some(old_value) = fields.get(name).cloned() {
+                    fields.insert(format!("{OLD_FIELD_PREFIX}{name}"), old_value);
+                }
+            }
+        }
+
+        Ok(Visit::Keep)
+    })
+}
+
+/// The value a fill gives a field
+fn fill_value<I>(fill: &FillExpression<I>, fields: &Map<String, Value>) -> Option<Value> {
+    match fill {
+        FillExpression::Literal(literal) => Some(literal_value(literal)),
+        FillExpression::OldField(name) => fields
+            .get(&format!("{OLD_FIELD_PREFIX}{name}"))
+            .or_else(|| fields.get(name.as_str()))
+            .cloned(),
+    }
+}
+
+/// Convert a literal to its json representation
+fn literal_value<I>(literal: &Literal<I>) -> Value {
+    match literal {
+        Literal::Number { value, .. } => serde_json::from_str(value).unwrap_or(Value::Null),
+        Literal::String { value, .. } => Value::String(value.clone()),
+        Literal::Bool { value, .. } => Value::Bool(*value),
+        Literal::None { .. } => Value::Null,
+        Literal::List { .. } => Value::Array(Vec::new()),
+        Literal::Map { .. } => Value::Object(Map::new()),
+        Literal::Varient { name } => Value::String(name.to_string()),
+    }
+}
+
+/// The json representation of the default value of a type
+///
+/// Returns None if the type has no default value
+fn default_value<I>(ty: &Types<I>, schema: &Schema<I>) -> Option<Value> {
+    let value = match ty {
+        Types::String(_) => Value::String(String::new()),
+        Types::Bool(_) => Value::Bool(false),
+        Types::F64(_) | Types::F32(_) => Value::from(0.0),
+        Types::Usize(_)
+        | Types::U64(_)
+        | Types::U32(_)
+        | Types::U16(_)
+        | Types::U8(_)
+        | Types::Isize(_)
+        | Types::I64(_)
+        | Types::I32(_)
+        | Types::I16(_)
+        | Types::I8(_)
+        | Types::U128(_)
+        | Types::I128(_) => Value::from(0),
+        Types::Char(_) => Value::String("\0".to_string()),
+        Types::DateTime(_) => Value::String("1970-01-01T00:00:00Z".to_string()),
+        Types::Date(_) => Value::String("1970-01-01".to_string()),
+        Types::Duration(_) => json!({ "secs": 0, "nanos": 0 }),
+        Types::Uuid(_) => Value::String("00000000-0000-0000-0000-000000000000".to_string()),
+        Types::Bytes(_) => Value::Array(Vec::new()),
+        Types::Option { .. } => Value::Null,
+        Types::List { .. } | Types::Set { .. } => Value::Array(Vec::new()),
+        Types::Map { .. } => Value::Object(Map::new()),
+        Types::Reference { inner, .. } => {
+            match schema.get_type(Some(SchemaStmType::Struct), inner)? {
+                SchemaStm::Struct(s) => {
+                    let mut fields = Map::new();
+                    for field in s.fields.iter() {
+                        let field_value = field
+                            .default
+                            .as_ref()
+                            .map(literal_value)
+                            .or_else(|| default_value(&field.field_type, schema))?;
+                        fields.insert(field.name.to_string(), field_value);
+                    }
+                    Value::Object(fields)
+                }
+                _ => return None,
+            }
+        }
+    };
+
+    Some(value)
+}
+
+fn get_field_path<I: Clone>(change: &SingleChange<I>) -> GenResult<FieldPath<I>> {
+    change
+        .get_target()
+        .ok_or_else(|| GenError::DataMigrationFailed(format!("{change} has no target")))
+}
+
+/// Split a path on the form Type.field or Type.Varient.field into the field and varient
+fn split_field_path<I>(path: &FieldPath<I>) -> GenResult<(&str, Option<&str>)> {
+    match path.path.as_slice() {
+        [field] => Ok((field.as_str(), None)),
+        [varient, field] => Ok((field.as_str(), Some(varient.as_str()))),
+        _ => Err(GenError::DataMigrationFailed(format!(
+            "{path} is not a path to a field"
+        ))),
+    }
+}
+
+/// Retrieve the fields of a value or of the varient it is using
+fn get_fields<'v>(
+    value: &'v mut Value,
+    varient: Option<&str>,
+) -> Option<&'v mut Map<String, Value>> {
+    match varient {
+        Some(varient) => value.get_mut(varient)?.as_object_mut(),
+        None => value.as_object_mut(),
+    }
+}
+
+fn field_pointer(pointer: &str, varient: Option<&str>, field: &str) -> String {
+    match varient {
+        Some(varient) => format!("{pointer}/{varient}/{field}"),
+        None => format!("{pointer}/{field}"),
+    }
+}
+
+fn rename_key<I>(value: &mut Value, old_name: &Ident<I>, new_name: &Ident<I>) {
+    if let Some(obj) = value.as_object_mut() {
+        if let Some(inner) = obj.remove(old_name.as_str()) {
+            obj.insert(new_name.to_string(), inner);
+        }
+    }
+}
+
+#[test]
+fn migrate_graph_test() -> GenResult<()> {
+    use build_script_shared::parsers::ParserDeserialize;
+    use serde_json::json;
+
+    let old_schema = "
+    <V0>
+    node Student {
+        name: String,
+        score: i64,
+        state: Option<State>
+    };
+    node Teacher {
+        name: String
+    };
+    edge Teaches(Teacher => Student) {};
+    enum State {
+        Idle,
+        Busy,
+        Away(u8)
+    };";
+
+    let changeset = "
+    < V0(0x0000000000000000) => V1(0x0000000000000000), hash = 1 >
+    + Student.nickname: String(3) <- old.name;
+    - Student.name;
+    * Student.score: i64(1) => i32(1) <- 0;
+    ~ Teacher.name => full_name;
+    ~ enum State.Away => Gone;
+    - enum State.Busy;
+    ~ node Teacher => Mentor;";
+
+    let (_, old_schema) = Schema::parse(old_schema).unwrap();
+    let (_, changeset) = ChangeSet::parse(changeset).unwrap();
+
+    let mut graph = json!({
+        "schema": "V0",
+        "nodes": [
+            {"Student": {"id": 0, "name": "Alice", "score": 10, "state": "Busy"}},
+            {"Student": {"id": 1, "name": "Bob", "score": 20, "state": {"Away": 3}}},
+            {"Teacher": {"id": 2, "name": "Carol"}}
+        ],
+        "edges": [
+            {"weight": {"Teaches": {"id": 0}}, "source": 2, "target": 0}
+        ]
+    });
+
+    let dropped = migrate_graph(&mut graph, &changeset, &old_schema)?;
+
+    assert_eq!(
+        graph,
+        json!({
+            "schema": "V1",
+            "nodes": [
+                {"Student": {"id": 0, "nickname": "Alice", "score": 0, "state": null}},
+                {"Student": {"id": 1, "nickname": "Bob", "score": 0, "state": {"Gone": 3}}},
+                {"Mentor": {"id": 2, "full_name": "Carol"}}
+            ],
+            "edges": [
+                {"weight": {"Teaches": {"id": 0}}, "source": 2, "target": 0}
+            ]
+        })
+    );
+
+    let pointers: Vec<_> = dropped.iter().map(|d| d.pointer.as_str()).collect();
+    assert_eq!(
+        pointers,
+        vec![
+            "/nodes/0/Student/name",
+            "/nodes/1/Student/name",
+            "/nodes/0/Student/score",
+            "/nodes/1/Student/score",
+            "/nodes/0/Student/state",
+        ]
+    );
+
+    // Inverting a filled change leaves a convertion without a fill
+    let changeset = "
+    < V0(0x0000000000000000) => V1(0x0000000000000000), hash = 1 >
+    * Student.name: String(0) => i64(0) <- 0;";
+
+    let (_, mut changeset) = ChangeSet::parse(changeset).unwrap();
+    changeset.old_hash = old_schema.get_hash();
+    let mut new_schema = old_schema.clone();
+    for change in &changeset.changes {
+        change.apply(&mut new_schema)?;
+    }
+    new_schema.version = changeset.new_version.clone();
+    changeset.new_hash = new_schema.get_hash();
+    let (inverted, _) = changeset.invert(&old_schema)?;
+    let mut graph = json!({
+        "schema": "V1",
+        "nodes": [
+            {"Student": {"id": 0, "name": 10, "score": 10, "state": null}}
+        ],
+        "edges": []
+    });
+
+    assert!(matches!(
+        migrate_graph(&mut graph, &inverted, &new_schema),
+        Err(GenError::DataMigrationFailed(msg)) if msg.contains("add a fill")
+    ));
+
+    Ok(())
+}
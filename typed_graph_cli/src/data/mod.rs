@@ -0,0 +1,9 @@
+mod generate_data;
+mod graph_walker;
+mod migrate_data;
+mod validate_data;
+
+pub use generate_data::*;
+pub(crate) use graph_walker::*;
+pub use migrate_data::*;
+pub use validate_data::*;
@@ -0,0 +1,820 @@
+use build_script_lang::schema::{EnumExp, EnumVarient, Fields, Quantifier, Schema, SchemaStm};
+use build_script_shared::parsers::Types;
+use indexmap::IndexMap;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::data::*;
+
+/// A place where a graph does not follow its schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataViolation {
+    /// Json pointer to the offending value
+    pub pointer: String,
+    pub reason: String,
+}
+
+impl Display for DataViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.pointer, self.reason)
+    }
+}
+
+/// Check that a serialized graph can be read using the given schema
+///
+/// The graph is expected to be on the form {"nodes": [...], "edges": [...]}
+/// Every violation is returned instead of stopping at the first one
+pub fn validate_graph<I: Clone + Ord>(graph: &Value, schema: &Schema<I>) -> Vec<DataViolation> {
+    // The data never refers to the aliases or groups themselves
+    // and only holds concrete nodes with all of their inherited fields
+    let schema = &schema.concrete_schema().expand_aliases().expand_groups();
+    let mut validator = Validator::new(schema);
+    validator.validate_graph(graph);
+    validator.violations
+}
+
+/// A node in the graph along with where it is stored
+struct NodeInfo {
+    node_type: String,
+    pointer: String,
+}
+
+struct Validator<'a, I> {
+    schema: &'a Schema<I>,
+    violations: Vec<DataViolation>,
+}
+
+impl<'a, I: Clone + Ord> Validator<'a, I> {
+    fn new(schema: &'a Schema<I>) -> Self {
+        Validator {
+            schema,
+            violations: Vec::new(),
+        }
+    }
+
+    fn violation(&mut self, pointer: impl Into<String>, reason: impl Into<String>) {
+        self.violations.push(DataViolation {
+            pointer: pointer.into(),
+            reason: reason.into(),
+        });
+    }
+
+    fn validate_graph(&mut self, graph: &Value) {
+        if let Some(name) = graph.get("schema").and_then(Value::as_str) {
+            if self.schema.version != name {
+                self.violation(
+                    "/schema",
+                    format!("the graph uses {name} and not {}", self.schema.version),
+                );
+            }
+        }
+
+        let nodes = self.validate_nodes(graph);
+        self.validate_edges(graph, &nodes);
+    }
+
+    fn validate_nodes(&mut self, graph: &Value) -> IndexMap<String, NodeInfo> {
+        let mut nodes: IndexMap<String, NodeInfo> = IndexMap::new();
+        let Some(node_values) = graph.get("nodes").and_then(Value::as_array) else {
+            self.violation("/nodes", "expected a list of nodes");
+            return nodes;
+        };
+
+        for (i, node) in node_values.iter().enumerate() {
+            let pointer = format!("/nodes/{i}");
+            let Some((name, body)) = self.get_tagged(node, &pointer) else {
+                continue;
+            };
+
+            let schema = self.schema;
+            let body_pointer = format!("{pointer}/{}", escape_pointer(name));
+            let Some(SchemaStm::Node(n)) = schema.get_type(None, name) else {
+                self.violation(pointer, format!("{name} is not a node type"));
+                continue;
+            };
+
+            self.validate_fields(&n.fields, body, &body_pointer, &HashMap::new());
+
+            let Some(id) = body.get("id") else {
+                self.violation(body_pointer, "the node is missing its id");
+                continue;
+            };
+
+            if let Some(other) = nodes.get(&id.to_string()) {
+                let reason = format!("the node has the same id as {}", other.pointer);
+                self.violation(format!("{body_pointer}/id"), reason);
+                continue;
+            }
+
+            nodes.insert(
+                id.to_string(),
+                NodeInfo {
+                    node_type: name.to_string(),
+                    pointer,
+                },
+            );
+        }
+
+        nodes
+    }
+
+    fn validate_edges(&mut self, graph: &Value, nodes: &IndexMap<String, NodeInfo>) {
+        let Some(edge_values) = graph.get("edges").and_then(Value::as_array) else {
+            self.violation("/edges", "expected a list of edges");
+            return;
+        };
+
+        // Number of edges of each type going out of and coming into each node
+        let mut outgoing: HashMap<(String, String, String), usize> = HashMap::new();
+        let mut incoming: HashMap<(String, String, String), usize> = HashMap::new();
+
+        for (i, edge) in edge_values.iter().enumerate() {
+            let pointer = format!("/edges/{i}");
+            let weight_pointer = format!("{pointer}/weight");
+            let Some(weight) = edge.get("weight") else {
+                self.violation(pointer, "the edge is missing its weight");
+                continue;
+            };
+
+            let Some((name, body)) = self.get_tagged(weight, &weight_pointer) else {
+                continue;
+            };
+
+            let schema = self.schema;
+            let body_pointer = format!("{weight_pointer}/{}", escape_pointer(name));
+            let Some(SchemaStm::Edge(e)) = schema.get_type(None, name) else {
+                self.violation(weight_pointer, format!("{name} is not an edge type"));
+                continue;
+            };
+
+            self.validate_fields(&e.fields, body, &body_pointer, &HashMap::new());
+
+            if body.get("id").is_none() {
+                self.violation(body_pointer, "the edge is missing its id");
+            }
+
+            let mut endpoints = Vec::new();
+            for side in ["source", "target"] {
+                match edge.get(side) {
+                    Some(id) => match nodes.get(&id.to_string()) {
+                        Some(node) => endpoints.push((id.to_string(), node)),
+                        None => self.violation(
+                            format!("{pointer}/{side}"),
+                            format!("{id} is not a node in the graph"),
+                        ),
+                    },
+                    None => {
+                        self.violation(pointer.clone(), format!("the edge is missing its {side}"))
+                    }
+                }
+            }
+
+            let [(source_id, source), (target_id, target)] = endpoints.as_slice() else {
+                continue;
+            };
+
+            let is_allowed = e.endpoints.values().any(|endpoint| {
+                endpoint.source == source.node_type && endpoint.target == target.node_type
+            });
+
+            if !is_allowed {
+                self.violation(
+                    pointer,
+                    format!(
+                        "{name} does not allow edges going from {} to {}",
+                        source.node_type, target.node_type
+                    ),
+                );
+                continue;
+            }
+
+            *outgoing
+                .entry((
+                    name.to_string(),
+                    source_id.clone(),
+                    target.node_type.clone(),
+                ))
+                .or_default() += 1;
+            *incoming
+                .entry((
+                    name.to_string(),
+                    target_id.clone(),
+                    source.node_type.clone(),
+                ))
+                .or_default() += 1;
+        }
+
+        // Quantifiers are checked for every node so lower bounds also apply to nodes without edges
+        let schema = self.schema;
+        for e in schema.edges() {
+            for endpoint in e.endpoints.values() {
+                for (id, node) in nodes {
+                    if endpoint.source == node.node_type {
+                        let key = (e.name.to_string(), id.clone(), endpoint.target.to_string());
+                        let count = outgoing.get(&key).copied().unwrap_or_default();
+                        self.validate_quantity(
+                            &node.pointer,
+                            count,
+                            &endpoint.outgoing_quantity,
+                            format!("outgoing {} edges going to {}", e.name, endpoint.target),
+                        );
+                    }
+
+                    if endpoint.target == node.node_type {
+                        let key = (e.name.to_string(), id.clone(), endpoint.source.to_string());
+                        let count = incoming.get(&key).copied().unwrap_or_default();
+                        self.validate_quantity(
+                            &node.pointer,
+                            count,
+                            &endpoint.incoming_quantity,
+                            format!("incoming {} edges coming from {}", e.name, endpoint.source),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_quantity(
+        &mut self,
+        pointer: &str,
+        count: usize,
+        quantity: &Quantifier<I>,
+        edges: String,
+    ) {
+        if let Some(upper) = quantity.upper {
+            if count > upper as usize {
+                self.violation(
+                    pointer,
+                    format!("the node has {count} {edges} but at most {upper} are allowed"),
+                );
+            }
+        }
+
+        let lower = quantity.lower;
+        if count < lower as usize {
+            self.violation(
+                pointer,
+                format!("the node has {count} {edges} but at least {lower} are required"),
+            );
+        }
+    }
+
+    fn validate_fields(
+        &mut self,
+        fields: &Fields<I>,
+        value: &Value,
+        pointer: &str,
+        generics: &HashMap<String, Types<I>>,
+    ) {
+        let Some(obj) = value.as_object() else {
+            self.violation(pointer, "expected an object");
+            return;
+        };
+
+        for field in fields.iter() {
+            let aliases = field.attributes.get_alias();
+            let names: Vec<&str> = std::iter::once(field.name.as_str())
+                .chain(aliases.iter().map(|alias| alias.as_str()))
+                .filter(|name| obj.contains_key(*name))
+                .collect();
+
+            if field.attributes.is_skipped() {
+                for name in names {
+                    self.violation(
+                        format!("{pointer}/{}", escape_pointer(name)),
+                        format!("{} is skipped and should not be stored", field.name),
+                    );
+                }
+                continue;
+            }
+
+            match names.as_slice() {
+                [] => {
+                    let is_optional = field.default.is_some()
+                        || field.attributes.is_default()
+                        || matches!(field.field_type, Types::Option { .. });
+
+                    if !is_optional {
+                        self.violation(pointer, format!("missing the field {}", field.name));
+                    }
+                }
+                [name] => {
+                    let field_pointer = format!("{pointer}/{}", escape_pointer(name));
+                    self.validate_value(&field.field_type, &obj[*name], &field_pointer, generics);
+                }
+                names => self.violation(
+                    pointer,
+                    format!(
+                        "{} is given multiple times as {}",
+                        field.name,
+                        names.join(", ")
+                    ),
+                ),
+            }
+        }
+    }
+
+    fn validate_value(
+        &mut self,
+        ty: &Types<I>,
+        value: &Value,
+        pointer: &str,
+        generics: &HashMap<String, Types<I>>,
+    ) {
+        let (min, max) = match ty {
+            Types::String(_) => {
+                if !value.is_string() {
+                    self.violation(pointer, format!("expected a string found {value}"));
+                }
+                return;
+            }
+            Types::Bool(_) => {
+                if !value.is_boolean() {
+                    self.violation(pointer, format!("expected a bool found {value}"));
+                }
+                return;
+            }
+            Types::F64(_) | Types::F32(_) => {
+                if !value.is_number() {
+                    self.violation(pointer, format!("expected a number found {value}"));
+                }
+                return;
+            }
+            Types::Usize(_) | Types::U64(_) => (0, u64::MAX as i128),
+            Types::U32(_) => (0, u32::MAX as i128),
+            Types::U16(_) => (0, u16::MAX as i128),
+            Types::U8(_) => (0, u8::MAX as i128),
+            Types::Isize(_) | Types::I64(_) => (i64::MIN as i128, i64::MAX as i128),
+            Types::I32(_) => (i32::MIN as i128, i32::MAX as i128),
+            Types::I16(_) => (i16::MIN as i128, i16::MAX as i128),
+            Types::I8(_) => (i8::MIN as i128, i8::MAX as i128),
+            Types::U128(_) | Types::I128(_) => {
+                // Numbers outside 64 bits are read as floats
+                let is_integer = value.is_i64()
+                    || value.is_u64()
+                    || value.as_f64().is_some_and(|number| number.fract() == 0.0);
+                let is_negative = value.as_f64().is_some_and(|number| number < 0.0);
+                if !is_integer || (matches!(ty, Types::U128(_)) && is_negative) {
+                    self.violation(pointer, format!("expected {ty} found {value}"));
+                }
+                return;
+            }
+            Types::Char(_) => {
+                if value.as_str().map(|s| s.chars().count()) != Some(1) {
+                    self.violation(pointer, format!("expected a single character found {value}"));
+                }
+                return;
+            }
+            Types::DateTime(_) | Types::Date(_) | Types::Uuid(_) => {
+                let is_valid = value.as_str().is_some_and(|s| match ty {
+                    Types::DateTime(_) => is_date_time(s),
+                    Types::Date(_) => is_date(s),
+                    _ => is_uuid(s),
+                });
+                if !is_valid {
+                    self.violation(pointer, format!("expected {ty} found {value}"));
+                }
+                return;
+            }
+            Types::Duration(_) => {
+                // Durations are stored the way serde stores std::time::Duration
+                let secs = value.get("secs").and_then(Value::as_u64);
+                let nanos = value.get("nanos").and_then(Value::as_u64);
+                let is_valid = value.as_object().is_some_and(|obj| obj.len() == 2)
+                    && secs.is_some()
+                    && nanos.is_some_and(|nanos| nanos < 1_000_000_000);
+                if !is_valid {
+                    self.violation(
+                        pointer,
+                        format!("expected a duration on the form {{\"secs\": .., \"nanos\": ..}} found {value}"),
+                    );
+                }
+                return;
+            }
+            Types::Bytes(_) => {
+                let is_valid = value.as_array().is_some_and(|items| {
+                    items
+                        .iter()
+                        .all(|item| item.as_u64().is_some_and(|byte| byte <= u8::MAX as u64))
+                });
+                if !is_valid {
+                    self.violation(pointer, format!("expected a list of bytes found {value}"));
+                }
+                return;
+            }
+            Types::Option { inner, .. } => {
+                if !value.is_null() {
+                    self.validate_value(inner, value, pointer, generics);
+                }
+                return;
+            }
+            Types::List { inner, .. } | Types::Set { inner, .. } => {
+                let Some(items) = value.as_array() else {
+                    self.violation(pointer, format!("expected a list found {value}"));
+                    return;
+                };
+
+                for (i, item) in items.iter().enumerate() {
+                    self.validate_value(inner, item, &format!("{pointer}/{i}"), generics);
+                }
+                return;
+            }
+            Types::Map {
+                key: key_type,
+                value: value_type,
+                ..
+            } => {
+                let Some(entries) = value.as_object() else {
+                    self.violation(pointer, format!("expected a map found {value}"));
+                    return;
+                };
+
+                for (key, entry) in entries {
+                    let entry_pointer = format!("{pointer}/{}", escape_pointer(key));
+
+                    // Json only allows string keys so other keys are stored as strings
+                    // Types stored as strings are used as keys without any quotes
+                    if matches!(
+                        **key_type,
+                        Types::Char(_) | Types::DateTime(_) | Types::Date(_) | Types::Uuid(_)
+                    ) {
+                        let key = Value::String(key.clone());
+                        self.validate_value(key_type, &key, &entry_pointer, generics);
+                    } else if !matches!(**key_type, Types::String(_)) {
+                        match serde_json::from_str(key) {
+                            Ok(key) => {
+                                self.validate_value(key_type, &key, &entry_pointer, generics)
+                            }
+                            Err(_) => self.violation(
+                                entry_pointer.clone(),
+                                format!("expected the key to be {key_type}"),
+                            ),
+                        }
+                    }
+
+                    self.validate_value(value_type, entry, &entry_pointer, generics);
+                }
+                return;
+            }
+            Types::Reference {
+                inner,
+                generics: type_generics,
+                ..
+            } => {
+                // Generics have already been replaced by the types they were given
+                if let Some(ty) = generics.get(inner.as_str()) {
+                    self.validate_value(ty, value, pointer, &HashMap::new());
+                    return;
+                }
+
+                let schema = self.schema;
+                match schema.get_type(None, inner) {
+                    Some(SchemaStm::Struct(s)) => {
+                        let scope = create_scope(
+                            s.generics.generics.iter().map(|g| g.letter.to_string()),
+                            type_generics,
+                            generics,
+                        );
+                        self.validate_fields(&s.fields, value, pointer, &scope);
+                    }
+                    Some(SchemaStm::Enum(e)) => {
+                        let scope = create_scope(
+                            e.generics.generics.iter().map(|g| g.letter.to_string()),
+                            type_generics,
+                            generics,
+                        );
+                        self.validate_enum(e, value, pointer, &scope);
+                    }
+                    // Imported types are defined outside the schema
+                    _ => (),
+                }
+                return;
+            }
+        };
+
+        let number = value
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| value.as_u64().map(i128::from));
+
+        match number {
+            Some(number) if number < min || number > max => {
+                self.violation(pointer, format!("{number} does not fit in {ty}"))
+            }
+            Some(_) => (),
+            None => self.violation(pointer, format!("expected {ty} found {value}")),
+        }
+    }
+
+    fn validate_enum(
+        &mut self,
+        e: &EnumExp<I>,
+        value: &Value,
+        pointer: &str,
+        generics: &HashMap<String, Types<I>>,
+    ) {
+        let tagged_varient = get_varient_name(value)
+            .filter(|_| !e.attributes.is_untagged())
+            .and_then(|name| {
+                e.varients.iter().find(|varient| {
+                    !varient.attributes().is_untagged()
+                        && (varient.name() == &name
+                            || varient
+                                .attributes()
+                                .get_alias()
+                                .iter()
+                                .any(|alias| **alias == name))
+                })
+            });
+
+        let Some(varient) = tagged_varient else {
+            // Untagged varients are stored without their name so they have to be tried one by one
+            let is_untagged_match = e
+                .varients
+                .iter()
+                .filter(|varient| e.attributes.is_untagged() || varient.attributes().is_untagged())
+                .any(|varient| self.matches_varient(varient, value, generics));
+
+            if !is_untagged_match {
+                self.violation(
+                    pointer,
+                    format!("expected a varient of {} found {value}", e.name),
+                );
+            }
+            return;
+        };
+
+        if varient.attributes().is_skipped() {
+            self.violation(
+                pointer,
+                format!(
+                    "{}.{} is skipped and can not be stored",
+                    e.name,
+                    varient.name()
+                ),
+            );
+            return;
+        }
+
+        let name = get_varient_name(value).unwrap_or_default();
+        let varient_pointer = format!("{pointer}/{}", escape_pointer(&name));
+        match (varient, value) {
+            (EnumVarient::Unit { .. }, Value::String(_)) => (),
+            (EnumVarient::Unit { .. }, _) => self.violation(
+                pointer,
+                format!(
+                    "{}.{} is a unit varient and should be stored as \"{name}\"",
+                    e.name,
+                    varient.name()
+                ),
+            ),
+            (EnumVarient::Struct { fields, .. }, Value::Object(obj)) => {
+                self.validate_fields(fields, &obj[&name], &varient_pointer, generics)
+            }
+            (EnumVarient::Opaque { ty, .. }, Value::Object(obj)) => {
+                self.validate_value(ty, &obj[&name], &varient_pointer, generics)
+            }
+            _ => self.violation(
+                pointer,
+                format!(
+                    "expected {}.{} to be stored as {{\"{name}\": ...}}",
+                    e.name,
+                    varient.name()
+                ),
+            ),
+        }
+    }
+
+    /// Check if the value is an untagged varient without reporting any violations
+    fn matches_varient(
+        &self,
+        varient: &EnumVarient<I>,
+        value: &Value,
+        generics: &HashMap<String, Types<I>>,
+    ) -> bool {
+        if varient.attributes().is_skipped() {
+            return false;
+        }
+
+        let mut validator = Validator::new(self.schema);
+        match varient {
+            EnumVarient::Unit { .. } => return value.is_null(),
+            EnumVarient::Struct { fields, .. } => {
+                validator.validate_fields(fields, value, "", generics)
+            }
+            EnumVarient::Opaque { ty, .. } => validator.validate_value(ty, value, "", generics),
+        }
+
+        validator.violations.is_empty()
+    }
+
+    /// Split a value on the form {"Type": body} into its name and body
+    fn get_tagged<'v>(
+        &mut self,
+        value: &'v Value,
+        pointer: &str,
+    ) -> Option<(&'v String, &'v Value)> {
+        match value.as_object() {
+            Some(obj) if obj.len() == 1 => obj.iter().next(),
+            _ => {
+                self.violation(pointer, "expected an object with a single type");
+                None
+            }
+        }
+    }
+}
+
+/// Check that every character is a digit and the number is within the range
+fn is_number_in(s: &str, min: u32, max: u32) -> bool {
+    !s.is_empty()
+        && s.chars().all(|c| c.is_ascii_digit())
+        && s.parse::<u32>().is_ok_and(|n| min <= n && n <= max)
+}
+
+/// Dates are stored as YYYY-MM-DD
+fn is_date(s: &str) -> bool {
+    let parts: Vec<_> = s.split('-').collect();
+    matches!(
+        parts.as_slice(),
+        [year, month, day] if year.len() == 4
+            && month.len() == 2
+            && day.len() == 2
+            && is_number_in(year, 0, 9999)
+            && is_number_in(month, 1, 12)
+            && is_number_in(day, 1, 31)
+    )
+}
+
+/// Dates with time are stored in RFC 3339 like 2024-01-31T12:00:00Z or 2024-01-31T12:00:00.5+02:00
+fn is_date_time(s: &str) -> bool {
+    let Some((date, time)) = s.split_once(['T', 't']) else {
+        return false;
+    };
+
+    let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, None)
+    } else if let Some(i) = time.rfind(['+', '-']) {
+        (&time[..i], Some(&time[i + 1..]))
+    } else {
+        return false;
+    };
+
+    let is_offset = offset.map_or(true, |offset| {
+        matches!(offset.split_once(':'), Some((h, m)) if h.len() == 2 && m.len() == 2 && is_number_in(h, 0, 23) && is_number_in(m, 0, 59))
+    });
+
+    let (time, fraction) = time.split_once('.').unwrap_or((time, "0"));
+    let parts: Vec<_> = time.split(':').collect();
+    let is_time = matches!(
+        parts.as_slice(),
+        [h, m, sec] if h.len() == 2
+            && m.len() == 2
+            && sec.len() == 2
+            && is_number_in(h, 0, 23)
+            && is_number_in(m, 0, 59)
+            && is_number_in(sec, 0, 60)
+    );
+
+    is_date(date)
+        && is_time
+        && is_offset
+        && !fraction.is_empty()
+        && fraction.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Uuids are stored as 8-4-4-4-12 hex digits
+fn is_uuid(s: &str) -> bool {
+    let lengths: Vec<_> = s.split('-').map(|part| part.len()).collect();
+    lengths == [8, 4, 4, 4, 12] && s.chars().all(|c| c == '-' || c.is_ascii_hexdigit())
+}
+
+#[test]
+fn validate_graph_test() {
+    use build_script_shared::parsers::ParserDeserialize;
+    use serde_json::json;
+
+    let schema = "
+    <V0>
+    node Student {
+        @json(alias = nickname)
+        name: String,
+        @json(skip)
+        secret: String,
+        score: u8,
+        state: Option<State>
+    };
+    node Teacher {
+        name: String
+    };
+    edge Teaches(Teacher => Student[1..2]) {};
+    enum State {
+        Idle,
+        Away(u8)
+    };";
+
+    let (_, schema) = Schema::parse(schema).unwrap();
+
+    let graph = json!({
+        "schema": "V0",
+        "nodes": [
+            {"Student": {"id": 0, "nickname": "Alice", "score": 10, "state": {"Away": 3}}},
+            {"Student": {"id": 1, "name": "Bob", "score": 300, "secret": "", "state": "Busy"}},
+            {"Teacher": {"id": 2, "name": "Carol"}},
+            {"Principal": {"id": 3}}
+        ],
+        "edges": [
+            {"weight": {"Teaches": {"id": 0}}, "source": 2, "target": 0},
+            {"weight": {"Teaches": {"id": 1}}, "source": 0, "target": 2},
+            {"weight": {"Teaches": {"id": 2}}, "source": 2, "target": 4}
+        ]
+    });
+
+    let violations: Vec<_> = validate_graph(&graph, &schema)
+        .into_iter()
+        .map(|v| v.pointer)
+        .collect();
+
+    assert_eq!(
+        violations,
+        vec![
+            "/nodes/1/Student/secret",
+            "/nodes/1/Student/score",
+            "/nodes/1/Student/state",
+            "/nodes/3",
+            "/edges/1",
+            "/edges/2/target",
+            "/nodes/1",
+        ]
+    );
+}
+
+#[test]
+fn validate_primitives_test() {
+    use build_script_shared::parsers::ParserDeserialize;
+    use serde_json::json;
+
+    let schema = "
+    <V0>
+    node Event {
+        at: DateTime,
+        day: Date,
+        length: Duration,
+        key: Uuid,
+        payload: Bytes,
+        counter: u128,
+        offset: i128,
+        grade: char
+    };";
+
+    let (_, schema) = Schema::parse(schema).unwrap();
+
+    let graph = json!({
+        "schema": "V0",
+        "nodes": [
+            {"Event": {
+                "id": 0,
+                "at": "2024-01-31T12:30:00.25+02:00",
+                "day": "2024-01-31",
+                "length": {"secs": 90, "nanos": 0},
+                "key": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                "payload": [104, 105],
+                "counter": 1e30,
+                "offset": -12,
+                "grade": "A"
+            }},
+            {"Event": {
+                "id": 1,
+                "at": "2024-01-31 12:30:00",
+                "day": "2024-13-01",
+                "length": 90,
+                "key": "67e55044",
+                "payload": [256],
+                "counter": -1,
+                "offset": 1.5,
+                "grade": "AB"
+            }}
+        ],
+        "edges": []
+    });
+
+    let violations: Vec<_> = validate_graph(&graph, &schema)
+        .into_iter()
+        .map(|v| v.pointer)
+        .collect();
+
+    assert_eq!(
+        violations,
+        vec![
+            "/nodes/1/Event/at",
+            "/nodes/1/Event/day",
+            "/nodes/1/Event/length",
+            "/nodes/1/Event/key",
+            "/nodes/1/Event/payload",
+            "/nodes/1/Event/counter",
+            "/nodes/1/Event/offset",
+            "/nodes/1/Event/grade",
+        ]
+    );
+}
@@ -36,6 +36,20 @@ pub enum GenError {
     UnknownSchema { name: String },
     #[error("Found no changeset called {name:#16x}")]
     UnknownChangeset { name: u64 },
+    #[error("{name} has other changesets depending on it")]
+    BranchingSchema { name: String },
+    #[error("Found no changeset from {old} to {new}")]
+    MissingChangeset { old: String, new: String },
+    #[error("Removing {target} would disconnect {} from the rest of the project, use --force to remove it anyway", orphans.join(", "))]
+    OrphanedSchemas { target: String, orphans: Vec<String> },
+    #[error("Squashing would leave {} without changesets, use --remove-schemas to remove them", schemas.join(", "))]
+    UnlinkedSchemas { schemas: Vec<String> },
+    #[error("{a} and {b} have no common ancestor")]
+    NoCommonAncestor { a: String, b: String },
+    #[error("Failed to merge due to conflicts\n{0}")]
+    MergeConflict(String),
+    #[error("Changes from {old} to {new} are breaking")]
+    BreakingChanges { old: String, new: String },
     #[error("Found no refence called {name}")]
     UnknownReference { name: String },
     #[error("Failed to find {kind} {missing_key} from version tree")]
@@ -46,6 +60,18 @@ pub enum GenError {
     MalformedPath,
     #[error("Export failed due to {0}")]
     ExportFailed(String),
+    #[error("Failed to migrate data as {0}")]
+    DataMigrationFailed(String),
+    #[error("The graph does not follow {schema}, found {count} violations")]
+    InvalidGraph { schema: String, count: usize },
+    #[error("Failed to generate data as {0}")]
+    DataGenerationFailed(String),
+    #[error("Found {count} problems in the project")]
+    InvalidProject { count: usize },
+    #[error("{count} generated files in {output:?} are out of date, run export to update them")]
+    OutdatedExport { output: PathBuf, count: usize },
+    #[error("Failed to read config at {path:?} due to {reason}")]
+    InvalidConfig { path: PathBuf, reason: String },
     #[error("Changeset {old_version} -> {new_version} has different hashes for {schema} expected {expected:#16x} recieved {recieved:#16x}")]
     DivergentChangeset {
         old_version: String,
@@ -54,6 +80,19 @@ pub enum GenError {
         expected: u64,
         recieved: u64,
     },
+    #[error("Changeset {old_version} -> {new_version} uses hash version {hash_version} but {expected} was expected, run migration rehash to update it")]
+    OutdatedHashVersion {
+        old_version: String,
+        new_version: String,
+        hash_version: u32,
+        expected: u32,
+    },
+    #[error("Changeset {old_version} -> {new_version} uses hash version {hash_version} which is newer than this version of the cli")]
+    UnknownHashVersion {
+        old_version: String,
+        new_version: String,
+        hash_version: u32,
+    },
     #[error(transparent)]
     ChangeSetError(#[from] ChangeSetError),
     #[error(transparent)]
@@ -66,4 +105,6 @@ pub enum GenError {
     TeraError(#[from] tera::Error),
     #[error(transparent)]
     ClapError(#[from] clap::Error),
+    #[error(transparent)]
+    NotifyError(#[from] notify_debouncer_mini::notify::Error),
 }
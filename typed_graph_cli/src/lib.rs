@@ -1,12 +1,22 @@
 mod case_changer;
 mod code_generation;
+mod data;
 mod book_generation;
 mod gen_error;
 mod project;
+mod project_config;
+mod project_problem;
+mod schema_diff;
+mod version_history;
 
 pub mod cli;
 
 pub use case_changer::*;
 pub use code_generation::*;
+pub use data::*;
 pub use gen_error::*;
-pub use project::*;
\ No newline at end of file
+pub use project::*;
+pub use project_config::*;
+pub use project_problem::*;
+pub use schema_diff::*;
+pub use version_history::*;
\ No newline at end of file
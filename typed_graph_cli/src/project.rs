@@ -1,15 +1,21 @@
-use build_changeset_lang::{ChangeSet, ChangeSetBuilder, DefaultChangeset};
+use build_changeset_lang::{
+    ChangeSet, ChangeSetBuilder, ClassifiedChange, Compatibility, DefaultChangeset,
+};
 use build_script_lang::schema::Schema;
 use build_script_shared::parsers::{
     Ident, Mark, Marked, ParserDeserialize, ParserDeserializeTo, ParserSerialize,
 };
-use build_script_shared::{BUILDScriptError, InputMarker};
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
-use std::fs::{create_dir_all, read_dir, read_to_string, remove_file, File};
+use build_script_shared::{BUILDScriptError, InputMarker, HASH_VERSION};
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::fs::{create_dir_all, read_dir, read_to_string, remove_dir_all, remove_file, File};
 use std::path::{Component, Path, PathBuf};
 use std::fmt::Write;
 
-use crate::{GenError, GenResult};
+use crate::{
+    migrate_graph, DroppedData, GenError, GenResult, ProjectConfig, ProjectProblem, CONFIG_FILE,
+};
 
 #[derive(Default)]
 pub struct Project {
@@ -20,6 +26,7 @@ pub struct Project {
     version_tree: HashMap<String, HashMap<String, (u64, Direction)>>,
     schema_folder: PathBuf,
     changeset_folder: PathBuf,
+    config: ProjectConfig,
 }
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, PartialOrd, Ord)]
@@ -31,20 +38,20 @@ pub enum Direction {
 impl Project {
     /// Create the project folder if it does not exist
     pub fn create_project<P: AsRef<Path>>(p: P) -> GenResult<Project> {
-        let (schema_folder, changeset_folder) = Project::create_project_directory(p)?;
-        Project::open(schema_folder, changeset_folder, true)
+        let (config, schema_folder, changeset_folder) = Project::create_project_directory(p)?;
+        Project::open(config, schema_folder, changeset_folder, true)
     }
 
     /// Open a project as normal
     pub fn open_project<P: AsRef<Path>>(p: P) -> GenResult<Project> {
-        let (schema_folder, changeset_folder) = Project::open_project_directory(p)?;
-        Project::open(schema_folder, changeset_folder, true)
+        let (config, schema_folder, changeset_folder) = Project::open_project_directory(p)?;
+        Project::open(config, schema_folder, changeset_folder, true)
     }
 
     /// Open a project without checking if the data makes sense
     pub fn open_project_raw<P: AsRef<Path>>(p: P) -> GenResult<Project> {
-        let (schema_folder, changeset_folder) = Project::open_project_directory(p)?;
-        Project::open(schema_folder, changeset_folder, false)
+        let (config, schema_folder, changeset_folder) = Project::open_project_directory(p)?;
+        Project::open(config, schema_folder, changeset_folder, false)
     }
 
     /// Internal method or opening a project  
@@ -54,12 +61,14 @@ impl Project {
     /// 1. a schema folder holding a number of schemas
     /// 2. a changeset folder holding a number of changesets between schemas
     fn open(
+        config: ProjectConfig,
         schema_folder: PathBuf,
         changeset_folder: PathBuf,
         check_integrity: bool,
     ) -> GenResult<Project> {
         let mut project = Project::default();
 
+        project.config = config;
         project.schema_folder = schema_folder.clone();
         project.changeset_folder = changeset_folder.clone();
 
@@ -73,6 +82,154 @@ impl Project {
         Ok(project)
     }
 
+    /// Open a project and collect every problem with it  
+    /// Unlike open_project this does not stop at the first error
+    ///
+    /// Only a missing project folder is returned as an error
+    pub fn check_project<P: AsRef<Path>>(p: P) -> GenResult<Vec<ProjectProblem>> {
+        let root_path = p.as_ref();
+        let (config, schema_folder, changeset_folder) = Project::open_project_directory(root_path)?;
+
+        let mut project = Project::default();
+        let mut problems = Vec::new();
+
+        let mut schema_paths: Vec<_> = read_dir(&schema_folder)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<_, _>>()?;
+        schema_paths.sort();
+        for path in schema_paths {
+            if let Err(e) = project.load_schema(&path) {
+                problems.extend(ProjectProblem::from_error(e, None, Some(&path)));
+            }
+        }
+
+        let mut changeset_paths: Vec<_> = read_dir(&changeset_folder)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<_, _>>()?;
+        changeset_paths.sort();
+        for path in changeset_paths {
+            if let Err(e) = project.load_changeset(&path) {
+                problems.extend(ProjectProblem::from_error(e, None, Some(&path)));
+            }
+        }
+
+        project.config = config;
+        project.schema_folder = schema_folder;
+        project.changeset_folder = changeset_folder;
+
+        let mut changesets: Vec<_> = project.changesets.iter().collect();
+        changesets.sort_by_key(|(_, changeset)| {
+            (changeset.old_version.to_string(), changeset.new_version.to_string())
+        });
+
+        for (id, changeset) in &changesets {
+            // Changesets pointing to missing schemas can not be tested
+            let mut is_dangling = false;
+            for (kind, schema) in [
+                ("old schema", &changeset.old_version),
+                ("new schema", &changeset.new_version),
+            ] {
+                if !project.has_schema(schema) {
+                    is_dangling = true;
+                    let e = GenError::MalformedVersionTree {
+                        kind: kind.to_string(),
+                        missing_key: schema.to_string(),
+                    };
+                    problems.extend(ProjectProblem::from_error(e, Some(schema.marker()), None));
+                }
+            }
+
+            if is_dangling {
+                continue;
+            }
+
+            if let Err(e) = project.test_changeset(changeset) {
+                let marker = changeset.old_version.marker();
+                problems.extend(ProjectProblem::from_error(e, Some(marker), None));
+            }
+
+            let is_registered = project
+                .version_tree
+                .get(changeset.old_version.as_str())
+                .and_then(|tree| tree.get(changeset.new_version.as_str()))
+                .is_some_and(|(tree_id, _)| tree_id == *id);
+            if !is_registered {
+                let e = GenError::MalformedVersionTree {
+                    kind: "changset".to_string(),
+                    missing_key: format!("{:#16x}", id),
+                };
+                let marker = changeset.old_version.marker();
+                problems.extend(ProjectProblem::from_error(e, Some(marker), None));
+            }
+        }
+
+        // A schema outside the tree of the root version can not be migrated to or from
+        let lint = &project.config.lint;
+        if let Some(severity) = lint.unreachable_schemas.severity() {
+            let missing_root = lint
+                .root_schema
+                .as_ref()
+                .filter(|root| !project.schemas.contains_key(*root));
+            if let Some(root) = missing_root {
+                let e = GenError::UnknownSchema { name: root.clone() };
+                let config_file = root_path.join(CONFIG_FILE);
+                problems.extend(
+                    ProjectProblem::from_error(e, None, Some(&config_file))
+                        .into_iter()
+                        .map(|problem| ProjectProblem { severity, ..problem }),
+                );
+            } else if project.schemas.len() > 1 {
+                let reachable = project.find_root_tree();
+                for (name, schema) in &project.schemas {
+                    if !reachable.contains(name) {
+                        let e = GenError::UnreachableSchema {
+                            target: name.clone(),
+                        };
+                        let marker = schema.version.marker();
+                        problems.extend(
+                            ProjectProblem::from_error(e, Some(marker), None)
+                                .into_iter()
+                                .map(|problem| ProjectProblem { severity, ..problem }),
+                        );
+                    }
+                }
+            }
+        }
+
+        if let Some(severity) = lint.breaking_changes.severity() {
+            for (_, changeset) in &changesets {
+                let old = changeset.old_version.to_string();
+                let new = changeset.new_version.to_string();
+                let Some(old_schema) = project.get_schema_safe(&old) else {
+                    continue;
+                };
+
+                // Changesets which can not be applied have already been reported
+                let Ok(classified_changes) = changeset.get_compatibility(old_schema) else {
+                    continue;
+                };
+
+                let compatibility = classified_changes
+                    .iter()
+                    .fold(Compatibility::Safe, |acc, classified| {
+                        acc.combine(&classified.compatibility)
+                    });
+
+                if compatibility.is_breaking() {
+                    let e = GenError::BreakingChanges { old, new };
+                    let marker = changeset.old_version.marker();
+                    problems.extend(
+                        ProjectProblem::from_error(e, Some(marker), None)
+                            .into_iter()
+                            .map(|problem| ProjectProblem { severity, ..problem }),
+                    );
+                }
+            }
+        }
+
+        Ok(problems)
+    }
+
     pub fn remove_changeset(
         &mut self,
         changeset_id: u64,
@@ -110,12 +267,69 @@ impl Project {
         }
 
         let changset = self.remove_changeset(*changeset_id)?;
-        let new_id = self.create_changeset(&changset.old_version, &changset.new_version)?;
+        let old = changset.old_version.to_string();
+        let new = changset.new_version.to_string();
+        let mut new_changeset = self.get_schema(&old)?.build_changeset(self.get_schema(&new)?)?;
+
+        // Fill expressions are written by hand so they should not be lost
+        new_changeset.keep_fills(&changset);
+
+        println!("Found changes from {old} to {new}:");
+        println!("{}", new_changeset);
+
+        let new_id = self.add_changeset(new_changeset)?;
         self.save_changeset(&new_id)?;
 
         Ok(())
     }
 
+    /// Update the hashes of a changeset to the current HASH_VERSION  
+    /// Unlike update_changeset the changes are kept as they are
+    ///
+    /// This will also update the corresponding changeset file  
+    /// Returns the new id of the changeset or None if it already uses the current HASH_VERSION
+    pub fn rehash_changeset(&mut self, changeset_id: &u64) -> GenResult<Option<u64>> {
+        let changeset = self.get_changeset(changeset_id)?;
+        if changeset.hash_version == HASH_VERSION {
+            return Ok(None);
+        }
+
+        if changeset.hash_version > HASH_VERSION {
+            return Err(GenError::UnknownHashVersion {
+                old_version: changeset.old_version.to_string(),
+                new_version: changeset.new_version.to_string(),
+                hash_version: changeset.hash_version,
+            });
+        }
+
+        // The old hashes has to match before we can trust the changeset
+        for (schema, expected) in [
+            (&changeset.old_version, changeset.old_hash),
+            (&changeset.new_version, changeset.new_hash),
+        ] {
+            let recieved = self.get_schema(&schema.to_string())?.get_legacy_hash();
+            if recieved != expected {
+                return Err(GenError::DivergentChangeset {
+                    old_version: changeset.old_version.to_string(),
+                    new_version: changeset.new_version.to_string(),
+                    schema: schema.to_string(),
+                    expected,
+                    recieved,
+                });
+            }
+        }
+
+        let mut changeset = self.remove_changeset(*changeset_id)?;
+        changeset.old_hash = self.get_schema(&changeset.old_version.to_string())?.get_hash();
+        changeset.new_hash = self.get_schema(&changeset.new_version.to_string())?.get_hash();
+        changeset.hash_version = HASH_VERSION;
+
+        let new_id = self.add_changeset(changeset)?;
+        self.save_changeset(&new_id)?;
+
+        Ok(Some(new_id))
+    }
+
     pub fn get_schema_folder(&self) -> &Path {
         &self.schema_folder
     }
@@ -124,6 +338,10 @@ impl Project {
         &self.changeset_folder
     }
 
+    pub fn get_config(&self) -> &ProjectConfig {
+        &self.config
+    }
+
     pub fn has_schema(&self, id: &str) -> bool {
         self.schemas.contains_key(id)
     }
@@ -414,6 +632,32 @@ impl Project {
         Ok(p)
     }
 
+    /// Add changesets to the project after checking that they match the schemas  
+    /// If any of them fails none of them are added
+    fn add_tested_changesets(
+        &mut self,
+        changesets: Vec<ChangeSet<InputMarker<String>>>,
+    ) -> GenResult<Vec<u64>> {
+        for changeset in &changesets {
+            self.test_changeset(changeset)?;
+
+            if let Some(existing) = self.changesets.get(&changeset.get_hash()) {
+                return Err(GenError::DuplicateKeys {
+                    kind: "changset".to_string(),
+                    old: existing.old_version.to_string(),
+                    new: existing.new_version.to_string(),
+                    old_hash: existing.old_hash,
+                    new_hash: existing.new_hash,
+                });
+            }
+        }
+
+        changesets
+            .into_iter()
+            .map(|changeset| self.add_changeset(changeset))
+            .collect()
+    }
+
     /// Add a new schema to the project  
     /// THIS WILL NOT SAVE IT AS A FILE!!!!
     pub fn add_schema(&mut self, schema: Schema<InputMarker<String>>) -> GenResult<String> {
@@ -574,6 +818,516 @@ impl Project {
         Ok(id)
     }
 
+    /// Find the changesets leading from one schema to another
+    pub fn find_changeset_path(&self, from: &String, to: &String) -> GenResult<Vec<u64>> {
+        let path = self.search_version_tree(from, to, false)?;
+        Ok(path.into_iter().map(|(id, _)| id).collect())
+    }
+
+    /// Find the shortest way through the version tree from one schema to another  
+    /// The path may go back to a common ancestor before going forward again
+    ///
+    /// Each changeset is paired with the direction it has to be applied in
+    pub fn find_migration_path(
+        &self,
+        from: &String,
+        to: &String,
+    ) -> GenResult<Vec<(u64, Direction)>> {
+        self.search_version_tree(from, to, true)
+    }
+
+    /// Search the version tree for the shortest path between two schemas  
+    /// Backwards changesets are only followed if undirected is set
+    fn search_version_tree(
+        &self,
+        from: &String,
+        to: &String,
+        undirected: bool,
+    ) -> GenResult<Vec<(u64, Direction)>> {
+        self.get_schema(from)?;
+        self.get_schema(to)?;
+
+        // Breadth first search so the shortest path is found
+        let mut visited: HashMap<&String, Option<(&String, u64, Direction)>> = HashMap::new();
+        let mut to_visit = VecDeque::new();
+        visited.insert(from, None);
+        to_visit.push_back(from);
+
+        while let Some(current) = to_visit.pop_front() {
+            if current == to {
+                break;
+            }
+
+            if let Some(neighbours) = self.version_tree.get(current) {
+                for (neighbour, (id, dir)) in neighbours {
+                    if (!undirected && dir != &Direction::Forward)
+                        || visited.contains_key(neighbour)
+                    {
+                        continue;
+                    }
+
+                    visited.insert(neighbour, Some((current, *id, *dir)));
+                    to_visit.push_back(neighbour);
+                }
+            }
+        }
+
+        if !visited.contains_key(to) {
+            return Err(GenError::UnreachableSchema { target: to.clone() });
+        }
+
+        // Walk back from the target to create the path
+        let mut path = Vec::new();
+        let mut current = to;
+        while let Some(Some((parent, id, dir))) = visited.get(current) {
+            path.push((*id, *dir));
+            current = parent;
+        }
+        path.reverse();
+
+        Ok(path)
+    }
+
+    /// Find the compatibility of every change leading from one schema to another
+    pub fn check_compatibility(
+        &self,
+        from: &String,
+        to: &String,
+    ) -> GenResult<Vec<ClassifiedChange<InputMarker<String>>>> {
+        let mut classified_changes = Vec::new();
+        for id in self.find_changeset_path(from, to)? {
+            let changeset = self.get_changeset(&id)?;
+            let old_schema = self.get_schema(&changeset.old_version.to_string())?;
+            classified_changes.extend(changeset.get_compatibility(old_schema)?);
+        }
+
+        Ok(classified_changes)
+    }
+
+    /// Migrate a serialized graph from one schema to another
+    ///
+    /// The shortest path through the version tree is used, so sibling schemas are migrated through their common ancestor.  
+    /// Changesets followed backwards are inverted before they are applied
+    pub fn migrate_data(
+        &self,
+        from: &String,
+        to: &String,
+        graph: &mut Value,
+    ) -> GenResult<Vec<DroppedData>> {
+        let mut dropped = Vec::new();
+        for (id, dir) in self.find_migration_path(from, to)? {
+            let changeset = self.get_changeset(&id)?;
+            let old_schema = self.get_schema(&changeset.old_version.to_string())?;
+            match dir {
+                Direction::Forward => {
+                    dropped.extend(migrate_graph(graph, changeset, old_schema)?);
+                }
+                Direction::Backwards => {
+                    let new_schema = self.get_schema(&changeset.new_version.to_string())?;
+                    let (inverted, _) = changeset.invert(old_schema)?;
+                    dropped.extend(migrate_graph(graph, &inverted, new_schema)?);
+                }
+            }
+        }
+
+        Ok(dropped)
+    }
+
+    /// Replace the changesets leading from one schema to another with a single changeset  
+    /// The schemas in between are deleted as well, so remove_schemas has to be set
+    ///
+    /// This will also update the files as needed  
+    /// Returns the id and path of the new changeset,
+    /// or None if the schemas are already linked by a single changeset
+    pub fn squash_changesets(
+        &mut self,
+        from: &String,
+        to: &String,
+        remove_schemas: bool,
+    ) -> GenResult<Option<(u64, PathBuf)>> {
+        let path = self.find_changeset_path(from, to)?;
+
+        let (first, rest) = path
+            .split_first()
+            .ok_or_else(|| GenError::UnreachableSchema { target: to.clone() })?;
+
+        if rest.is_empty() {
+            return Ok(None);
+        }
+
+        let mut squashed = self.get_changeset(first)?.clone();
+        for id in rest {
+            squashed = squashed.compose(self.get_changeset(id)?)?;
+        }
+
+        // The schemas in between must not be used by anything else
+        let mut between = Vec::new();
+        for id in path.iter().skip(1) {
+            let schema = self.get_changeset(id)?.old_version.to_string();
+            let neighbours = self.version_tree.get(&schema).map_or(0, |tree| tree.len());
+            if neighbours != 2 {
+                return Err(GenError::BranchingSchema { name: schema });
+            }
+            between.push(schema);
+        }
+
+        // Schemas without changesets are unreachable so they have to be removed as well
+        if !remove_schemas {
+            return Err(GenError::UnlinkedSchemas { schemas: between });
+        }
+
+        // The squashed changeset is checked before anything is deleted
+        let id = self.add_tested_changesets(vec![squashed])?[0];
+
+        for id in path {
+            self.delete_changeset(id)?;
+        }
+
+        for schema in between {
+            self.remove_schema(&schema)?;
+        }
+
+        let p = self.save_changeset(&id)?;
+
+        Ok(Some((id, p)))
+    }
+
+    /// Remove a schema from the project and delete its files  
+    /// The changesets for the schema are left untouched
+    pub fn remove_schema(&mut self, id: &String) -> GenResult<Schema<InputMarker<String>>> {
+        let schema = self
+            .schemas
+            .remove(id)
+            .ok_or_else(|| GenError::UnknownSchema { name: id.clone() })?;
+
+        let root_path = PathBuf::from(schema.marker().get_source());
+        let local_path: Vec<_> = root_path
+            .strip_prefix(&self.schema_folder)
+            .map(|local_path| local_path.components().collect())
+            .unwrap_or_default();
+
+        match local_path.as_slice() {
+            // Schemas split into multiple files lives in their own folder
+            [Component::Normal(folder), Component::Normal(file)] if *file == "schema.bs" => {
+                remove_dir_all(self.schema_folder.join(folder))?;
+            }
+            _ => {
+                if root_path.exists() {
+                    remove_file(root_path)?;
+                }
+            }
+        }
+
+        Ok(schema)
+    }
+
+    /// Remove a changeset from the project and delete its file
+    pub fn delete_changeset(
+        &mut self,
+        changeset_id: u64,
+    ) -> GenResult<ChangeSet<InputMarker<String>>> {
+        let changeset = self.remove_changeset(changeset_id)?;
+
+        // Changesets created by the project have not been loaded from a file yet
+        let source = PathBuf::from(changeset.old_version.marker().get_source());
+        let p = if source.is_file() {
+            source
+        } else {
+            self.changeset_folder.join(format!(
+                "{} {}.bs.diff",
+                changeset.old_version, changeset.new_version
+            ))
+        };
+        if p.exists() {
+            remove_file(p)?;
+        }
+
+        Ok(changeset)
+    }
+
+    /// Delete the changeset going from old to new  
+    /// Fails if it would cut schemas off from the rest of the version tree unless forced
+    pub fn delete_changeset_between(
+        &mut self,
+        old: &String,
+        new: &String,
+        force: bool,
+    ) -> GenResult<ChangeSet<InputMarker<String>>> {
+        let id = match self.version_tree.get(old).and_then(|tree| tree.get(new)) {
+            Some((id, Direction::Forward)) => *id,
+            _ => {
+                return Err(GenError::MissingChangeset {
+                    old: old.clone(),
+                    new: new.clone(),
+                })
+            }
+        };
+
+        let orphans = self.find_orphans(&[old, new], None, Some(id), &[]);
+        if !orphans.is_empty() && !force {
+            return Err(GenError::OrphanedSchemas {
+                target: format!("{old} -> {new}"),
+                orphans: orphans.into_iter().collect(),
+            });
+        }
+
+        self.delete_changeset(id)
+    }
+
+    /// Delete a schema along with all of its changesets
+    ///
+    /// When reconnecting, the changesets around the schema are composed so its parents lead directly to its children.  
+    /// Fails if it would cut schemas off from the rest of the version tree unless forced.  
+    /// Returns the ids of the changesets created to reconnect the tree
+    pub fn delete_schema(
+        &mut self,
+        name: &String,
+        reconnect: bool,
+        force: bool,
+    ) -> GenResult<Vec<u64>> {
+        self.get_schema(name)?;
+
+        // Parents goes first so the schemas connected to them are kept if the tree is split
+        let mut neighbours: Vec<(String, u64, Direction)> = self
+            .version_tree
+            .get(name)
+            .map(|tree| {
+                tree.iter()
+                    .map(|(neighbour, (id, dir))| (neighbour.clone(), *id, *dir))
+                    .collect()
+            })
+            .unwrap_or_default();
+        neighbours.sort_by_key(|(_, _, dir)| *dir != Direction::Backwards);
+
+        // The changesets are composed up front so nothing is changed if the schema can not be deleted
+        let mut composed = Vec::new();
+        if reconnect {
+            let parents = neighbours
+                .iter()
+                .filter(|(_, _, dir)| *dir == Direction::Backwards);
+            for (parent, parent_id, _) in parents {
+                let children = neighbours
+                    .iter()
+                    .filter(|(_, _, dir)| *dir == Direction::Forward);
+                for (child, child_id, _) in children {
+                    // The parent may already lead directly to the child
+                    let is_linked = self
+                        .version_tree
+                        .get(parent)
+                        .is_some_and(|tree| tree.contains_key(child));
+                    if is_linked {
+                        continue;
+                    }
+
+                    let changeset = self
+                        .get_changeset(parent_id)?
+                        .compose(self.get_changeset(child_id)?)?;
+                    composed.push(changeset);
+                }
+            }
+        }
+
+        // The schemas linked by the composed changesets stay connected
+        let links: Vec<_> = composed
+            .iter()
+            .map(|changeset| {
+                (
+                    changeset.old_version.to_string(),
+                    changeset.new_version.to_string(),
+                )
+            })
+            .collect();
+        let neighbour_names: Vec<_> = neighbours
+            .iter()
+            .map(|(neighbour, _, _)| neighbour)
+            .collect();
+        let orphans = self.find_orphans(&neighbour_names, Some(name), None, &links);
+        if !orphans.is_empty() && !force {
+            return Err(GenError::OrphanedSchemas {
+                target: name.clone(),
+                orphans: orphans.into_iter().collect(),
+            });
+        }
+
+        let ids = self.add_tested_changesets(composed)?;
+
+        for (_, id, _) in neighbours {
+            self.delete_changeset(id)?;
+        }
+        self.remove_schema(name)?;
+
+        for id in &ids {
+            self.save_changeset(id)?;
+        }
+
+        Ok(ids)
+    }
+
+    /// Find the schemas which are no longer connected to the first schema if a schema or changeset is left out
+    ///
+    /// The links are pairs of schemas which are about to be connected by a new changeset
+    fn find_orphans(
+        &self,
+        schemas: &[&String],
+        skip_schema: Option<&str>,
+        skip_changeset: Option<u64>,
+        links: &[(String, String)],
+    ) -> BTreeSet<String> {
+        let mut orphans = BTreeSet::new();
+        let Some((first, rest)) = schemas.split_first() else {
+            return orphans;
+        };
+
+        let connected = self.find_connected(first, skip_schema, skip_changeset, links);
+        for schema in rest {
+            if !connected.contains(*schema) && !orphans.contains(*schema) {
+                orphans.extend(self.find_connected(schema, skip_schema, skip_changeset, links));
+            }
+        }
+
+        orphans
+    }
+
+    /// Find every schema which can be reached from the start by following changesets in any direction
+    fn find_connected<'a>(
+        &'a self,
+        start: &'a str,
+        skip_schema: Option<&str>,
+        skip_changeset: Option<u64>,
+        links: &'a [(String, String)],
+    ) -> BTreeSet<String> {
+        let mut connected = BTreeSet::new();
+        connected.insert(start.to_string());
+        let mut to_visit = vec![start];
+
+        while let Some(current) = to_visit.pop() {
+            let tree_neighbours = self
+                .version_tree
+                .get(current)
+                .into_iter()
+                .flatten()
+                .filter(|(neighbour, (id, _))| {
+                    Some(*id) != skip_changeset && Some(neighbour.as_str()) != skip_schema
+                })
+                .map(|(neighbour, _)| neighbour.as_str());
+            let linked_neighbours = links.iter().filter_map(|(old, new)| {
+                if old == current {
+                    Some(new.as_str())
+                } else if new == current {
+                    Some(old.as_str())
+                } else {
+                    None
+                }
+            });
+
+            for neighbour in tree_neighbours.chain(linked_neighbours) {
+                if connected.insert(neighbour.to_string()) {
+                    to_visit.push(neighbour);
+                }
+            }
+        }
+
+        connected
+    }
+
+    /// Find the closest schema which both heads originate from
+    pub fn find_common_ancestor(&self, a: &str, b: &str) -> GenResult<String> {
+        let mut parents_a = self.get_parents(a);
+        parents_a.insert(a.to_string());
+        let mut parents_b = self.get_parents(b);
+        parents_b.insert(b.to_string());
+
+        // The closest ancestor is the one with the most parents of its own
+        parents_a
+            .intersection(&parents_b)
+            .max_by_key(|ancestor| self.get_parents(ancestor).len())
+            .cloned()
+            .ok_or_else(|| GenError::NoCommonAncestor {
+                a: a.to_string(),
+                b: b.to_string(),
+            })
+    }
+
+    /// Merge two heads into a new schema with changesets from both of them  
+    /// If no name is given the name of ours is incremented
+    ///
+    /// This will also create the schema and changeset files
+    pub fn merge_heads(
+        &mut self,
+        ours: &String,
+        theirs: &String,
+        new_name: Option<String>,
+    ) -> GenResult<String> {
+        let base = self.find_common_ancestor(ours, theirs)?;
+
+        let base_schema = self.get_schema(&base)?;
+        let our_schema = self.get_schema(ours)?;
+        let their_schema = self.get_schema(theirs)?;
+
+        let (merged, conflicts) = base_schema.merge(our_schema, their_schema);
+
+        if !conflicts.is_empty() {
+            let mut s = String::new();
+            for conflict in conflicts {
+                writeln!(s, "{} was changed by both {ours} and {theirs}", conflict.path)?;
+                for (version, mark) in [(ours, conflict.ours), (theirs, conflict.theirs)] {
+                    match mark {
+                        Some(mark) => {
+                            writeln!(s, "{version} at {}:", mark.get_source())?;
+                            writeln!(s, "{}", mark.get_preview(2, 2))?;
+                        }
+                        None => writeln!(s, "{version} removed it")?,
+                    }
+                }
+            }
+            return Err(GenError::MergeConflict(s));
+        }
+
+        merged.check_integrity().map_err(BUILDScriptError::from)?;
+
+        let mut new_name =
+            new_name.unwrap_or_else(|| Project::increment_name(ours, || "_merge".chars()));
+        while self.has_schema(&new_name) {
+            new_name += "_merge";
+        }
+
+        // Place the files of the merged schema the same way ours are placed
+        let our_root = PathBuf::from(our_schema.marker().get_source());
+        let their_root = PathBuf::from(their_schema.marker().get_source());
+        let is_folder = our_root.ends_with("schema.bs");
+        let new_folder = self.schema_folder.join(&new_name);
+        let new_root = if is_folder {
+            new_folder.join("schema.bs")
+        } else {
+            self.schema_folder.join(format!("{new_name}.bs"))
+        };
+
+        let mut merged = merged.map(|mut input| {
+            let source = PathBuf::from(input.get_source());
+            let local_path = [&our_root, &their_root]
+                .into_iter()
+                .filter(|root| root.ends_with("schema.bs"))
+                .filter_map(|root| root.parent())
+                .find_map(|folder| source.strip_prefix(folder).ok());
+
+            let updated_source = match local_path {
+                Some(local_path) if is_folder => new_folder.join(local_path),
+                _ => new_root.clone(),
+            };
+            input.set_source(updated_source.to_str().unwrap_or_default().to_string());
+            input
+        });
+        merged.version = Ident::new(new_name.clone(), Mark::default());
+
+        self.add_schema(merged)?;
+        self.save_schema(&new_name)?;
+
+        self.create_changeset(ours, &new_name)?;
+        self.create_changeset(theirs, &new_name)?;
+
+        Ok(new_name)
+    }
+
     /// Find heads in the version tree
     pub fn find_heads(&self) -> Vec<String> {
         let mut schema_keys: HashSet<String> = self.schemas.keys().cloned().collect();
@@ -592,15 +1346,63 @@ impl Project {
         schema_keys.into_iter().collect()
     }
 
+    /// Find the schemas connected to the root version  
+    /// The root version is the root_schema from the lint config if it is set.
+    /// Otherwise the root connected to the most schemas is used,
+    /// and if several roots are connected to the same number of schemas the lowest version wins
+    pub fn find_root_tree(&self) -> HashSet<&String> {
+        if let Some(root) = &self.config.lint.root_schema {
+            return self.find_tree(root);
+        }
+
+        let mut roots = self.find_roots();
+        roots.sort_by(|a, b| compare_versions(a, b));
+
+        let mut root_tree = HashSet::new();
+        for root in &roots {
+            let tree = self.find_tree(root);
+            if tree.len() > root_tree.len() {
+                root_tree = tree;
+            }
+        }
+
+        root_tree
+    }
+
+    /// Find all schemas linked to the given schema by changesets
+    fn find_tree(&self, root: &str) -> HashSet<&String> {
+        let mut tree = HashSet::new();
+        let Some((root, _)) = self.schemas.get_key_value(root) else {
+            return tree;
+        };
+
+        // Changesets are followed in both directions so merged branches are included
+        let mut to_visit = vec![root];
+        while let Some(current) = to_visit.pop() {
+            if !tree.insert(current) {
+                continue;
+            }
+
+            if let Some(neighbours) = self.version_tree.get(current) {
+                to_visit.extend(neighbours.keys());
+            }
+        }
+
+        tree
+    }
+
     /// Check if the project folder exists
-    fn open_project_directory<P: AsRef<Path>>(p: P) -> GenResult<(PathBuf, PathBuf)> {
+    pub(crate) fn open_project_directory<P: AsRef<Path>>(
+        p: P,
+    ) -> GenResult<(ProjectConfig, PathBuf, PathBuf)> {
         let root = p.as_ref();
         if !root.exists() {
             return Err(GenError::InvalidProjectPath(root.to_path_buf()));
         }
 
-        let schema_folder = root.join("schemas");
-        let changeset_folder = root.join("changesets");
+        let config = ProjectConfig::load(root)?;
+        let schema_folder = root.join(&config.schemas);
+        let changeset_folder = root.join(&config.changesets);
 
         if !schema_folder.exists() || !schema_folder.is_dir() {
             return Err(GenError::MissingFolder {
@@ -620,18 +1422,21 @@ impl Project {
             });
         }
 
-        Ok((schema_folder, changeset_folder))
+        Ok((config, schema_folder, changeset_folder))
     }
 
     /// Create the project folder and the path to the folder if it does not exist
-    fn create_project_directory<P: AsRef<Path>>(p: P) -> GenResult<(PathBuf, PathBuf)> {
+    fn create_project_directory<P: AsRef<Path>>(
+        p: P,
+    ) -> GenResult<(ProjectConfig, PathBuf, PathBuf)> {
         let root = p.as_ref();
         if !root.exists() {
             return Err(GenError::InvalidProjectPath(root.to_path_buf()));
         }
 
-        let schema_folder = root.join("schemas");
-        let changeset_folder = root.join("changesets");
+        let config = ProjectConfig::load(root)?;
+        let schema_folder = root.join(&config.schemas);
+        let changeset_folder = root.join(&config.changesets);
 
         if !schema_folder.exists() {
             create_dir_all(&schema_folder)?;
@@ -641,7 +1446,7 @@ impl Project {
             create_dir_all(&changeset_folder)?;
         }
 
-        Ok((schema_folder, changeset_folder))
+        Ok((config, schema_folder, changeset_folder))
     }
 
     /// Load all schemas files from a folder
@@ -691,7 +1496,19 @@ impl Project {
     }
 
     fn load_schema_from_folder<P: AsRef<Path>>(&mut self, schema_folder: P) -> GenResult<()> {
-        let path = schema_folder.as_ref();
+        let schema = Project::read_schema(schema_folder)?;
+        self.add_schema(schema)?;
+        Ok(())
+    }
+
+    /// Read a schema file or schema folder without adding it to the project  
+    /// This allows schemas outside the project to be compared with the ones inside it
+    pub fn read_schema<P: AsRef<Path>>(p: P) -> GenResult<Schema<InputMarker<String>>> {
+        let path = p.as_ref();
+        if !path.is_dir() {
+            return Project::parse_schema(path, true, true);
+        }
+
         let root_path = path.join("schema.bs");
         if !root_path.exists() {
             return Err(GenError::InvalidSchemaPath(root_path));
@@ -704,8 +1521,7 @@ impl Project {
             .check_integrity()
             .map_err(|e| BUILDScriptError::from(e))?;
 
-        self.add_schema(root_schema)?;
-        Ok(())
+        Ok(root_schema)
     }
 
     fn load_schema_from_file<P: AsRef<Path>>(&mut self, schema_file: P) -> GenResult<()> {
@@ -715,7 +1531,7 @@ impl Project {
             .ok_or_else(|| GenError::InvalidSchemaPath(path.to_path_buf()))?;
         if let Some(s) = file_name.to_str() {
             if s.ends_with(".bs") {
-                let schema = Project::parse_schema(path, true, true)?;
+                let schema = Project::read_schema(path)?;
                 self.add_schema(schema)?;
             }
         }
@@ -754,24 +1570,34 @@ impl Project {
     fn load_changesets<P: AsRef<Path>>(&mut self, changeset_folder: P) -> GenResult<()> {
         let changeset_iter = read_dir(changeset_folder)?;
         for changeset_file in changeset_iter {
-            let changeset_path = changeset_file?;
-            if let Ok(s) = changeset_path.file_name().into_string() {
-                if s.ends_with(".bs.diff") {
-                    let content = read_to_string(changeset_path.path())?;
-                    let input = InputMarker::new_from_file(
-                        content.as_str(),
-                        changeset_path
-                            .path()
-                            .to_str()
-                            .ok_or_else(|| GenError::MalformedPath)?
-                            .to_string(),
-                    );
-                    let changeset = DefaultChangeset::deserialize(input)?;
-                    let owned_changeset = changeset.map(|i| i.map(|data| data.to_string()));
-                    self.add_changeset(owned_changeset)?;
-                }
-            }
+            self.load_changeset(changeset_file?.path())?;
+        }
+        Ok(())
+    }
+
+    /// Load a single changeset file  
+    /// Files which are not changesets are ignored
+    fn load_changeset<P: AsRef<Path>>(&mut self, changeset_path: P) -> GenResult<()> {
+        let path = changeset_path.as_ref();
+        let is_changeset = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with(".bs.diff"));
+
+        if !is_changeset {
+            return Ok(());
         }
+
+        let content = read_to_string(path)?;
+        let input = InputMarker::new_from_file(
+            content.as_str(),
+            path.to_str()
+                .ok_or_else(|| GenError::MalformedPath)?
+                .to_string(),
+        );
+        let changeset = DefaultChangeset::deserialize(input)?;
+        let owned_changeset = changeset.map(|i| i.map(|data| data.to_string()));
+        self.add_changeset(owned_changeset)?;
         Ok(())
     }
 
@@ -850,6 +1676,23 @@ impl Project {
 
     /// Check the hashes and correctness of a changeset
     fn test_changeset(&self, changeset: &ChangeSet<InputMarker<String>>) -> GenResult<()> {
+        if changeset.hash_version > HASH_VERSION {
+            return Err(GenError::UnknownHashVersion {
+                old_version: changeset.old_version.to_string(),
+                new_version: changeset.new_version.to_string(),
+                hash_version: changeset.hash_version,
+            });
+        }
+
+        if changeset.hash_version != HASH_VERSION {
+            return Err(GenError::OutdatedHashVersion {
+                old_version: changeset.old_version.to_string(),
+                new_version: changeset.new_version.to_string(),
+                hash_version: changeset.hash_version,
+                expected: HASH_VERSION,
+            });
+        }
+
         let old = self.get_schema(&changeset.old_version)?;
         let new = self.get_schema(&changeset.new_version)?;
 
@@ -910,3 +1753,302 @@ impl Project {
         Ok(())
     }
 }
+
+/// Compare version names with the numbers in them compared by value  
+/// So V0.9 comes before V0.10
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    fn split(name: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut start = 0;
+        let mut chars = name.char_indices().peekable();
+        while let Some((_, c)) = chars.next() {
+            if let Some(&(i, next)) = chars.peek() {
+                if c.is_ascii_digit() != next.is_ascii_digit() {
+                    parts.push(&name[start..i]);
+                    start = i;
+                }
+            }
+        }
+        parts.push(&name[start..]);
+        parts
+    }
+
+    for (a, b) in split(a).into_iter().zip(split(b)) {
+        let ordering = match (a.parse::<u128>(), b.parse::<u128>()) {
+            (Ok(a), Ok(b)) => a.cmp(&b),
+            _ => a.cmp(b),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    a.cmp(b)
+}
+
+#[test]
+fn remove_schema_test() -> GenResult<()> {
+    use std::fs::write;
+    use tempdir::TempDir;
+
+    let tmp = TempDir::new("typed_graph")?;
+    Project::create_project(tmp.path())?;
+
+    // A schema stored directly in the schema folder under the name used by folder schemas
+    let schemas = tmp.path().join("schemas");
+    create_dir_all(schemas.join("V0.0"))?;
+    write(schemas.join("V0.0").join("schema.bs"), "<V0.0>\n")?;
+    write(schemas.join("schema.bs"), "<V1.0>\n")?;
+
+    let mut project = Project::open_project(tmp.path())?;
+    project.remove_schema(&"V1.0".to_string())?;
+    assert!(!schemas.join("schema.bs").exists());
+    assert!(schemas.join("V0.0").join("schema.bs").exists());
+
+    project.remove_schema(&"V0.0".to_string())?;
+    assert!(!schemas.join("V0.0").exists());
+    assert!(schemas.exists());
+
+    Ok(())
+}
+
+/// Create a project with a schema file for each schema and a changeset for each link
+#[cfg(test)]
+fn create_test_project(
+    schemas: &[(&str, &str)],
+    links: &[(&str, &str)],
+) -> GenResult<(tempdir::TempDir, Project)> {
+    use std::fs::write;
+    use tempdir::TempDir;
+
+    let tmp = TempDir::new("typed_graph")?;
+    Project::create_project(tmp.path())?;
+
+    for (name, content) in schemas {
+        write(
+            tmp.path().join("schemas").join(format!("{name}.bs")),
+            format!("<{name}>\n{content}"),
+        )?;
+    }
+
+    let mut project = Project::open_project(tmp.path())?;
+    for (old, new) in links {
+        project.create_changeset(&old.to_string(), &new.to_string())?;
+    }
+
+    // Reopen the project so everything is loaded from the files
+    let project = Project::open_project(tmp.path())?;
+    Ok((tmp, project))
+}
+
+#[test]
+fn delete_changeset_between_test() -> GenResult<()> {
+    use std::fs::rename;
+
+    let schemas = [
+        ("V0.0", "node A {\n    a: String\n};\n"),
+        ("V0.1", "node A {\n    a: String,\n    b: bool\n};\n"),
+        (
+            "V0.2",
+            "node A {\n    a: String,\n    b: bool,\n    c: bool\n};\n",
+        ),
+    ];
+    let (tmp, _) = create_test_project(&schemas, &[("V0.0", "V0.1"), ("V0.1", "V0.2")])?;
+
+    // The changeset is deleted even if the file was given another name
+    let changesets = tmp.path().join("changesets");
+    let custom = changesets.join("custom.bs.diff");
+    rename(changesets.join("V0.1 V0.2.bs.diff"), &custom)?;
+    let mut project = Project::open_project(tmp.path())?;
+
+    let [v0, v1, v2] = ["V0.0", "V0.1", "V0.2"].map(String::from);
+    let res = project.delete_changeset_between(&v0, &v1, false);
+    assert!(matches!(res, Err(GenError::OrphanedSchemas { .. })));
+    assert!(changesets.join("V0.0 V0.1.bs.diff").exists());
+    assert_eq!(project.iter_changesets().count(), 2);
+
+    // With a shortcut from V0.0 to V0.2 nothing is cut off
+    project.create_changeset(&v0, &v2)?;
+    project.delete_changeset_between(&v1, &v2, false)?;
+    assert!(!custom.exists());
+
+    project.delete_changeset_between(&v0, &v1, true)?;
+    assert!(!changesets.join("V0.0 V0.1.bs.diff").exists());
+
+    let project = Project::open_project(tmp.path())?;
+    let remaining: Vec<_> = project
+        .iter_changesets()
+        .map(|id| project.get_changeset(id).unwrap().new_version.to_string())
+        .collect();
+    assert_eq!(remaining, vec!["V0.2".to_string()]);
+
+    Ok(())
+}
+
+#[test]
+fn delete_schema_test() -> GenResult<()> {
+    let schemas = [
+        ("V0.0", "node A {\n    a: String\n};\n"),
+        ("V0.1", "node A {\n    a: String,\n    b: bool\n};\n"),
+        (
+            "V0.2",
+            "node A {\n    a: String,\n    b: bool,\n    c: bool\n};\n",
+        ),
+    ];
+    let links = [("V0.0", "V0.1"), ("V0.1", "V0.2")];
+    let [v0, v1, v2] = ["V0.0", "V0.1", "V0.2"].map(String::from);
+
+    // Nothing is changed when the tree would be split
+    let (tmp, mut project) = create_test_project(&schemas, &links)?;
+    let schema_folder = tmp.path().join("schemas");
+    let changeset_folder = tmp.path().join("changesets");
+    let res = project.delete_schema(&v1, false, false);
+    assert!(matches!(res, Err(GenError::OrphanedSchemas { .. })));
+    assert!(schema_folder.join("V0.1.bs").exists());
+    assert!(changeset_folder.join("V0.0 V0.1.bs.diff").exists());
+    assert!(changeset_folder.join("V0.1 V0.2.bs.diff").exists());
+    assert_eq!(project.iter_changesets().count(), 2);
+
+    // Reconnecting composes the changesets around the schema
+    let composed = project.delete_schema(&v1, true, false)?;
+    assert_eq!(composed.len(), 1);
+    assert!(!schema_folder.join("V0.1.bs").exists());
+    assert!(!changeset_folder.join("V0.0 V0.1.bs.diff").exists());
+    assert!(!changeset_folder.join("V0.1 V0.2.bs.diff").exists());
+
+    let project = Project::open_project(tmp.path())?;
+    assert_eq!(project.find_changeset_path(&v0, &v2)?, composed);
+
+    // Forcing leaves the rest of the tree disconnected
+    let (tmp, mut project) = create_test_project(&schemas, &links)?;
+    let composed = project.delete_schema(&v1, false, true)?;
+    assert!(composed.is_empty());
+
+    let project = Project::open_project(tmp.path())?;
+    assert_eq!(project.iter_changesets().count(), 0);
+    assert!(project.has_schema(&v2));
+
+    Ok(())
+}
+
+#[test]
+fn squash_changesets_test() -> GenResult<()> {
+    let schemas = [
+        ("V0.0", "node A {\n    a: String\n};\n"),
+        ("V0.1", "node A {\n    a: String,\n    b: bool\n};\n"),
+        (
+            "V0.2",
+            "node A {\n    a: String,\n    b: bool,\n    c: bool\n};\n",
+        ),
+    ];
+    let links = [("V0.0", "V0.1"), ("V0.1", "V0.2")];
+    let [v0, v1, v2] = ["V0.0", "V0.1", "V0.2"].map(String::from);
+
+    // V0.1 would be left behind without any changesets
+    let (tmp, mut project) = create_test_project(&schemas, &links)?;
+    let schema_folder = tmp.path().join("schemas");
+    let changeset_folder = tmp.path().join("changesets");
+    let res = project.squash_changesets(&v0, &v2, false);
+    assert!(matches!(res, Err(GenError::UnlinkedSchemas { .. })));
+    assert!(schema_folder.join("V0.1.bs").exists());
+    assert!(changeset_folder.join("V0.0 V0.1.bs.diff").exists());
+    assert!(changeset_folder.join("V0.1 V0.2.bs.diff").exists());
+
+    let (id, _) = project.squash_changesets(&v0, &v2, true)?.unwrap();
+    assert!(!schema_folder.join("V0.1.bs").exists());
+    assert!(!changeset_folder.join("V0.0 V0.1.bs.diff").exists());
+    assert!(!changeset_folder.join("V0.1 V0.2.bs.diff").exists());
+    assert!(changeset_folder.join("V0.0 V0.2.bs.diff").exists());
+
+    let project = Project::open_project(tmp.path())?;
+    assert!(!project.has_schema(&v1));
+    assert_eq!(project.find_changeset_path(&v0, &v2)?, vec![id]);
+    assert!(Project::check_project(tmp.path())?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn merge_heads_test() -> GenResult<()> {
+    use build_script_lang::schema::SchemaStm;
+
+    let [base, ours, theirs] = ["V0.0", "V0.1", "V0.1_beta"].map(String::from);
+    let links = [("V0.0", "V0.1"), ("V0.0", "V0.1_beta")];
+
+    // Each side added its own field
+    let schemas = [
+        ("V0.0", "node A {\n    a: String\n};\n"),
+        ("V0.1", "node A {\n    a: String,\n    b: bool\n};\n"),
+        ("V0.1_beta", "node A {\n    a: String,\n    c: bool\n};\n"),
+    ];
+    let (tmp, mut project) = create_test_project(&schemas, &links)?;
+    let merged = project.merge_heads(&ours, &theirs, Some("V0.2".to_string()))?;
+    assert_eq!(merged, "V0.2");
+
+    let project = Project::open_project(tmp.path())?;
+    let fields: Vec<_> = project
+        .get_schema(&merged)?
+        .iter()
+        .flat_map(|stm| match stm {
+            SchemaStm::Node(node) => node.fields.iter().map(|f| f.name.to_string()).collect(),
+            _ => Vec::new(),
+        })
+        .collect();
+    assert_eq!(fields, vec!["a", "b", "c"]);
+    project.find_changeset_path(&ours, &merged)?;
+    project.find_changeset_path(&theirs, &merged)?;
+    project.find_changeset_path(&base, &merged)?;
+    assert!(Project::check_project(tmp.path())?.is_empty());
+
+    // Both sides added the same field with different types
+    let schemas = [
+        ("V0.0", "node A {\n    a: String\n};\n"),
+        ("V0.1", "node A {\n    a: String,\n    b: bool\n};\n"),
+        ("V0.1_beta", "node A {\n    a: String,\n    b: u8\n};\n"),
+    ];
+    let (tmp, mut project) = create_test_project(&schemas, &links)?;
+    let res = project.merge_heads(&ours, &theirs, Some("V0.2".to_string()));
+    assert!(matches!(res, Err(GenError::MergeConflict(_))));
+    assert!(!project.has_schema("V0.2"));
+    assert!(!tmp.path().join("schemas").join("V0.2.bs").exists());
+
+    Ok(())
+}
+
+#[test]
+fn find_root_tree_test() -> GenResult<()> {
+    use std::fs::write;
+
+    let schemas = [
+        ("V0.9", "node A {};\n"),
+        ("V0.10", "node B {};\n"),
+        ("V0.11", "node C {};\n"),
+    ];
+    let (_tmp, project) = create_test_project(&schemas, &[])?;
+
+    // Trees of the same size are settled by the lowest version
+    let root_tree: Vec<_> = project.find_root_tree().into_iter().collect();
+    assert_eq!(root_tree, vec!["V0.9"]);
+
+    let (tmp, project) = create_test_project(&schemas, &[("V0.10", "V0.11")])?;
+    let mut root_tree: Vec<_> = project.find_root_tree().into_iter().collect();
+    root_tree.sort();
+    assert_eq!(root_tree, vec!["V0.10", "V0.11"]);
+
+    // The configured root is used even if its tree is smaller
+    write(tmp.path().join(CONFIG_FILE), "[lint]\nroot_schema = \"V0.9\"\n")?;
+    let project = Project::open_project(tmp.path())?;
+    let root_tree: Vec<_> = project.find_root_tree().into_iter().collect();
+    assert_eq!(root_tree, vec!["V0.9"]);
+
+    let problems = Project::check_project(tmp.path())?;
+    assert_eq!(problems.len(), 2);
+
+    // A root which does not exist is reported instead of every schema
+    write(tmp.path().join(CONFIG_FILE), "[lint]\nroot_schema = \"V1.0\"\n")?;
+    let problems = Project::check_project(tmp.path())?;
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].message.contains("V1.0"));
+
+    Ok(())
+}
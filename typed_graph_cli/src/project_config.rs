@@ -0,0 +1,218 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+
+use crate::{GenError, GenResult, Severity};
+
+/// Name of the configuration file placed in the project folder
+pub const CONFIG_FILE: &str = "typed_graph.toml";
+
+/// Settings stored in typed_graph.toml
+/// All paths are relative to the project folder
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// Name of the folder containing the schemas
+    pub schemas: PathBuf,
+    /// Name of the folder containing the changesets
+    pub changesets: PathBuf,
+    /// Targets exported by a bare `export`
+    pub export: ExportConfig,
+    pub lint: LintConfig,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExportConfig {
+    pub rust: Option<CodeConfig>,
+    pub python: Option<CodeConfig>,
+    pub json: Option<OutputConfig>,
+    pub svg: Option<OutputConfig>,
+    pub doc: Option<DocConfig>,
+    pub history: Option<HistoryConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutputConfig {
+    /// Where to output the export
+    pub output: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CodeConfig {
+    /// Where to output the interface
+    pub output: PathBuf,
+    /// Fail if the generated code is out of date instead of writing it
+    #[serde(default)]
+    pub check: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HistoryConfig {
+    /// File to save the diagram in
+    pub output: PathBuf,
+    /// Format of the diagram, defaults to svg
+    #[serde(default)]
+    pub format: HistoryFormat,
+}
+
+/// Formats the version history can be exported as
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HistoryFormat {
+    Dot,
+    Mermaid,
+    #[default]
+    Svg,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DocConfig {
+    /// Build directory for the docs, defaults to a temporary directory
+    pub out_dir: Option<PathBuf>,
+    /// Where to save the resulting docs
+    pub target_dir: Option<PathBuf>,
+}
+
+/// How the check command should treat the problems it finds
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LintConfig {
+    /// Schemas which can not be reached from the root version
+    pub unreachable_schemas: LintLevel,
+    /// Changesets which are neither backward nor forward compatible
+    pub breaking_changes: LintLevel,
+    /// Schema the other schemas must be reachable from  
+    /// By default the largest tree of schemas is used
+    pub root_schema: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Error,
+}
+
+impl Default for ProjectConfig {
+    fn default() -> Self {
+        ProjectConfig {
+            schemas: PathBuf::from("schemas"),
+            changesets: PathBuf::from("changesets"),
+            export: Default::default(),
+            lint: Default::default(),
+        }
+    }
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        LintConfig {
+            unreachable_schemas: LintLevel::Error,
+            breaking_changes: LintLevel::Allow,
+            root_schema: None,
+        }
+    }
+}
+
+impl ExportConfig {
+    pub fn is_empty(&self) -> bool {
+        self == &ExportConfig::default()
+    }
+}
+
+impl LintLevel {
+    /// How problems found by the lint should be reported
+    pub fn severity(&self) -> Option<Severity> {
+        match self {
+            LintLevel::Allow => None,
+            LintLevel::Warn => Some(Severity::Warning),
+            LintLevel::Error => Some(Severity::Error),
+        }
+    }
+}
+
+impl ProjectConfig {
+    /// Load the configuration of a project
+    /// If the project has no typed_graph.toml the default configuration is used
+    pub fn load<P: AsRef<Path>>(project_folder: P) -> GenResult<ProjectConfig> {
+        let path = project_folder.as_ref().join(CONFIG_FILE);
+        if !path.exists() {
+            return Ok(ProjectConfig::default());
+        }
+
+        let content = read_to_string(&path)?;
+        toml::from_str(&content).map_err(|e| GenError::InvalidConfig {
+            path,
+            reason: e.to_string(),
+        })
+    }
+}
+
+#[test]
+fn project_config_test() {
+    let config: ProjectConfig = toml::from_str(
+        r#"
+        schemas = "bs"
+
+        [export.rust]
+        output = "../src/graph"
+        check = true
+
+        [export.python]
+        output = "../python/graph"
+
+        [export.history]
+        output = "history.md"
+        format = "mermaid"
+
+        [export.doc]
+        target_dir = "../book"
+
+        [lint]
+        breaking_changes = "warn"
+        root_schema = "V0.0"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config,
+        ProjectConfig {
+            schemas: PathBuf::from("bs"),
+            changesets: PathBuf::from("changesets"),
+            export: ExportConfig {
+                rust: Some(CodeConfig {
+                    output: PathBuf::from("../src/graph"),
+                    check: true,
+                }),
+                python: Some(CodeConfig {
+                    output: PathBuf::from("../python/graph"),
+                    check: false,
+                }),
+                history: Some(HistoryConfig {
+                    output: PathBuf::from("history.md"),
+                    format: HistoryFormat::Mermaid,
+                }),
+                doc: Some(DocConfig {
+                    out_dir: None,
+                    target_dir: Some(PathBuf::from("../book"))
+                }),
+                ..Default::default()
+            },
+            lint: LintConfig {
+                unreachable_schemas: LintLevel::Error,
+                breaking_changes: LintLevel::Warn,
+                root_schema: Some("V0.0".to_string()),
+            }
+        }
+    );
+
+    assert!(toml::from_str::<ProjectConfig>("[export.java]\noutput = \"java\"").is_err());
+}
@@ -0,0 +1,169 @@
+use build_script_shared::error::ParserErrorKind;
+use build_script_shared::{BUILDScriptError, CodePreview, InputMarker};
+use serde::Serialize;
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::GenError;
+
+/// A problem found while checking a project
+#[derive(Debug, Serialize)]
+pub struct ProjectProblem {
+    /// File the problem was found in
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+    pub severity: Severity,
+    #[serde(skip)]
+    pub preview: Option<CodePreview>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl ProjectProblem {
+    /// Create a problem for every location mentioned in the error
+    ///
+    /// Parser errors know where they were found
+    /// All other errors are placed at the marker if it has any source and otherwise at the file
+    pub fn from_error(
+        e: GenError,
+        marker: Option<&InputMarker<String>>,
+        file: Option<&Path>,
+    ) -> Vec<ProjectProblem> {
+        if let GenError::ParserError(BUILDScriptError::ParserError(e)) = &e {
+            // Contexts only tell what was being parsed so they are only used if nothing else is available
+            let mut errors: Vec<_> = e
+                .iter_displayed()
+                .filter(|owned| {
+                    !matches!(
+                        owned.kind(),
+                        ParserErrorKind::Context(_) | ParserErrorKind::OwnedContext(_)
+                    )
+                })
+                .collect();
+            if errors.is_empty() {
+                errors.extend(e.iter_displayed().next());
+            }
+
+            let problems: Vec<_> = errors
+                .into_iter()
+                .map(|owned| {
+                    let preview = e.get_preview(owned);
+                    ProjectProblem {
+                        file: Some(owned.source().to_string()),
+                        line: preview.as_ref().map(|p| p.caret_line_number() + 1),
+                        column: preview.as_ref().map(|p| p.caret_offset() + 1),
+                        message: owned.kind().to_string().trim().to_string(),
+                        severity: Severity::Error,
+                        preview,
+                    }
+                })
+                .collect();
+
+            if !problems.is_empty() {
+                return problems;
+            }
+        }
+
+        let problem = match marker {
+            Some(marker) if !marker.get_source().is_empty() => {
+                let preview = marker.get_preview(2, 2);
+                ProjectProblem {
+                    file: Some(marker.get_source().to_string()),
+                    line: Some(preview.caret_line_number() + 1),
+                    column: Some(preview.caret_offset() + 1),
+                    message: e.to_string(),
+                    severity: Severity::Error,
+                    preview: Some(preview),
+                }
+            }
+            _ => ProjectProblem {
+                file: file.map(|p| p.to_string_lossy().to_string()),
+                line: None,
+                column: None,
+                message: e.to_string(),
+                severity: Severity::Error,
+                preview: None,
+            },
+        };
+
+        vec![problem]
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Display for ProjectProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(
+                f,
+                "{}:{}:{}: ",
+                file,
+                self.line.unwrap_or(1),
+                self.column.unwrap_or(1)
+            )?;
+        }
+
+        if self.severity == Severity::Warning {
+            write!(f, "warning: ")?;
+        }
+
+        writeln!(f, "{}", self.message)?;
+
+        if let Some(preview) = &self.preview {
+            write!(f, "{preview}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn check_project_test() -> crate::GenResult<()> {
+    use crate::Project;
+    use std::fs::write;
+    use tempdir::TempDir;
+
+    let tmp = TempDir::new("typed_graph")?;
+    Project::create_project(tmp.path())?;
+
+    // V0.2 and V0.4 are linked so they are the largest tree
+    let schemas = tmp.path().join("schemas");
+    write(schemas.join("V0.2.bs"), "<V0.2>\n")?;
+    write(schemas.join("V0.3.bs"), "<V0.3>\n")?;
+    write(schemas.join("V0.4.bs"), "<V0.4>\nnode A {};\n")?;
+    Project::open_project(tmp.path())?.create_changeset(&"V0.2".to_string(), &"V0.4".to_string())?;
+
+    write(schemas.join("V0.0.bs"), "<V0.0>\nnode A {\n    b: Missing\n};\n")?;
+    write(schemas.join("V0.1.bs"), "<V0.1>\nnode A {\n    b: String\n")?;
+
+    let problems = Project::check_project(tmp.path())?;
+    let locations: Vec<_> = problems
+        .iter()
+        .map(|problem| {
+            let file = problem.file.as_deref().unwrap_or_default();
+            let file = Path::new(file).file_name().unwrap().to_string_lossy();
+            (file.to_string(), problem.line, problem.column)
+        })
+        .collect();
+
+    assert_eq!(
+        locations,
+        vec![
+            ("V0.0.bs".to_string(), Some(3), Some(8)),
+            ("V0.1.bs".to_string(), Some(3), Some(14)),
+            ("V0.3.bs".to_string(), Some(1), Some(2)),
+        ]
+    );
+
+    Ok(())
+}
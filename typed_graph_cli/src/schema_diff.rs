@@ -0,0 +1,183 @@
+use build_changeset_lang::{
+    ChangeSet, ChangeSetBuilder, ClassifiedChange, Compatibility, SingleChange,
+};
+use build_script_lang::schema::Schema;
+use clap::builder::styling::{AnsiColor, Style};
+use std::fmt::{Debug, Display, Write};
+use std::hash::Hash;
+
+use crate::GenResult;
+
+/// Changes between two schemas created without saving a changeset
+pub struct SchemaDiff<I> {
+    pub changeset: ChangeSet<I>,
+    pub changes: Vec<ClassifiedChange<I>>,
+}
+
+impl<I> SchemaDiff<I>
+where
+    I: Clone + Hash + Default + PartialEq + Debug + Ord,
+{
+    pub fn new(old_schema: &Schema<I>, new_schema: &Schema<I>) -> GenResult<SchemaDiff<I>> {
+        let changeset = old_schema.build_changeset(new_schema)?;
+        let changes = changeset.get_compatibility(old_schema)?;
+
+        Ok(SchemaDiff { changeset, changes })
+    }
+
+    /// Compatibility of all the changes combined
+    pub fn compatibility(&self) -> Compatibility {
+        self.changes
+            .iter()
+            .fold(Compatibility::Safe, |acc, classified| {
+                acc.combine(&classified.compatibility)
+            })
+    }
+
+    /// Describe every change on its own line along with its compatibility
+    ///
+    /// Additions are shown in green, removals in red and everything else in yellow
+    pub fn summary(&self, color: bool) -> String {
+        let mut s = String::new();
+        let old = &self.changeset.old_version;
+        let new = &self.changeset.new_version;
+
+        if self.changes.is_empty() {
+            let _ = writeln!(s, "No changes from {old} to {new}");
+            return s;
+        }
+
+        let _ = writeln!(s, "Changes from {old} to {new}:");
+        for classified in &self.changes {
+            let kind = ChangeKind::of(&classified.change);
+            let target = classified
+                .change
+                .get_target()
+                .map_or_else(|| "schema".to_string(), |target| target.to_string());
+
+            let style = if color {
+                Style::new().fg_color(Some(kind.color().into()))
+            } else {
+                Style::new()
+            };
+            let compatibility_style = match classified.compatibility {
+                Compatibility::Breaking if color => Style::new().bold(),
+                _ => Style::new(),
+            };
+
+            let _ = writeln!(
+                s,
+                "  {}{:<8}{} {} ({}{}{})",
+                style.render(),
+                kind.to_string(),
+                style.render_reset(),
+                target,
+                compatibility_style.render(),
+                classified.compatibility,
+                compatibility_style.render_reset()
+            );
+        }
+
+        let _ = writeln!(s, "The changes are {}", self.compatibility());
+        s
+    }
+}
+
+/// What kind of change was made regardless of what it was made to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Renamed,
+    Edited,
+}
+
+impl ChangeKind {
+    pub fn of<I>(change: &SingleChange<I>) -> ChangeKind {
+        match change {
+            SingleChange::AddedType(_)
+            | SingleChange::AddedVarient(_)
+            | SingleChange::AddedField(_)
+            | SingleChange::AddedEndpoint(_) => ChangeKind::Added,
+            SingleChange::RemovedType(_)
+            | SingleChange::RemovedVarient(_)
+            | SingleChange::RemovedEndpoint(_)
+            | SingleChange::RemovedField(_) => ChangeKind::Removed,
+            SingleChange::RenamedType(_)
+            | SingleChange::RenamedField(_)
+            | SingleChange::RenamedVarient(_) => ChangeKind::Renamed,
+            SingleChange::EditedSchema(_)
+            | SingleChange::EditedFieldType(_)
+            | SingleChange::EditedOpaque(_)
+            | SingleChange::EditedType(_)
+            | SingleChange::EditedVariantsOrder(_)
+            | SingleChange::EditedEndpoint(_)
+            | SingleChange::EditedVariant(_)
+            | SingleChange::EditedGenerics(_) => ChangeKind::Edited,
+        }
+    }
+
+    fn color(&self) -> AnsiColor {
+        match self {
+            ChangeKind::Added => AnsiColor::Green,
+            ChangeKind::Removed => AnsiColor::Red,
+            ChangeKind::Renamed | ChangeKind::Edited => AnsiColor::Yellow,
+        }
+    }
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangeKind::Added => write!(f, "added"),
+            ChangeKind::Removed => write!(f, "removed"),
+            ChangeKind::Renamed => write!(f, "renamed"),
+            ChangeKind::Edited => write!(f, "edited"),
+        }
+    }
+}
+
+#[test]
+fn schema_diff_test() -> GenResult<()> {
+    use build_script_shared::parsers::ParserDeserialize;
+
+    let (_, old_schema) = Schema::<&str>::parse(
+        "
+    <V0>
+    node Student {
+        name: String,
+        score: u8
+    };",
+    )
+    .unwrap();
+    let (_, new_schema) = Schema::<&str>::parse(
+        "
+    <V1>
+    node Student {
+        name: String,
+        age: u8
+    };
+    node Teacher {
+        name: String
+    };",
+    )
+    .unwrap();
+
+    let diff = SchemaDiff::new(&old_schema, &new_schema)?;
+    assert_eq!(diff.compatibility(), Compatibility::Breaking);
+    assert_eq!(
+        diff.summary(false),
+        "Changes from V0 to V1:
+  added    Teacher (backward compatible)
+  added    Teacher.name (safe)
+  added    Student.age (forward compatible)
+  removed  Student.score (backward compatible)
+The changes are breaking
+"
+    );
+
+    let same = SchemaDiff::new(&old_schema, &old_schema)?;
+    assert_eq!(same.summary(false), "No changes from V0 to V0\n");
+
+    Ok(())
+}
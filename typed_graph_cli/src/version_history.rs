@@ -0,0 +1,243 @@
+use build_changeset_lang::ChangeSet;
+use graphviz_rust::cmd::{Format, Layout};
+use graphviz_rust::exec_dot;
+use std::fmt::Write;
+
+use crate::{ChangeKind, Direction, GenResult, Project};
+
+/// A changeset in the version tree along with a count of what it changes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryStep {
+    pub old_version: String,
+    pub new_version: String,
+    pub added: usize,
+    pub removed: usize,
+    /// Renamed items are counted as edited
+    pub edited: usize,
+    pub handler: Option<String>,
+}
+
+impl HistoryStep {
+    pub fn new<I>(changeset: &ChangeSet<I>) -> HistoryStep {
+        let mut step = HistoryStep {
+            old_version: changeset.old_version.to_string(),
+            new_version: changeset.new_version.to_string(),
+            added: 0,
+            removed: 0,
+            edited: 0,
+            handler: changeset.handler.as_ref().map(|handler| handler.to_string()),
+        };
+
+        for change in &changeset.changes {
+            match ChangeKind::of(change) {
+                ChangeKind::Added => step.added += 1,
+                ChangeKind::Removed => step.removed += 1,
+                ChangeKind::Renamed | ChangeKind::Edited => step.edited += 1,
+            }
+        }
+
+        step
+    }
+
+    /// Short description of the changes on the form "+added -removed ~edited"
+    pub fn label(&self) -> String {
+        format!("+{} -{} ~{}", self.added, self.removed, self.edited)
+    }
+}
+
+/// The version tree of a project
+///
+/// Every schema is a node and every changeset is an edge from the old to the new version
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VersionHistory {
+    pub schemas: Vec<String>,
+    pub steps: Vec<HistoryStep>,
+}
+
+impl VersionHistory {
+    pub fn new(prj: &Project) -> GenResult<VersionHistory> {
+        let mut schemas: Vec<String> = prj.iter_schema().cloned().collect();
+        schemas.sort();
+
+        let mut steps = Vec::new();
+        for (_, _, id) in prj.iter_version(Some(Direction::Forward)) {
+            steps.push(HistoryStep::new(prj.get_changeset(id)?));
+        }
+
+        Ok(VersionHistory::from_steps(schemas, steps))
+    }
+
+    /// Schemas are shown in the given order and steps are sorted by version
+    pub fn from_steps(schemas: Vec<String>, mut steps: Vec<HistoryStep>) -> VersionHistory {
+        steps.sort_by(|a, b| {
+            (&a.old_version, &a.new_version).cmp(&(&b.old_version, &b.new_version))
+        });
+        VersionHistory { schemas, steps }
+    }
+
+    /// Changesets with a handler are drawn in bold
+    pub fn to_dot(&self) -> String {
+        let mut s = String::new();
+        let _ = writeln!(s, "digraph history {{");
+        let _ = writeln!(s, "    rankdir=LR;");
+        let _ = writeln!(s, "    node [shape=box, fontsize=10];");
+        let _ = writeln!(s, "    edge [fontsize=10];");
+
+        for schema in &self.schemas {
+            let _ = writeln!(s, "    \"{}\";", dot_escape(schema));
+        }
+
+        for step in &self.steps {
+            let mut label = step.label();
+            let mut style = "";
+            if let Some(handler) = &step.handler {
+                label = format!("{label}\\nhandler: {}", dot_escape(handler));
+                style = ", style=bold";
+            }
+
+            let _ = writeln!(
+                s,
+                "    \"{}\" -> \"{}\" [label=\"{}\"{}];",
+                dot_escape(&step.old_version),
+                dot_escape(&step.new_version),
+                label,
+                style
+            );
+        }
+
+        let _ = writeln!(s, "}}");
+        s
+    }
+
+    /// Changesets with a handler are drawn as thick arrows
+    pub fn to_mermaid(&self) -> String {
+        // Mermaid does not allow dots in ids so every schema is given an id based on its position
+        let node_id = |schema: &str| {
+            self.schemas
+                .iter()
+                .position(|s| s == schema)
+                .map_or_else(|| mermaid_id(schema), |i| format!("s{i}"))
+        };
+
+        let mut s = String::new();
+        let _ = writeln!(s, "graph LR");
+
+        for schema in &self.schemas {
+            let _ = writeln!(s, "    {}[\"{}\"]", node_id(schema), mermaid_text(schema));
+        }
+
+        for step in &self.steps {
+            let mut label = step.label();
+            let mut arrow = "-->";
+            if let Some(handler) = &step.handler {
+                label = format!("{label}<br/>handler: {}", mermaid_text(handler));
+                arrow = "==>";
+            }
+
+            let _ = writeln!(
+                s,
+                "    {} {}|\"{}\"| {}",
+                node_id(&step.old_version),
+                arrow,
+                label,
+                node_id(&step.new_version)
+            );
+        }
+
+        s
+    }
+
+    /// Requires graphviz to be installed
+    pub fn to_svg(&self) -> GenResult<String> {
+        let svg = exec_dot(self.to_dot(), vec![Format::Svg.into(), Layout::Dot.into()])?;
+        Ok(String::from_utf8_lossy(&svg).to_string())
+    }
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn mermaid_id(s: &str) -> String {
+    s.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn mermaid_text(s: &str) -> String {
+    s.replace('"', "#quot;")
+}
+
+#[test]
+fn version_history_test() -> GenResult<()> {
+    use build_changeset_lang::ChangeSetBuilder;
+    use build_script_lang::schema::Schema;
+    use build_script_shared::parsers::ParserDeserialize;
+
+    let (_, v0) = Schema::<&str>::parse(
+        "
+    <V0.0>
+    node Student {
+        name: String,
+        score: u8
+    };",
+    )
+    .unwrap();
+    let (_, v1) = Schema::<&str>::parse(
+        "
+    <V0.1>
+    node Student {
+        name: String,
+        age: u8
+    };
+    node Teacher {
+        name: String
+    };",
+    )
+    .unwrap();
+
+    let first = HistoryStep::new(&v0.build_changeset(&v1)?);
+    let second = HistoryStep {
+        old_version: "V0.1".to_string(),
+        new_version: "V0.2".to_string(),
+        added: 0,
+        removed: 0,
+        edited: 2,
+        handler: Some("migrate_teachers".to_string()),
+    };
+
+    assert_eq!(first.label(), "+3 -1 ~0");
+
+    let history = VersionHistory::from_steps(
+        vec!["V0.0".to_string(), "V0.1".to_string(), "V0.2".to_string()],
+        vec![second, first],
+    );
+
+    assert_eq!(
+        history.to_dot(),
+        r#"digraph history {
+    rankdir=LR;
+    node [shape=box, fontsize=10];
+    edge [fontsize=10];
+    "V0.0";
+    "V0.1";
+    "V0.2";
+    "V0.0" -> "V0.1" [label="+3 -1 ~0"];
+    "V0.1" -> "V0.2" [label="+0 -0 ~2\nhandler: migrate_teachers", style=bold];
+}
+"#
+    );
+
+    assert_eq!(
+        history.to_mermaid(),
+        r#"graph LR
+    s0["V0.0"]
+    s1["V0.1"]
+    s2["V0.2"]
+    s0 -->|"+3 -1 ~0"| s1
+    s1 ==>|"+0 -0 ~2<br/>handler: migrate_teachers"| s2
+"#
+    );
+
+    Ok(())
+}